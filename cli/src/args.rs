@@ -117,6 +117,17 @@ pub struct CliFlags {
     /// Disable human-readable formatting for all byte numbers.
     #[arg(short, long, action, global = true)]
     pub raw_bytes: bool,
+
+    /// Template of the progress counter, e.g. to show the rate or ETA. See the indicatif
+    /// documentation for all available keys.
+    #[arg(long, global = true, value_parser = parse_progress_template)]
+    pub progress_template: Option<String>,
+}
+
+/// Only accepts templates that indicatif can parse.
+fn parse_progress_template(s: &str) -> Result<String> {
+    ProgressStyle::with_template(s)?;
+    Ok(s.to_string())
 }
 
 impl CliFlags {
@@ -125,13 +136,15 @@ impl CliFlags {
             return None;
         }
 
-        let template = if self.raw_bytes {
+        let template = if let Some(template) = &self.progress_template {
+            template
+        } else if self.raw_bytes {
             "{pos} of {len}"
         } else {
             "{binary_bytes} of {binary_total_bytes}"
         };
 
-        Some(ProgressStyle::with_template(template).expect("Static template always works"))
+        Some(ProgressStyle::with_template(template).expect("Template is validated when parsing"))
     }
 }
 
@@ -157,9 +170,21 @@ pub struct CommonArgs {
     #[arg(long, action, global = true)]
     pub no_mmap_prefix: bool,
 
-    /// Path to the seek table file. If specified, implies the "Head" seek table format.
+    /// Path to the seek table file.
     #[arg(long, global = true)]
     pub seek_table_file: Option<PathBuf>,
+
+    /// Remove the input file after successful compression or decompression.
+    ///
+    /// The input file is kept if data is read from STDIN or written to STDOUT. Decompressing only
+    /// a part of the input file fails.
+    #[arg(long, action, global = true)]
+    pub rm: bool,
+
+    /// The format of the seek table file. Detected automatically when reading, compression
+    /// writes the "Head" format by default.
+    #[arg(long, global = true, requires = "seek_table_file")]
+    pub seek_table_format: Option<SeekTableFormat>,
 }
 
 impl CommonArgs {
@@ -209,9 +234,24 @@ pub struct CompressArgs {
     #[arg(long)]
     pub patch_from: Option<PathBuf>,
 
-    /// Input file.
+    /// The number of threads used for compression, 0 uses all available cores.
+    ///
+    /// Multi-threading only speeds up compression of large inputs, zstd hands at least 512K to a
+    /// thread.
+    #[arg(short = 'T', long, value_name = "N", default_value_t = 1)]
+    pub threads: u32,
+
+    /// Compress every frame with the given dictionary.
+    #[arg(short = 'D', long, value_name = "FILE", conflicts_with = "patch_from")]
+    pub dictionary: Option<PathBuf>,
+
+    /// Input files.
+    ///
+    /// Multiple files are bundled into a single archive that requires an output file or STDOUT.
+    /// Every file starts a new frame and a manifest records the position of the files, see
+    /// `--file` to decompress a single file of a bundle.
     #[arg(default_value = "-")]
-    pub input_file: String,
+    pub input_files: Vec<String>,
 
     /// Write data to the specified file.
     #[arg(short, long)]
@@ -219,6 +259,10 @@ pub struct CompressArgs {
 }
 
 impl CompressArgs {
+    pub fn is_bundle(&self) -> bool {
+        self.input_files.len() > 1
+    }
+
     pub fn to_frame_size_policy(&self) -> Result<zeekstd::FrameSizePolicy> {
         let frame_size: u32 = self
             .frame_size
@@ -262,6 +306,21 @@ pub struct DecompressArgs {
     #[arg(long)]
     pub patch_apply: Option<PathBuf>,
 
+    /// Decompress with the dictionary that was used for compression.
+    #[arg(short = 'D', long, value_name = "FILE", conflicts_with = "patch_apply")]
+    pub dictionary: Option<PathBuf>,
+
+    /// Decompress only the file with the given name from a bundle of multiple files.
+    #[arg(long, value_name = "NAME", conflicts_with_all = ["start", "end"])]
+    pub file: Option<String>,
+
+    /// Limit the memory used for the decompression window.
+    ///
+    /// Frames that require a larger window are rejected. Protects against archives that demand
+    /// excessive amounts of memory. The smallest possible limit is 1K.
+    #[arg(long, value_name = "SIZE")]
+    pub memory_limit: Option<ByteValue>,
+
     /// Input file.
     pub input_file: String,
 
@@ -298,7 +357,7 @@ impl DecompressArgs {
     }
 }
 
-#[derive(Debug, ValueEnum, Clone)]
+#[derive(Debug, ValueEnum, Clone, Copy)]
 pub enum SeekTableFormat {
     Head,
     Foot,
@@ -306,10 +365,23 @@ pub enum SeekTableFormat {
 
 #[derive(Debug, Parser)]
 pub struct ListArgs {
+    /// The offset (of the decompressed data) at which listing starts.
+    ///
+    /// Listing starts with the frame that contains the offset.
+    #[arg(long, group = "start")]
+    pub from: Option<ByteValue>,
+
     /// The frame number at which listing starts.
-    #[arg(long)]
+    #[arg(long, group = "start")]
     pub from_frame: Option<u32>,
 
+    /// The offset (of the decompressed data) at which listing ends.
+    ///
+    /// Listing ends with the frame that contains the last byte before the offset. Accepts the
+    /// special value 'end'.
+    #[arg(long, group = "end")]
+    pub to: Option<OffsetLimit>,
+
     /// The frame number at which listing ends (inclusive).
     ///
     /// Accepts special value 'last'.
@@ -320,18 +392,76 @@ pub struct ListArgs {
     #[arg(long, group = "end")]
     pub num_frames: Option<NumFrames>,
 
+    /// List the first N frames, can be combined with --tail.
+    #[arg(long, value_name = "N", conflicts_with_all = ["start", "end"])]
+    pub head: Option<usize>,
+
+    /// List the last N frames, can be combined with --head.
+    #[arg(long, value_name = "N", conflicts_with_all = ["start", "end"])]
+    pub tail: Option<usize>,
+
     /// Detailed listing of individual frames, implied when frame boundaries are specified.
     #[arg(short, long, action)]
     pub detail: bool,
 
-    /// The format of the seek table.
-    #[arg(long, default_value = "foot")]
-    pub seek_table_format: SeekTableFormat,
+    /// Print the summary and the frames as JSON.
+    ///
+    /// If frame boundaries are specified, the summary only covers the selected frames.
+    #[arg(long, action, conflicts_with_all = ["head", "tail"])]
+    pub json: bool,
+
+    /// The format of the seek table, detected automatically if not specified.
+    #[arg(long)]
+    pub seek_table_format: Option<SeekTableFormat>,
 
     /// Input file.
     pub input_file: String,
 }
 
+impl ListArgs {
+    pub fn start_frame(&self, seek_table: &SeekTable) -> Option<u32> {
+        self.from_frame.or_else(|| {
+            self.from
+                .as_ref()
+                .map(|from| seek_table.frame_index_decomp(from.as_u64()))
+        })
+    }
+
+    pub fn end_frame(&self, seek_table: &SeekTable) -> Option<u32> {
+        let last_frame = seek_table.num_frames().saturating_sub(1);
+
+        if let Some(num) = &self.num_frames {
+            Some(self.start_frame(seek_table).unwrap_or(0) + num.additional_frames())
+        } else if let Some(end) = &self.to_frame {
+            match end {
+                LastFrame::End => Some(last_frame),
+                LastFrame::Index(i) => Some(*i),
+            }
+        } else {
+            self.to.as_ref().map(|to| match to {
+                OffsetLimit::End => last_frame,
+                // The offset limit is exclusive
+                OffsetLimit::Value(val) => seek_table.frame_index_decomp(val.saturating_sub(1)),
+            })
+        }
+    }
+}
+
+#[derive(Debug, Parser)]
+pub struct CatArgs {
+    /// Disable output checks.
+    #[arg(short, long, action)]
+    pub force: bool,
+
+    /// Seekable archives to concatenate, in order.
+    #[arg(required = true)]
+    pub input_files: Vec<String>,
+
+    /// Write data to the specified file instead of STDOUT.
+    #[arg(short, long)]
+    pub output_file: Option<PathBuf>,
+}
+
 impl From<SeekTableFormat> for seek_table::Format {
     fn from(value: SeekTableFormat) -> Self {
         match value {
@@ -0,0 +1,75 @@
+use std::{
+    fs::File,
+    io::{self, Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result, bail};
+use zeekstd::{SeekTable, seek_table::Format};
+
+/// Seekable archives that are concatenated without recompression.
+pub struct Archives {
+    inputs: Vec<(PathBuf, File, SeekTable)>,
+}
+
+impl Archives {
+    /// Opens all inputs and reads their seek tables.
+    pub fn open(paths: &[impl AsRef<Path>]) -> Result<Self> {
+        let mut inputs = Vec::with_capacity(paths.len());
+        let mut checksums = None;
+
+        for path in paths {
+            let path = path.as_ref();
+            let mut file = File::open(path)
+                .with_context(|| format!("Failed to open input file {}", path.display()))?;
+            let seek_table = SeekTable::from_seekable(&mut file)
+                .with_context(|| format!("Failed to read seek table of {}", path.display()))?;
+
+            // The merged seek table can only store checksums if every frame has one
+            if seek_table.num_frames() > 0 {
+                let has_checksums = seek_table.stats().has_checksums();
+                if *checksums.get_or_insert(has_checksums) != has_checksums {
+                    bail!(
+                        "{}: frame checksums are {}, but not in the previous inputs",
+                        path.display(),
+                        if has_checksums { "present" } else { "missing" }
+                    );
+                }
+            }
+
+            inputs.push((path.to_path_buf(), file, seek_table));
+        }
+
+        Ok(Self { inputs })
+    }
+
+    /// Copies the frame data of every input to `writer`, followed by a seek table that covers the
+    /// frames of all inputs.
+    ///
+    /// Returns the number of frames and the number of bytes written.
+    pub fn concat<W: Write>(self, mut writer: W) -> Result<(u32, u64)> {
+        let mut merged = SeekTable::new();
+        let mut written = 0;
+
+        for (path, mut file, seek_table) in self.inputs {
+            // Copy the frame data, stripping the seek table
+            file.seek(SeekFrom::Start(0))?;
+            let size = seek_table.size_comp();
+            let n = io::copy(&mut file.take(size), &mut writer)?;
+            if n != size {
+                bail!("{}: unexpected end of file", path.display());
+            }
+            written += n;
+
+            merged
+                .merge(&seek_table)
+                .with_context(|| format!("{}: cannot append the frames", path.display()))?;
+        }
+
+        let num_frames = merged.num_frames();
+        written += merged.write_to(&mut writer, Format::Foot)?;
+        writer.flush()?;
+
+        Ok((num_frames, written))
+    }
+}
@@ -1,4 +1,6 @@
 use std::{
+    collections::HashSet,
+    fmt,
     fs::{self, File},
     io::{self, IsTerminal, Read, Write},
     ops::Deref,
@@ -9,12 +11,15 @@ use anyhow::{Context, Result, bail};
 use clap::Subcommand;
 use indicatif::{HumanBytes, ProgressBar, ProgressDrawTarget};
 use memmap2::Mmap;
-use zeekstd::SeekTable;
+use serde::Serialize;
+use zeekstd::{SeekTable, seek_table::FrameInfo};
 
 use crate::{
-    args::{CliFlags, CompressArgs, DecompressArgs, LastFrame, ListArgs},
-    compress::Compressor,
+    args::{CatArgs, CliFlags, CommonArgs, CompressArgs, DecompressArgs, ListArgs},
+    cat,
+    compress::{Bundler, Compressor},
     decompress::Decompressor,
+    manifest::{self, Manifest},
 };
 
 #[inline]
@@ -31,7 +36,7 @@ fn raw_bytes(n: u64) -> String {
 #[derive(Debug, Subcommand)]
 #[command(arg_required_else_help(true))]
 pub enum Command {
-    /// Compress INPUT_FILE (default); reads from STDIN if INPUT_FILE is `-` or not provided
+    /// Compress INPUT_FILES (default); reads from STDIN if INPUT_FILES is `-` or not provided
     #[clap(alias = "c")]
     Compress(CompressArgs),
     /// Decompress INPUT_FILE
@@ -40,6 +45,8 @@ pub enum Command {
     /// Print information about seekable Zstandard-compressed files
     #[clap(alias = "l")]
     List(ListArgs),
+    /// Concatenate seekable archives into a single seekable archive without recompression
+    Cat(CatArgs),
 }
 
 pub fn checked_out_file(
@@ -81,9 +88,10 @@ pub fn checked_out_file(
 impl Command {
     fn in_path(&self) -> Option<String> {
         let input_file = match self {
-            Command::Compress(CompressArgs { input_file, .. })
-            | Command::Decompress(DecompressArgs { input_file, .. })
+            Command::Decompress(DecompressArgs { input_file, .. })
             | Command::List(ListArgs { input_file, .. }) => input_file.as_str(),
+            Command::Compress(CompressArgs { input_files, .. })
+            | Command::Cat(CatArgs { input_files, .. }) => input_files.first()?.as_str(),
         };
 
         match input_file {
@@ -97,13 +105,18 @@ impl Command {
         let is_stdout = match self {
             Self::Compress(CompressArgs { common, .. })
             | Self::Decompress(DecompressArgs { common, .. }) => common.stdout,
-            Self::List(_) => false,
+            Self::List(_) | Self::Cat(_) => false,
         };
         if is_stdout {
             return Ok(None);
         }
 
         match &self {
+            Command::Compress(args @ CompressArgs { output_file, .. })
+                if args.is_bundle() && output_file.is_none() =>
+            {
+                bail!("Compressing multiple files requires an output file or --stdout")
+            }
             Command::Compress(CompressArgs { output_file, .. }) => Ok(output_file
                 .clone()
                 .or_else(|| in_path.map(|p| p.with_added_extension("zst")))),
@@ -111,26 +124,57 @@ impl Command {
                 if output_file.is_some() {
                     Ok(output_file.clone())
                 } else {
-                    if in_path
-                        .as_ref()
-                        .is_some_and(|p| p.extension().is_none_or(|e| e != "zst"))
-                    {
-                        bail!(
-                            "{}: unknown extension (.zst expected); cannot derive the output file name",
-                            in_path.unwrap_or_default().display()
-                        )
+                    // Like zstd, strip a trailing ".zst" and replace ".tzst" with ".tar"
+                    match in_path {
+                        Some(p) => match p.extension().and_then(|e| e.to_str()) {
+                            Some("zst") => Ok(Some(p.with_extension(""))),
+                            Some("tzst") => Ok(Some(p.with_extension("tar"))),
+                            _ => bail!(
+                                "{}: unknown extension (.zst or .tzst expected); cannot derive the output file name",
+                                p.display()
+                            ),
+                        },
+                        None => Ok(None),
                     }
-                    Ok(in_path.map(|p| p.with_extension("")))
                 }
             }
+            Command::Cat(CatArgs { output_file, .. }) => Ok(output_file.clone()),
             Command::List(_) => Ok(None),
         }
     }
 
+    fn dictionary(&self) -> Option<&Path> {
+        match self {
+            Self::Compress(CompressArgs { dictionary, .. })
+            | Self::Decompress(DecompressArgs { dictionary, .. }) => dictionary.as_deref(),
+            Self::List(_) | Self::Cat(_) => None,
+        }
+    }
+
+    /// The input file that is removed after the command succeeded.
+    fn removable_input(&self, out_path: Option<&Path>) -> Option<PathBuf> {
+        let rm = match self {
+            Self::Compress(CompressArgs { common, .. })
+            | Self::Decompress(DecompressArgs { common, .. }) => common.rm,
+            Self::List(_) | Self::Cat(_) => false,
+        };
+        let in_path = PathBuf::from(self.in_path()?);
+        let out_path = out_path?;
+
+        // Never remove the output
+        let is_output = match (fs::canonicalize(&in_path), fs::canonicalize(out_path)) {
+            (Ok(input), Ok(output)) => input == output,
+            _ => in_path == out_path,
+        };
+
+        (rm && !is_output).then_some(in_path)
+    }
+
     fn force_write_stdout(&self) -> bool {
         match self {
             Self::Compress(CompressArgs { common, .. })
             | Self::Decompress(DecompressArgs { common, .. }) => common.force,
+            Self::Cat(CatArgs { force, .. }) => *force,
             // Always write to stdout in list mode
             Self::List(_) => true,
         }
@@ -140,7 +184,13 @@ impl Command {
     pub fn run(self, flags: &CliFlags) -> Result<()> {
         let in_path = self.in_path();
         let out_path = self.out_path()?;
+        let remove_input = self.removable_input(out_path.as_deref());
         let force_write_stdout = self.force_write_stdout();
+        let dictionary = self
+            .dictionary()
+            .map(fs::read)
+            .transpose()
+            .context("Failed to read dictionary")?;
 
         // This is a closure so the writer can be created after the input has been validated
         let new_writer = || -> Result<Box<dyn Write>> {
@@ -162,7 +212,69 @@ impl Command {
         } else {
             human_bytes
         };
+        let new_seek_table_file = |common: &CommonArgs| -> Result<Option<File>> {
+            common
+                .seek_table_file
+                .as_ref()
+                .map(|p| checked_out_file(p, in_path.as_deref(), flags.quiet, force_write_stdout))
+                .transpose()
+                .context("Failed to create seek table file")
+        };
+        let new_bar = |len: Option<u64>| {
+            flags.progress_style().map(|style| {
+                ProgressBar::with_draw_target(len, ProgressDrawTarget::stderr_with_hz(5))
+                    .with_style(style)
+            })
+        };
+
         let exec = match self {
+            Command::Compress(args) if args.is_bundle() => {
+                if args.patch_from.is_some() {
+                    bail!("Cannot compress multiple files with --patch-from");
+                }
+                if args.common.rm {
+                    bail!("Cannot remove the input files when compressing multiple files");
+                }
+                // Check all inputs before the output is created
+                let mut in_len = 0;
+                let mut names = HashSet::new();
+                for path in &args.input_files {
+                    if path == "-" {
+                        bail!("Cannot read STDIN when compressing multiple files");
+                    }
+                    let name = manifest::entry_name(path)?;
+                    if !names.insert(name) {
+                        bail!("Cannot bundle multiple files named {name}");
+                    }
+                    let meta = fs::metadata(path)
+                        .with_context(|| format!("Failed to open input file {path}"))?;
+                    in_len += meta.len();
+                }
+                let bundler = Bundler::new(
+                    &args,
+                    dictionary.as_deref(),
+                    new_seek_table_file(&args.common)?,
+                    new_writer()?,
+                    new_bar(Some(in_len)),
+                )?;
+
+                let mode = ExecMode::Bundle {
+                    num_files: args.input_files.len(),
+                    paths: args.input_files,
+                    bundler,
+                    out_path: out_path
+                        .and_then(|p| p.to_str().map(Into::into))
+                        .unwrap_or("STDOUT".into()),
+                };
+
+                Executor {
+                    mode,
+                    quiet: flags.quiet,
+                    in_path: in_path.unwrap_or_default(),
+                    remove_input: None,
+                    byte_fmt,
+                }
+            }
             Command::Compress(args) => {
                 let reader: Box<dyn Read> = if let Some(p) = &in_path {
                     let file = File::open(p).context("Failed to open input file")?;
@@ -178,32 +290,17 @@ impl Command {
                     .patch_from
                     .as_ref()
                     .and_then(|p| fs::metadata(p).map(|m| m.len()).ok());
-                let seek_table_file = args
-                    .common
-                    .seek_table_file
+                let in_len = in_path
                     .as_ref()
-                    .map(|p| {
-                        checked_out_file(p, in_path.as_deref(), flags.quiet, force_write_stdout)
-                    })
-                    .transpose()
-                    .context("Failed to create seek table file")?;
-                let progress_style = flags.progress_style();
-                let bar = if let Some(style) = progress_style {
-                    let in_len = in_path
-                        .as_ref()
-                        .and_then(|p| fs::metadata(p).map(|m| m.len()).ok());
-                    Some(
-                        ProgressBar::with_draw_target(
-                            in_len,
-                            ProgressDrawTarget::stderr_with_hz(5),
-                        )
-                        .with_style(style),
-                    )
-                } else {
-                    None
-                };
-                let compressor =
-                    Compressor::new(&args, prefix_len, seek_table_file, new_writer()?, bar)?;
+                    .and_then(|p| fs::metadata(p).map(|m| m.len()).ok());
+                let compressor = Compressor::new(
+                    &args,
+                    prefix_len,
+                    dictionary.as_deref(),
+                    new_seek_table_file(&args.common)?,
+                    new_writer()?,
+                    new_bar(in_len),
+                )?;
 
                 let mode = ExecMode::Compress {
                     reader,
@@ -219,6 +316,7 @@ impl Command {
                     mode,
                     quiet: flags.quiet,
                     in_path: in_path.unwrap_or("STDIN".into()),
+                    remove_input,
                     byte_fmt,
                 }
             }
@@ -227,8 +325,16 @@ impl Command {
                     .patch_apply
                     .as_ref()
                     .and_then(|p| fs::metadata(p).map(|m| m.len()).ok());
+                let decompressor = Decompressor::new(
+                    &args,
+                    prefix_len,
+                    dictionary.as_deref(),
+                    flags.progress_style(),
+                )?;
+                if args.common.rm && !decompressor.is_complete() {
+                    bail!("Cannot remove the input file when decompressing only a part of it");
+                }
                 let writer = new_writer()?;
-                let decompressor = Decompressor::new(&args, prefix_len, flags.progress_style())?;
 
                 let mode = ExecMode::Decompress {
                     decompressor,
@@ -241,35 +347,54 @@ impl Command {
                     mode,
                     quiet: flags.quiet,
                     in_path: args.input_file,
+                    remove_input,
                     byte_fmt,
                 }
             }
             Command::List(args) => {
                 let mut file = File::open(&args.input_file).context("Failed to open input file")?;
-                let seek_table =
-                    SeekTable::from_seekable_format(&mut file, args.seek_table_format.into())
-                        .context("Failed to read seek table")?;
-
-                let end_frame = if let Some(num) = args.num_frames {
-                    Some(args.from_frame.unwrap_or(0) + num.additional_frames())
-                } else {
-                    args.to_frame.map(|e| match e {
-                        LastFrame::End => seek_table.num_frames() - 1,
-                        LastFrame::Index(i) => i,
-                    })
-                };
+                let seek_table = match args.seek_table_format {
+                    Some(format) => SeekTable::from_seekable_format(&mut file, format.into()),
+                    None => SeekTable::from_seekable_auto(&mut file),
+                }
+                .context("Failed to read seek table")?;
+                let manifest = Manifest::read(&mut file, &seek_table)?;
 
                 let mode = ExecMode::List {
+                    start_frame: args.start_frame(&seek_table),
+                    end_frame: args.end_frame(&seek_table),
                     seek_table,
-                    start_frame: args.from_frame,
-                    end_frame,
+                    manifest,
+                    head: args.head,
+                    tail: args.tail,
                     detail: args.detail,
+                    json: args.json,
                 };
 
                 Executor {
                     mode,
                     quiet: flags.quiet,
                     in_path: args.input_file,
+                    remove_input: None,
+                    byte_fmt,
+                }
+            }
+            Command::Cat(args) => {
+                let archives = cat::Archives::open(&args.input_files)?;
+                let mode = ExecMode::Cat {
+                    archives,
+                    num_files: args.input_files.len(),
+                    writer: new_writer()?,
+                    out_path: out_path
+                        .and_then(|p| p.to_str().map(Into::into))
+                        .unwrap_or("STDOUT".into()),
+                };
+
+                Executor {
+                    mode,
+                    quiet: flags.quiet,
+                    in_path: in_path.unwrap_or_default(),
+                    remove_input: None,
                     byte_fmt,
                 }
             }
@@ -295,9 +420,25 @@ enum ExecMode<'a> {
     },
     List {
         seek_table: SeekTable,
+        manifest: Option<Manifest>,
         start_frame: Option<u32>,
         end_frame: Option<u32>,
+        head: Option<usize>,
+        tail: Option<usize>,
         detail: bool,
+        json: bool,
+    },
+    Bundle {
+        paths: Vec<String>,
+        num_files: usize,
+        bundler: Bundler<'a, Box<dyn Write>>,
+        out_path: String,
+    },
+    Cat {
+        archives: cat::Archives,
+        num_files: usize,
+        writer: Box<dyn Write>,
+        out_path: String,
     },
 }
 
@@ -305,6 +446,8 @@ struct Executor<'a> {
     mode: ExecMode<'a>,
     quiet: bool,
     in_path: String,
+    // Removed after the operation succeeded
+    remove_input: Option<PathBuf>,
     byte_fmt: fn(u64) -> String,
 }
 
@@ -333,6 +476,23 @@ impl Executor<'_> {
                     );
                 }
             }
+            ExecMode::Bundle {
+                paths,
+                num_files,
+                bundler,
+                out_path,
+            } => {
+                let (read, written) = bundler.compress_files(&paths)?;
+
+                if !self.quiet {
+                    eprintln!(
+                        "{num_files} files : {ratio:.2}% ( {bytes_read} => {bytes_written}, {out_path})",
+                        ratio = 100. / read as f64 * written as f64,
+                        bytes_read = (self.byte_fmt)(read),
+                        bytes_written = (self.byte_fmt)(written),
+                    );
+                }
+            }
             ExecMode::Decompress {
                 decompressor,
                 mut writer,
@@ -342,6 +502,7 @@ impl Executor<'_> {
                 let prefix = Prefix::new(prefix, mmap_prefix)
                     .context("Failed to load prefix (patch) file")?;
                 let written = decompressor.decompress_into(&mut writer, prefix.as_deref())?;
+                writer.flush().context("Failed to flush output")?;
 
                 if !self.quiet {
                     eprintln!(
@@ -353,16 +514,47 @@ impl Executor<'_> {
             }
             ExecMode::List {
                 seek_table,
+                manifest,
                 start_frame,
                 end_frame,
+                head,
+                tail,
                 detail,
+                json,
             } => {
-                if start_frame.is_none() && end_frame.is_none() && !detail {
+                if json {
+                    list_json(&seek_table, start_frame, end_frame)?;
+                } else if head.is_some() || tail.is_some() {
+                    list_head_tail(&seek_table, head, tail, self.byte_fmt)?;
+                } else if start_frame.is_none() && end_frame.is_none() && !detail {
                     list_summarize(&seek_table, &self.in_path, self.byte_fmt);
+                    if let Some(manifest) = manifest {
+                        list_files(&manifest, self.byte_fmt);
+                    }
                 } else {
                     list_frames(&seek_table, start_frame, end_frame, self.byte_fmt)?;
                 }
             }
+            ExecMode::Cat {
+                archives,
+                num_files,
+                writer,
+                out_path,
+            } => {
+                let (frames, written) = archives.concat(writer)?;
+
+                if !self.quiet {
+                    eprintln!(
+                        "{num_files} files : {frames} frames, {bytes_written} ({out_path})",
+                        bytes_written = (self.byte_fmt)(written),
+                    );
+                }
+            }
+        }
+
+        if let Some(path) = self.remove_input {
+            fs::remove_file(&path)
+                .with_context(|| format!("Failed to remove input file {}", path.display()))?;
         }
 
         Ok(())
@@ -407,17 +599,12 @@ impl Deref for Prefix {
 
 #[allow(clippy::cast_precision_loss)]
 fn list_summarize(st: &SeekTable, in_path: &str, byte_fmt: fn(u64) -> String) {
-    let num_frames = st.num_frames();
-    let compressed = st
-        .frame_end_comp(num_frames - 1)
-        .expect("Frame index is never out of range");
-    let uncompressed = st
-        .frame_end_decomp(num_frames - 1)
-        .expect("Frame index is never out of range");
-    let ratio = uncompressed as f64 / compressed as f64;
-    let compressed = (byte_fmt)(compressed);
-    let uncompressed = (byte_fmt)(uncompressed);
-    let max_frame_size = (byte_fmt)(st.max_frame_size_decomp());
+    let stats = st.stats();
+    let num_frames = stats.num_frames();
+    let ratio = stats.size_decomp() as f64 / stats.size_comp() as f64;
+    let compressed = (byte_fmt)(stats.size_comp());
+    let uncompressed = (byte_fmt)(stats.size_decomp());
+    let max_frame_size = (byte_fmt)(stats.max_frame_size_decomp());
 
     println!(
         "{: <15} {: <15} {: <15} {: <15} {: <10} {: <15}",
@@ -428,44 +615,165 @@ fn list_summarize(st: &SeekTable, in_path: &str, byte_fmt: fn(u64) -> String) {
     );
 }
 
+/// Prints the files of a bundle.
+fn list_files(manifest: &Manifest, byte_fmt: fn(u64) -> String) {
+    println!();
+    println!(
+        "{: <15} {: <15} {: <20} {: <15}",
+        "First Frame", "Uncompressed", "Uncompressed Offset", "Filename"
+    );
+    for entry in manifest.entries() {
+        println!(
+            "{frame: <15} {size: <15} {offset: <20} {name: <15}",
+            frame = entry.frame,
+            size = (byte_fmt)(entry.size),
+            offset = (byte_fmt)(entry.offset),
+            name = entry.name,
+        );
+    }
+}
+
 fn list_frames(
     st: &SeekTable,
     start_frame: Option<u32>,
     end_frame: Option<u32>,
     byte_fmt: fn(u64) -> String,
 ) -> Result<()> {
-    use std::fmt::Write as _;
+    let (start, end) = frame_range(st, start_frame, end_frame)?;
+    let mut buf = String::new();
+
+    print_frame_header();
+
+    let frames = st
+        .frames()
+        .skip(start as usize)
+        .take((end - start) as usize + 1);
+    for (cnt, frame) in frames.enumerate() {
+        write_frame(&mut buf, &frame, byte_fmt)?;
+
+        if cnt % 100 == 99 {
+            print!("{buf}");
+            buf.clear();
+        }
+    }
+    print!("{buf}");
 
+    Ok(())
+}
+
+/// The inclusive range of frames to list.
+fn frame_range(
+    st: &SeekTable,
+    start_frame: Option<u32>,
+    end_frame: Option<u32>,
+) -> Result<(u32, u32)> {
+    let num_frames = st.num_frames();
     let start = start_frame.unwrap_or(0);
-    let end = end_frame.unwrap_or_else(|| st.num_frames() - 1);
+    let end = end_frame.unwrap_or_else(|| num_frames.saturating_sub(1));
     if start > end {
         bail!("Start frame ({start}) cannot be greater than end frame ({end})");
     }
-    let mut buf = String::new();
+    if end >= num_frames {
+        bail!("End frame ({end}) is out of range, the archive has {num_frames} frames");
+    }
+
+    Ok((start, end))
+}
+
+/// The output of `list --json`, the summary describes the listed frames.
+#[derive(Default, Serialize)]
+struct JsonListing {
+    num_frames: u32,
+    size_compressed: u64,
+    size_decompressed: u64,
+    frames: Vec<JsonFrame>,
+}
+
+#[derive(Serialize)]
+struct JsonFrame {
+    index: u32,
+    comp: u64,
+    decomp: u64,
+    comp_offset: u64,
+    decomp_offset: u64,
+}
+
+fn list_json(st: &SeekTable, start_frame: Option<u32>, end_frame: Option<u32>) -> Result<()> {
+    // Archives without frames have no valid range
+    let (start, len) = if start_frame.is_none() && end_frame.is_none() {
+        (0, st.num_frames() as usize)
+    } else {
+        let (start, end) = frame_range(st, start_frame, end_frame)?;
+        (start as usize, (end - start) as usize + 1)
+    };
 
+    let mut listing = JsonListing::default();
+    for frame in st.frames().skip(start).take(len) {
+        listing.num_frames += 1;
+        listing.size_compressed += frame.comp_size();
+        listing.size_decompressed += frame.decomp_size();
+        listing.frames.push(JsonFrame {
+            index: frame.index(),
+            comp: frame.comp_size(),
+            decomp: frame.decomp_size(),
+            comp_offset: frame.comp_offset(),
+            decomp_offset: frame.decomp_offset(),
+        });
+    }
+
+    let mut stdout = io::stdout().lock();
+    serde_json::to_writer(&mut stdout, &listing).context("Failed to write JSON")?;
+    writeln!(stdout)?;
+
+    Ok(())
+}
+
+fn print_frame_header() {
     println!(
         "{: <15} {: <15} {: <15} {: <20} {: <20}",
         "Frame Index", "Compressed", "Uncompressed", "Compressed Offset", "Uncompressed Offset"
     );
+}
 
-    let mut cnt = 0;
-    for n in start..=end {
-        let comp = (byte_fmt)(st.frame_size_comp(n)?);
-        let uncomp = (byte_fmt)(st.frame_size_decomp(n)?);
-        let comp_off = (byte_fmt)(st.frame_start_comp(n)?);
-        let uncomp_off = (byte_fmt)(st.frame_start_decomp(n)?);
-
-        writeln!(
-            &mut buf,
-            "{n: <15} {comp: <15} {uncomp: <15} {comp_off: <20} {uncomp_off: <20}",
-        )?;
-
-        cnt += 1;
-        if cnt == 100 {
-            cnt = 0;
-            print!("{buf}");
-            buf.clear();
-        }
+fn write_frame(buf: &mut String, frame: &FrameInfo, byte_fmt: fn(u64) -> String) -> fmt::Result {
+    use std::fmt::Write as _;
+
+    writeln!(
+        buf,
+        "{n: <15} {comp: <15} {uncomp: <15} {comp_off: <20} {uncomp_off: <20}",
+        n = frame.index(),
+        comp = (byte_fmt)(frame.comp_size()),
+        uncomp = (byte_fmt)(frame.decomp_size()),
+        comp_off = (byte_fmt)(frame.comp_offset()),
+        uncomp_off = (byte_fmt)(frame.decomp_offset()),
+    )
+}
+
+fn list_head_tail(
+    st: &SeekTable,
+    head: Option<usize>,
+    tail: Option<usize>,
+    byte_fmt: fn(u64) -> String,
+) -> Result<()> {
+    use std::fmt::Write as _;
+
+    let (head, _) = st.head_tail(head.unwrap_or(0));
+    let (_, tail) = st.head_tail(tail.unwrap_or(0));
+    let mut buf = String::new();
+
+    print_frame_header();
+
+    for frame in &head {
+        write_frame(&mut buf, frame, byte_fmt)?;
+    }
+    // Skip frames that were already listed in the head
+    let next = head.last().map_or(0, |f| f.index() + 1);
+    let tail: Vec<_> = tail.iter().filter(|f| f.index() >= next).collect();
+    if tail.first().is_some_and(|f| f.index() > next) && !head.is_empty() {
+        writeln!(&mut buf, "...")?;
+    }
+    for frame in tail {
+        write_frame(&mut buf, frame, byte_fmt)?;
     }
     print!("{buf}");
 
@@ -1,51 +1,82 @@
 use std::{
     fs::File,
-    io::{self, Read, Write},
+    io::{Read, Write},
+    thread,
 };
 
-use anyhow::{Context, Result, anyhow};
+use anyhow::{Context, Result};
 use indicatif::ProgressBar;
-use zeekstd::{EncodeOptions, Encoder, seek_table::Format};
-use zstd_safe::{CCtx, CParameter};
+use zeekstd::{EncodeOptions, Encoder, RawEncoder, seek_table::Format};
+use zstd_safe::CCtx;
 
-use crate::args::CompressArgs;
+use crate::{
+    args::CompressArgs,
+    manifest::{self, Entry, Manifest},
+};
+
+/// Creates the [`EncodeOptions`] for the compression arguments.
+fn encode_options<'a>(
+    args: &CompressArgs,
+    prefix_len: Option<u64>,
+    dictionary: Option<&'a [u8]>,
+) -> Result<EncodeOptions<'a>> {
+    let policy = args.to_frame_size_policy()?;
+    let cctx = CCtx::try_create().context("Failed to create compression context")?;
+    let mut opts = EncodeOptions::with_cctx(cctx);
+
+    if let Some(len) = prefix_len {
+        let window_log = if len == 0 { 0 } else { len.ilog2() + 1 };
+        opts = opts.window_log(window_log).long_distance_matching(true);
+    }
+    if let Some(dict) = dictionary {
+        opts = opts.dictionary(dict);
+    }
+
+    let threads = match args.threads {
+        0 => thread::available_parallelism().map_or(1, |n| n.get() as u32),
+        n => n,
+    };
+    // A single thread compresses in the foreground, without workers
+    if threads > 1 {
+        opts = opts.workers(threads);
+    }
+
+    Ok(opts
+        .frame_size_policy(policy)
+        .content_checksum(!args.no_checksum)
+        .compression_level(args.compression_level))
+}
+
+fn seek_table_format(args: &CompressArgs) -> Format {
+    args.common
+        .seek_table_format
+        .map_or(Format::Head, Into::into)
+}
 
 pub struct Compressor<'a, W> {
     encoder: Encoder<'a, W>,
     seek_table_file: Option<File>,
+    seek_table_format: Format,
     bar: Option<ProgressBar>,
 }
 
-impl<W> Compressor<'_, W> {
+impl<'a, W> Compressor<'a, W> {
     pub fn new(
         args: &CompressArgs,
         prefix_len: Option<u64>,
+        dictionary: Option<&'a [u8]>,
         seek_table_file: Option<File>,
         writer: W,
         bar: Option<ProgressBar>,
     ) -> Result<Self> {
-        let cctx_err = |msg, c| anyhow!("{msg}: {}", zstd_safe::get_error_name(c));
-        let policy = args.to_frame_size_policy()?;
-        let mut cctx = CCtx::try_create().context("Failed to create compression context")?;
-
-        if let Some(len) = prefix_len {
-            let window_log = if len == 0 { 0 } else { len.ilog2() + 1 };
-            cctx.set_parameter(CParameter::WindowLog(window_log))
-                .map_err(|c| cctx_err("Failed to set window log", c))?;
-            cctx.set_parameter(CParameter::EnableLongDistanceMatching(true))
-                .map_err(|c| cctx_err("Failed to enable long distance matching", c))?;
-        }
-
-        let encoder = EncodeOptions::with_cctx(cctx)
-            .frame_size_policy(policy)
-            .checksum_flag(!args.no_checksum)
-            .compression_level(args.compression_level)
+        let encoder = encode_options(args, prefix_len, dictionary)?
             .into_encoder(writer)
             .context("Failed to create encoder")?;
 
         Ok(Self {
             encoder,
             seek_table_file,
+            seek_table_format: seek_table_format(args),
             bar,
         })
     }
@@ -57,7 +88,8 @@ impl<'a, W: Write> Compressor<'a, W> {
         reader: &mut R,
         prefix: Option<&'b [u8]>,
     ) -> Result<(u64, u64)> {
-        let mut buf = vec![0; CCtx::in_size()];
+        let (in_size, _) = zeekstd::recommended_compress_buffer_sizes();
+        let mut buf = vec![0; in_size];
         let mut bytes_read = 0;
 
         loop {
@@ -88,9 +120,11 @@ impl<'a, W: Write> Compressor<'a, W> {
                     .context("Failed to end last frame")?;
                 self.encoder.flush().context("Failed to flush encoder")?;
                 let written = self.encoder.written_compressed();
-                let st = self.encoder.into_seek_table();
-                let mut ser = st.into_format_serializer(Format::Head);
-                let n = io::copy(&mut ser, &mut file).context("Failed to write seek table")?;
+                let n = self
+                    .encoder
+                    .into_seek_table()
+                    .write_to(&mut file, self.seek_table_format)
+                    .context("Failed to write seek table")?;
                 written + n
             }
             None => self
@@ -105,3 +139,140 @@ impl<'a, W: Write> Compressor<'a, W> {
         Ok((bytes_read, bytes_written))
     }
 }
+
+/// Compresses multiple files into a single archive, starting a new frame for every file.
+///
+/// A [`Manifest`] of the files is written after the frames of the last file.
+pub struct Bundler<'a, W> {
+    encoder: RawEncoder<'a>,
+    out_buf: Vec<u8>,
+    writer: W,
+    written: u64,
+    manifest: Manifest,
+    seek_table_file: Option<File>,
+    seek_table_format: Format,
+    bar: Option<ProgressBar>,
+}
+
+impl<'a, W> Bundler<'a, W> {
+    pub fn new(
+        args: &CompressArgs,
+        dictionary: Option<&'a [u8]>,
+        seek_table_file: Option<File>,
+        writer: W,
+        bar: Option<ProgressBar>,
+    ) -> Result<Self> {
+        let encoder = encode_options(args, None, dictionary)?
+            .into_raw_encoder()
+            .context("Failed to create encoder")?;
+        let (_, out_size) = zeekstd::recommended_compress_buffer_sizes();
+
+        Ok(Self {
+            encoder,
+            out_buf: vec![0; out_size],
+            writer,
+            written: 0,
+            manifest: Manifest::default(),
+            seek_table_file,
+            seek_table_format: seek_table_format(args),
+            bar,
+        })
+    }
+}
+
+impl<W: Write> Bundler<'_, W> {
+    /// Compresses the files in order and finishes the archive.
+    ///
+    /// Returns the number of bytes read and written.
+    pub fn compress_files(mut self, paths: &[String]) -> Result<(u64, u64)> {
+        let mut bytes_read = 0;
+
+        for path in paths {
+            let mut file =
+                File::open(path).with_context(|| format!("Failed to open input file {path}"))?;
+            let frame = self.encoder.seek_table().num_frames();
+            let size = self
+                .compress_file(&mut file)
+                .with_context(|| format!("Failed to compress {path}"))?;
+            self.manifest.push(Entry {
+                name: manifest::entry_name(path)?.into(),
+                frame,
+                offset: bytes_read,
+                size,
+            });
+            bytes_read += size;
+        }
+
+        let manifest = self.manifest.to_frame()?;
+        self.writer
+            .write_all(&manifest)
+            .context("Failed to write manifest")?;
+        self.written += manifest.len() as u64;
+
+        let mut seek_table = self.encoder.into_seek_table();
+        seek_table
+            .log_frame(u32::try_from(manifest.len())?, 0)
+            .context("Failed to log manifest")?;
+        self.written += match self.seek_table_file {
+            Some(mut file) => seek_table.write_to(&mut file, self.seek_table_format),
+            None => seek_table.write_to(&mut self.writer, Format::Foot),
+        }
+        .context("Failed to write seek table")?;
+        self.writer.flush().context("Failed to flush output")?;
+
+        if let Some(bar) = &self.bar {
+            bar.finish_and_clear();
+        }
+        Ok((bytes_read, self.written))
+    }
+
+    /// Compresses `reader` and ends the frame, so the next file starts a new frame.
+    fn compress_file<R: Read>(&mut self, reader: &mut R) -> Result<u64> {
+        let (in_size, _) = zeekstd::recommended_compress_buffer_sizes();
+        let mut buf = vec![0; in_size];
+        let mut bytes_read = 0;
+
+        loop {
+            let limit = reader.read(&mut buf).context("Failed to read input")?;
+            if limit == 0 {
+                break;
+            }
+            bytes_read += limit as u64;
+            if let Some(bar) = &self.bar {
+                bar.inc(limit as u64);
+            }
+
+            let mut buf_pos = 0;
+            while buf_pos < limit {
+                let prog = self
+                    .encoder
+                    .compress(&buf[buf_pos..limit], &mut self.out_buf)
+                    .context("Failed to compress data")?;
+                self.write_out_buf(prog.out_progress())?;
+                buf_pos += prog.in_progress();
+            }
+        }
+
+        loop {
+            let prog = self
+                .encoder
+                .end_frame(&mut self.out_buf)
+                .context("Failed to end frame")?;
+            self.write_out_buf(prog.out_progress())?;
+            if prog.data_left() == 0 {
+                break;
+            }
+        }
+
+        Ok(bytes_read)
+    }
+
+    fn write_out_buf(&mut self, len: usize) -> Result<()> {
+        self.writer
+            .write_all(&self.out_buf[..len])
+            .context("Failed to write compressed data")?;
+        self.written += len as u64;
+
+        Ok(())
+    }
+}
@@ -5,36 +5,49 @@ use indicatif::{ProgressBar, ProgressDrawTarget, ProgressStyle};
 use zeekstd::{DecodeOptions, Decoder, SeekTable};
 use zstd_safe::{DCtx, DParameter};
 
-use crate::args::DecompressArgs;
+use crate::{args::DecompressArgs, manifest::Manifest};
 
 pub struct Decompressor<'a> {
     decoder: Decoder<'a, File>,
     bar: Option<ProgressBar>,
 }
 
-impl Decompressor<'_> {
+impl<'a> Decompressor<'a> {
     pub fn new(
         args: &DecompressArgs,
         prefix_len: Option<u64>,
+        dictionary: Option<&'a [u8]>,
         progress_style: Option<ProgressStyle>,
     ) -> Result<Self> {
         let mut src = File::open(&args.input_file).context("Failed to open input file")?;
         let seek_table = match &args.common.seek_table_file {
             Some(path) => {
                 let mut file = File::open(path).context("Failed to open seek table file")?;
-                SeekTable::from_reader(&mut file)
+                match args.common.seek_table_format {
+                    Some(format) => SeekTable::from_seekable_format(&mut file, format.into()),
+                    None => SeekTable::from_seekable_auto(&mut file),
+                }
             }
             None => SeekTable::from_seekable(&mut src),
         }
         .context("Failed to parse seek table")?;
 
-        let offset = args
-            .offset(&seek_table)
-            .context("Failed to get decompression offset")?;
-
-        let offset_limit = args
-            .offset_limit(&seek_table)
-            .context("Failed to get decompression offset limit")?;
+        let (offset, offset_limit) = if let Some(name) = &args.file {
+            let manifest = Manifest::read(&mut src, &seek_table)?
+                .context("The archive is not a bundle of multiple files")?;
+            let entry = manifest
+                .get(name)
+                .with_context(|| format!("{name} is not part of the bundle"))?;
+            (entry.offset, entry.offset + entry.size)
+        } else {
+            let offset = args
+                .offset(&seek_table)
+                .context("Failed to get decompression offset")?;
+            let offset_limit = args
+                .offset_limit(&seek_table)
+                .context("Failed to get decompression offset limit")?;
+            (offset, offset_limit)
+        };
 
         let bar = if let Some(style) = progress_style {
             let bar = ProgressBar::with_draw_target(
@@ -50,9 +63,15 @@ impl Decompressor<'_> {
             None
         };
 
+        let mut window_log_max = prefix_len.and_then(|len| len.checked_ilog2().map(|l| l + 1));
+        if let Some(limit) = &args.memory_limit {
+            // The window must fit into the memory limit, zstd requires at least 1 KiB
+            let limit_log = limit.as_u64().max(1).ilog2().max(10);
+            window_log_max = Some(window_log_max.map_or(limit_log, |log| log.min(limit_log)));
+        }
+
         let mut dctx = DCtx::try_create().context("Failed to create decompression context")?;
-        if let Some(len) = prefix_len {
-            let window_log_max = if len == 0 { 0 } else { len.ilog2() + 1 };
+        if let Some(window_log_max) = window_log_max {
             dctx.set_parameter(DParameter::WindowLogMax(window_log_max))
                 .map_err(|c| {
                     anyhow!(
@@ -62,24 +81,33 @@ impl Decompressor<'_> {
                 })?;
         }
 
-        let decoder = DecodeOptions::with_dctx(src, dctx)
+        let mut opts = DecodeOptions::with_dctx(src, dctx)
             .seek_table(seek_table)
             .offset(offset)
-            .offset_limit(offset_limit)
-            .into_decoder()
-            .context("Failed to create decoder")?;
+            .offset_limit(offset_limit);
+        if let Some(dict) = dictionary {
+            opts = opts.dictionary(dict);
+        }
+        let decoder = opts.into_decoder().context("Failed to create decoder")?;
 
         Ok(Self { decoder, bar })
     }
 }
 
 impl<'a> Decompressor<'a> {
+    /// Whether the entire decompressed data is decompressed, not only a part of it.
+    pub fn is_complete(&self) -> bool {
+        self.decoder.offset() == 0
+            && self.decoder.offset_limit() == self.decoder.seek_table().size_decomp()
+    }
+
     pub fn decompress_into<'b: 'a, W: Write>(
         mut self,
         writer: &mut W,
         prefix: Option<&'b [u8]>,
     ) -> Result<u64> {
-        let mut buf = vec![0; DCtx::out_size()];
+        let (_, out_size) = zeekstd::recommended_decompress_buffer_sizes();
+        let mut buf = vec![0; out_size];
         let mut buf_pos = 0;
         let mut written = 0;
 
@@ -4,9 +4,11 @@ use clap::Parser;
 use command::Command;
 
 mod args;
+mod cat;
 mod command;
 mod compress;
 mod decompress;
+mod manifest;
 
 /// Compress and decompress data using the Zstandard Seekable Format.
 #[derive(Debug, Parser)]
@@ -0,0 +1,200 @@
+use std::{
+    fs::File,
+    io::{Read, Seek, SeekFrom},
+    path::Path,
+};
+
+use anyhow::{Context, Result, bail};
+use zeekstd::SeekTable;
+use zstd_safe::zstd_sys::ZSTD_MAGIC_SKIPPABLE_START;
+
+/// Magic number of the skippable frame that holds the manifest.
+const SKIPPABLE_MAGIC_NUMBER: u32 = ZSTD_MAGIC_SKIPPABLE_START | 0xD;
+/// Identifies the content of the skippable frame as a manifest.
+const MANIFEST_MAGIC_NUMBER: u32 = 0x4D46_4B5A;
+const SKIPPABLE_HEADER_SIZE: usize = 8;
+// Magic number and number of entries
+const MANIFEST_HEADER_SIZE: usize = 8;
+// Manifests are read into memory entirely, larger frames are not considered a manifest
+const MAX_MANIFEST_SIZE: u64 = 0x0400_0000;
+
+/// The name of the file at `path` in a bundle, i.e. its file name without directories.
+pub fn entry_name(path: &str) -> Result<&str> {
+    Path::new(path)
+        .file_name()
+        .and_then(|name| name.to_str())
+        .with_context(|| format!("Cannot derive a file name from {path}"))
+}
+
+/// A file of a bundle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub name: String,
+    /// The index of the first frame of the file.
+    pub frame: u32,
+    /// The offset of the file in the decompressed data.
+    pub offset: u64,
+    /// The decompressed size of the file.
+    pub size: u64,
+}
+
+/// Maps the files of a bundle to their position in the decompressed data.
+///
+/// The manifest is stored in a skippable frame after the frames of all files. The skippable frame
+/// is logged as the last frame of the seek table, with a decompressed size of zero.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Manifest {
+    entries: Vec<Entry>,
+}
+
+impl Manifest {
+    pub fn push(&mut self, entry: Entry) {
+        self.entries.push(entry);
+    }
+
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    /// Finds the file with the given name.
+    pub fn get(&self, name: &str) -> Option<&Entry> {
+        self.entries.iter().find(|e| e.name == name)
+    }
+
+    /// Serializes the manifest into a skippable frame.
+    pub fn to_frame(&self) -> Result<Vec<u8>> {
+        let mut content = Vec::new();
+        content.extend(MANIFEST_MAGIC_NUMBER.to_le_bytes());
+        content.extend(u32::try_from(self.entries.len())?.to_le_bytes());
+        for entry in &self.entries {
+            content.extend(entry.frame.to_le_bytes());
+            content.extend(entry.offset.to_le_bytes());
+            content.extend(entry.size.to_le_bytes());
+            content.extend(u32::try_from(entry.name.len())?.to_le_bytes());
+            content.extend(entry.name.as_bytes());
+        }
+
+        let mut frame = Vec::with_capacity(SKIPPABLE_HEADER_SIZE + content.len());
+        frame.extend(SKIPPABLE_MAGIC_NUMBER.to_le_bytes());
+        frame.extend(
+            u32::try_from(content.len())
+                .context("Manifest too large")?
+                .to_le_bytes(),
+        );
+        frame.extend(content);
+
+        Ok(frame)
+    }
+
+    /// Parses a manifest from a skippable frame.
+    ///
+    /// Returns `None` if `frame` is not a manifest.
+    pub fn from_frame(frame: &[u8]) -> Result<Option<Self>> {
+        let mut reader = Reader(frame);
+        if frame.len() < SKIPPABLE_HEADER_SIZE + MANIFEST_HEADER_SIZE
+            || reader.u32()? != SKIPPABLE_MAGIC_NUMBER
+            || reader.u32()? as usize != frame.len() - SKIPPABLE_HEADER_SIZE
+            || reader.u32()? != MANIFEST_MAGIC_NUMBER
+        {
+            return Ok(None);
+        }
+
+        let num_entries = reader.u32()?;
+        let mut entries = Vec::new();
+        for _ in 0..num_entries {
+            let frame = reader.u32()?;
+            let offset = reader.u64()?;
+            let size = reader.u64()?;
+            let len = reader.u32()? as usize;
+            let name = String::from_utf8(reader.take(len)?.to_vec())
+                .context("Manifest contains an invalid file name")?;
+            entries.push(Entry {
+                name,
+                frame,
+                offset,
+                size,
+            });
+        }
+
+        Ok(Some(Self { entries }))
+    }
+
+    /// Reads the manifest from the last frame of `file`.
+    ///
+    /// Returns `None` if the archive doesn't contain a manifest.
+    pub fn read(file: &mut File, seek_table: &SeekTable) -> Result<Option<Self>> {
+        let Some(last) = seek_table.num_frames().checked_sub(1) else {
+            return Ok(None);
+        };
+        let size = seek_table.frame_size_comp(last)?;
+        if seek_table.frame_size_decomp(last)? != 0 || size > MAX_MANIFEST_SIZE {
+            return Ok(None);
+        }
+
+        let mut frame = Vec::new();
+        file.seek(SeekFrom::Start(seek_table.frame_start_comp(last)?))?;
+        file.take(size).read_to_end(&mut frame)?;
+
+        Self::from_frame(&frame).context("Failed to parse manifest")
+    }
+}
+
+/// Reads little-endian values from a buffer.
+struct Reader<'a>(&'a [u8]);
+
+impl Reader<'_> {
+    fn take(&mut self, n: usize) -> Result<&[u8]> {
+        if self.0.len() < n {
+            bail!("Manifest is truncated");
+        }
+        let (head, tail) = self.0.split_at(n);
+        self.0 = tail;
+
+        Ok(head)
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into()?))
+    }
+
+    fn u64(&mut self) -> Result<u64> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entry_names() {
+        assert_eq!(entry_name("a.txt").unwrap(), "a.txt");
+        assert_eq!(entry_name("/home/u/a.txt").unwrap(), "a.txt");
+        assert_eq!(entry_name("../dir/b.txt").unwrap(), "b.txt");
+        assert!(entry_name("..").is_err());
+        assert!(entry_name("/").is_err());
+    }
+
+    #[test]
+    fn frame_cycle() {
+        let mut manifest = Manifest::default();
+        for (i, name) in ["a.txt", "", "dir/c.bin"].into_iter().enumerate() {
+            manifest.push(Entry {
+                name: name.into(),
+                frame: i as u32 * 3,
+                offset: i as u64 * 100,
+                size: 100,
+            });
+        }
+
+        let frame = manifest.to_frame().unwrap();
+        assert_eq!(Manifest::from_frame(&frame).unwrap(), Some(manifest));
+        assert!(
+            Manifest::from_frame(&frame[..frame.len() - 1])
+                .unwrap()
+                .is_none()
+        );
+        // Regular skippable frames are not a manifest
+        assert!(Manifest::from_frame(&[0; 16]).unwrap().is_none());
+    }
+}
@@ -178,6 +178,61 @@ fn cycle_with_separate_seek_table() {
     }
 }
 
+#[test]
+fn detect_separate_seek_table_format() {
+    let dir = TempDir::new().unwrap();
+    let compressed_path = dir.path().join("seekable.zst");
+    let seek_table_path = dir.path().join("seek_table");
+
+    for format in ["head", "foot"] {
+        cargo_bin_cmd!("zeekstd")
+            .arg("compress")
+            .arg(test_input())
+            .arg("--output-file")
+            .arg(&compressed_path)
+            .arg("--frame-size")
+            .arg("3K")
+            .arg("--seek-table-file")
+            .arg(&seek_table_path)
+            .arg("--seek-table-format")
+            .arg(format)
+            .arg("--force")
+            .assert()
+            .success();
+
+        cargo_bin_cmd!("zeekstd")
+            .arg("list")
+            .arg(&seek_table_path)
+            .assert()
+            .success();
+
+        let decompressed = cargo_bin_cmd!("zeekstd")
+            .arg("decompress")
+            .arg(&compressed_path)
+            .arg("--seek-table-file")
+            .arg(&seek_table_path)
+            .arg("--stdout")
+            .assert()
+            .success()
+            .get_output()
+            .stdout
+            .clone();
+        assert_eq!(fs::read(test_input()).unwrap(), decompressed);
+
+        // The format can still be specified explicitly
+        cargo_bin_cmd!("zeekstd")
+            .arg("decompress")
+            .arg(&compressed_path)
+            .arg("--seek-table-file")
+            .arg(&seek_table_path)
+            .arg("--seek-table-format")
+            .arg(format)
+            .arg("--stdout")
+            .assert()
+            .success();
+    }
+}
+
 #[test]
 fn derive_out_name_when_compressing() {
     let dir = TempDir::new().unwrap();
@@ -227,6 +282,43 @@ fn derive_out_name_when_decompressing() {
     );
 }
 
+#[test]
+fn derive_out_name_when_decompressing_multiple_extensions() {
+    let dir = TempDir::new().unwrap();
+    let mut input = NamedTempFile::new_in(dir.path()).unwrap();
+    input.write_all(b"foo").unwrap();
+
+    for (compressed, decompressed) in [
+        ("foo.txt.zst", "foo.txt"),
+        ("data.zst.zst", "data.zst"),
+        ("archive.tzst", "archive.tar"),
+        ("archive.tar.zst", "archive.tar"),
+    ] {
+        let compressed_path = dir.path().join(compressed);
+        let decompressed_path = dir.path().join(decompressed);
+
+        cargo_bin_cmd!("zeekstd")
+            .arg("compress")
+            .arg(input.path())
+            .arg("--output-file")
+            .arg(&compressed_path)
+            .assert()
+            .success();
+
+        cargo_bin_cmd!("zeekstd")
+            .arg("decompress")
+            .arg(&compressed_path)
+            .arg("--force")
+            .assert()
+            .success();
+
+        assert_eq!(
+            fs::read(input.path()).unwrap(),
+            fs::read(&decompressed_path).unwrap()
+        );
+    }
+}
+
 #[test]
 fn fail_to_derive_out_name_when_decompressing_without_extension() {
     let dir = TempDir::new().unwrap();
@@ -422,7 +514,7 @@ fn decompress_frames() {
         .stdout
         .clone();
 
-    assert_eq!(first_frame.len(), frame_size.try_into().unwrap());
+    assert_eq!(first_frame.len(), usize::try_from(frame_size).unwrap());
 
     let mut last_frames = cargo_bin_cmd!("zeekstd")
         .arg("decompress")
@@ -477,7 +569,7 @@ fn decompress_frames_separate_seek_table() {
         .stdout
         .clone();
 
-    assert_eq!(first_frame.len(), frame_size.try_into().unwrap());
+    assert_eq!(first_frame.len(), usize::try_from(frame_size).unwrap());
     assert_eq!(
         first_frame,
         fs::read(test_input()).unwrap()[0..frame_size as usize]
@@ -599,3 +691,653 @@ fn list_separate_seek_table() {
         .assert()
         .success();
 }
+
+#[test]
+fn custom_progress_template() {
+    let compressed = NamedTempFile::new().unwrap();
+
+    cargo_bin_cmd!("zeekstd")
+        .arg("compress")
+        .arg(test_input())
+        .arg("--output-file")
+        .arg(compressed.path())
+        .arg("--progress-template")
+        .arg("{wide_bar} {binary_bytes_per_sec} {eta}")
+        .arg("--force")
+        .assert()
+        .success();
+
+    // Invalid templates are rejected before anything is decompressed
+    let output = cargo_bin_cmd!("zeekstd")
+        .arg("decompress")
+        .arg(compressed.path())
+        .arg("--stdout")
+        .arg("--progress-template")
+        .arg("{pos:x}")
+        .arg("--force")
+        .assert()
+        .failure()
+        .get_output()
+        .clone();
+    assert!(output.stdout.is_empty());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--progress-template"));
+}
+
+#[test]
+fn list_head_and_tail() {
+    // Exactly 6 frames
+    let frame_size = fs::metadata(test_input()).unwrap().len() / 6 + 1;
+    let seekable = NamedTempFile::new().unwrap();
+    compress_test_input(seekable.path(), &frame_size.to_string());
+
+    let list = |args: &[&str]| {
+        let output = cargo_bin_cmd!("zeekstd")
+            .arg("list")
+            .arg(seekable.path())
+            .args(args)
+            .assert()
+            .success()
+            .get_output()
+            .stdout
+            .clone();
+        let output = String::from_utf8(output).unwrap();
+        // Skip the header line
+        output
+            .lines()
+            .skip(1)
+            .map(|l| l.split_whitespace().next().unwrap().to_string())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(list(&["--head", "2"]), ["0", "1"]);
+    assert_eq!(list(&["--tail", "2"]), ["4", "5"]);
+    assert_eq!(list(&["--head", "1", "--tail", "1"]), ["0", "...", "5"]);
+    assert_eq!(
+        list(&["--head", "4", "--tail", "4"]),
+        ["0", "1", "2", "3", "4", "5"]
+    );
+
+    cargo_bin_cmd!("zeekstd")
+        .arg("list")
+        .arg(seekable.path())
+        .args(["--head", "1", "--from-frame", "1"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn list_byte_range() {
+    // Exactly 6 frames
+    let frame_size = fs::metadata(test_input()).unwrap().len() / 6 + 1;
+    let seekable = NamedTempFile::new().unwrap();
+    compress_test_input(seekable.path(), &frame_size.to_string());
+
+    let list = |args: &[String]| {
+        let output = cargo_bin_cmd!("zeekstd")
+            .arg("list")
+            .arg(seekable.path())
+            .args(args)
+            .assert()
+            .success()
+            .get_output()
+            .stdout
+            .clone();
+        let output = String::from_utf8(output).unwrap();
+        // Skip the header line
+        output
+            .lines()
+            .skip(1)
+            .map(|l| l.split_whitespace().next().unwrap().to_string())
+            .collect::<Vec<_>>()
+    };
+    let args = |from: u64, to: &str| ["--from".into(), from.to_string(), "--to".into(), to.into()];
+
+    assert_eq!(
+        list(&args(frame_size + 10, &(3 * frame_size).to_string())),
+        ["1", "2"]
+    );
+    assert_eq!(
+        list(&args(frame_size - 1, &(3 * frame_size + 1).to_string())),
+        ["0", "1", "2", "3"]
+    );
+    assert_eq!(list(&args(4 * frame_size, "end")), ["4", "5"]);
+    assert_eq!(
+        list(&["--from".into(), (2 * frame_size).to_string()]),
+        ["2", "3", "4", "5"]
+    );
+    assert_eq!(
+        list(&[
+            "--from".into(),
+            "0".into(),
+            "--num-frames".into(),
+            "2".into()
+        ]),
+        ["0", "1"]
+    );
+
+    cargo_bin_cmd!("zeekstd")
+        .arg("list")
+        .arg(seekable.path())
+        .args(["--from", "10", "--from-frame", "1"])
+        .assert()
+        .failure();
+    cargo_bin_cmd!("zeekstd")
+        .arg("list")
+        .arg(seekable.path())
+        .args(["--to", "10", "--to-frame", "1"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn decompress_with_memory_limit() {
+    let compressed = NamedTempFile::new().unwrap();
+    compress_test_input(compressed.path(), "2M");
+
+    let decompress = |limit: &str| {
+        let output = NamedTempFile::new().unwrap();
+        let assert = cargo_bin_cmd!("zeekstd")
+            .arg("decompress")
+            .arg(compressed.path())
+            .arg("--memory-limit")
+            .arg(limit)
+            .arg("--output-file")
+            .arg(output.path())
+            .write_stdin("y")
+            .assert();
+        (assert, output)
+    };
+
+    let (assert, output) = decompress("64M");
+    assert.success();
+    assert_eq!(
+        fs::read(test_input()).unwrap(),
+        fs::read(output.path()).unwrap()
+    );
+
+    // The window of the frames doesn't fit
+    let (assert, _) = decompress("1K");
+    assert.failure();
+}
+
+#[test]
+fn dictionary_cycle() {
+    let mut dictionary = NamedTempFile::new().unwrap();
+    let input = fs::read(test_input()).unwrap();
+    dictionary.write_all(&input[..64 * 1024]).unwrap();
+
+    let plain = NamedTempFile::new().unwrap();
+    compress_test_input(plain.path(), "3K");
+    let compressed = NamedTempFile::new().unwrap();
+    cargo_bin_cmd!("zeekstd")
+        .arg("compress")
+        .arg(test_input())
+        .arg("--dictionary")
+        .arg(dictionary.path())
+        .arg("--frame-size")
+        .arg("3K")
+        .arg("--output-file")
+        .arg(compressed.path())
+        .write_stdin("y")
+        .assert()
+        .success();
+    assert!(
+        fs::metadata(compressed.path()).unwrap().len() < fs::metadata(plain.path()).unwrap().len()
+    );
+
+    let output = NamedTempFile::new().unwrap();
+    cargo_bin_cmd!("zeekstd")
+        .arg("decompress")
+        .arg(compressed.path())
+        .arg("-D")
+        .arg(dictionary.path())
+        .arg("--output-file")
+        .arg(output.path())
+        .write_stdin("y")
+        .assert()
+        .success();
+    assert_eq!(input, fs::read(output.path()).unwrap());
+
+    // Decompression fails without the dictionary
+    cargo_bin_cmd!("zeekstd")
+        .arg("decompress")
+        .arg(compressed.path())
+        .arg("--output-file")
+        .arg(output.path())
+        .write_stdin("y")
+        .assert()
+        .failure();
+
+    // A dictionary cannot be combined with a patch
+    cargo_bin_cmd!("zeekstd")
+        .arg("decompress")
+        .arg(compressed.path())
+        .arg("--dictionary")
+        .arg(dictionary.path())
+        .arg("--patch-apply")
+        .arg(dictionary.path())
+        .arg("--stdout")
+        .assert()
+        .failure();
+}
+
+#[test]
+fn list_json() {
+    let input_len = fs::metadata(test_input()).unwrap().len();
+    let seekable = NamedTempFile::new().unwrap();
+    compress_test_input(seekable.path(), &(input_len / 14).to_string());
+
+    let list = |args: &[&str]| {
+        let out = cargo_bin_cmd!("zeekstd")
+            .arg("list")
+            .arg("--json")
+            .args(args)
+            .arg(seekable.path())
+            .assert()
+            .success()
+            .get_output()
+            .stdout
+            .clone();
+        serde_json::from_slice::<serde_json::Value>(&out).unwrap()
+    };
+
+    let json = list(&[]);
+    assert_eq!(json["num_frames"], 15);
+    assert_eq!(json["size_decompressed"], input_len);
+    assert_eq!(
+        json["size_compressed"],
+        fs::metadata(seekable.path()).unwrap().len() - 15 * 8 - 17
+    );
+    let frames = json["frames"].as_array().unwrap();
+    assert_eq!(frames.len(), 15);
+    let decomp: u64 = frames.iter().map(|f| f["decomp"].as_u64().unwrap()).sum();
+    assert_eq!(decomp, input_len);
+    assert_eq!(frames[1]["decomp_offset"], frames[0]["decomp"]);
+    assert_eq!(frames[1]["comp_offset"], frames[0]["comp"]);
+
+    let all = frames.clone();
+    let json = list(&["--from-frame", "3", "--to-frame", "5"]);
+    let indices: Vec<_> = json["frames"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|f| f["index"].as_u64().unwrap())
+        .collect();
+    assert_eq!(indices, [3, 4, 5]);
+    // The summary describes the selected frames
+    assert_eq!(json["num_frames"], 3);
+    let sum = |key: &str| {
+        all[3..=5]
+            .iter()
+            .map(|f| f[key].as_u64().unwrap())
+            .sum::<u64>()
+    };
+    assert_eq!(json["size_compressed"], sum("comp"));
+    assert_eq!(json["size_decompressed"], sum("decomp"));
+}
+
+#[test]
+fn compress_with_threads() {
+    let input_len = fs::metadata(test_input()).unwrap().len();
+    for threads in ["2", "0"] {
+        let compressed = NamedTempFile::new().unwrap();
+        cargo_bin_cmd!("zeekstd")
+            .arg("compress")
+            .arg(test_input())
+            .arg("--threads")
+            .arg(threads)
+            .arg("--frame-size")
+            .arg("1M")
+            .arg("--output-file")
+            .arg(compressed.path())
+            .write_stdin("y")
+            .assert()
+            .success();
+        verify_compressed_file(compressed.path());
+
+        let out = cargo_bin_cmd!("zeekstd")
+            .arg("list")
+            .arg("--json")
+            .arg(compressed.path())
+            .assert()
+            .success()
+            .get_output()
+            .stdout
+            .clone();
+        let json = serde_json::from_slice::<serde_json::Value>(&out).unwrap();
+        assert_eq!(json["num_frames"], input_len.div_ceil(1024 * 1024));
+    }
+}
+
+#[test]
+fn cat_archives() {
+    let input = fs::read(test_input()).unwrap();
+    let (first, second) = input.split_at(input.len() / 3);
+    let dir = TempDir::new().unwrap();
+
+    let mut archives = vec![];
+    for (i, part) in [first, second].into_iter().enumerate() {
+        let plain = dir.path().join(format!("part{i}"));
+        fs::write(&plain, part).unwrap();
+        cargo_bin_cmd!("zeekstd")
+            .arg("compress")
+            .arg(&plain)
+            .arg("--frame-size")
+            .arg("64K")
+            .assert()
+            .success();
+        archives.push(dir.path().join(format!("part{i}.zst")));
+    }
+
+    let merged = dir.path().join("merged.zst");
+    cargo_bin_cmd!("zeekstd")
+        .arg("cat")
+        .args(&archives)
+        .arg("--output-file")
+        .arg(&merged)
+        .assert()
+        .success();
+
+    cargo_bin_cmd!("zeekstd")
+        .arg("decompress")
+        .arg(&merged)
+        .assert()
+        .success();
+    assert_eq!(input, fs::read(dir.path().join("merged")).unwrap());
+
+    let out = cargo_bin_cmd!("zeekstd")
+        .arg("list")
+        .arg("--json")
+        .arg(&merged)
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let json = serde_json::from_slice::<serde_json::Value>(&out).unwrap();
+    let frames = first.len().div_ceil(64 * 1024) + second.len().div_ceil(64 * 1024);
+    assert_eq!(json["num_frames"], frames);
+
+    // Frame checksums must be present in all inputs or none
+    let with_checksums = dir.path().join("checksums.zst");
+    let mut encoder = zeekstd::EncodeOptions::new()
+        .seek_table_checksum(true)
+        .into_encoder(fs::File::create(&with_checksums).unwrap())
+        .unwrap();
+    encoder.write_all(second).unwrap();
+    encoder.finish().unwrap();
+    let out = cargo_bin_cmd!("zeekstd")
+        .arg("cat")
+        .arg(&archives[0])
+        .arg(&with_checksums)
+        .arg("--output-file")
+        .arg(dir.path().join("mismatch.zst"))
+        .assert()
+        .failure()
+        .get_output()
+        .stderr
+        .clone();
+    assert!(String::from_utf8(out).unwrap().contains("frame checksums"));
+    assert!(!dir.path().join("mismatch.zst").exists());
+}
+
+#[test]
+fn bundle_files() {
+    let input = fs::read(test_input()).unwrap();
+    let (first, rest) = input.split_at(input.len() / 3);
+    let (second, third) = rest.split_at(100);
+    let dir = TempDir::new().unwrap();
+    let names = ["first.txt", "second.txt", "third.txt"];
+    for (name, part) in names.iter().zip([first, second, third]) {
+        fs::write(dir.path().join(name), part).unwrap();
+    }
+
+    cargo_bin_cmd!("zeekstd")
+        .current_dir(dir.path())
+        .arg("compress")
+        .args(names)
+        .arg("--frame-size")
+        .arg("64K")
+        .arg("-o")
+        .arg("bundle.zst")
+        .assert()
+        .success();
+
+    // Every file starts a new frame
+    let out = cargo_bin_cmd!("zeekstd")
+        .current_dir(dir.path())
+        .arg("list")
+        .arg("--raw-bytes")
+        .arg("bundle.zst")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let out = String::from_utf8(out).unwrap();
+    let second_frame = first.len().div_ceil(64 * 1024);
+    let third_frame = second_frame + 1;
+    for (name, frame, offset, size) in [
+        ("first.txt", 0, 0, first.len()),
+        ("second.txt", second_frame, first.len(), second.len()),
+        (
+            "third.txt",
+            third_frame,
+            first.len() + second.len(),
+            third.len(),
+        ),
+    ] {
+        let line = out.lines().find(|l| l.contains(name)).unwrap();
+        let fields: Vec<_> = line.split_whitespace().collect();
+        assert_eq!(
+            fields,
+            [
+                frame.to_string(),
+                size.to_string(),
+                offset.to_string(),
+                name.to_string()
+            ]
+        );
+    }
+
+    // Extract a single file by name
+    cargo_bin_cmd!("zeekstd")
+        .current_dir(dir.path())
+        .arg("decompress")
+        .arg("bundle.zst")
+        .arg("--file")
+        .arg("second.txt")
+        .arg("-o")
+        .arg("extracted.txt")
+        .assert()
+        .success();
+    assert_eq!(second, fs::read(dir.path().join("extracted.txt")).unwrap());
+
+    // The whole archive decompresses to the concatenated files
+    cargo_bin_cmd!("zeekstd")
+        .current_dir(dir.path())
+        .arg("decompress")
+        .arg("bundle.zst")
+        .assert()
+        .success();
+    assert_eq!(input, fs::read(dir.path().join("bundle")).unwrap());
+
+    cargo_bin_cmd!("zeekstd")
+        .current_dir(dir.path())
+        .arg("decompress")
+        .arg("bundle.zst")
+        .arg("--file")
+        .arg("missing.txt")
+        .arg("-o")
+        .arg("missing.txt")
+        .assert()
+        .failure();
+
+    // Bundles need an explicit output
+    cargo_bin_cmd!("zeekstd")
+        .current_dir(dir.path())
+        .arg("compress")
+        .args(names)
+        .assert()
+        .failure();
+}
+
+#[test]
+fn bundle_file_names() {
+    let dir = TempDir::new().unwrap();
+    let sub = dir.path().join("sub");
+    fs::create_dir(&sub).unwrap();
+    fs::write(dir.path().join("a.txt"), "a").unwrap();
+    fs::write(dir.path().join("b.txt"), "b").unwrap();
+    fs::write(sub.join("a.txt"), "nested a").unwrap();
+
+    // Files are recorded by their file name without directories
+    cargo_bin_cmd!("zeekstd")
+        .current_dir(&sub)
+        .args(["compress", "../a.txt"])
+        .arg(dir.path().join("b.txt"))
+        .args(["-o", "bundle.zst"])
+        .assert()
+        .success();
+    cargo_bin_cmd!("zeekstd")
+        .current_dir(&sub)
+        .args(["decompress", "bundle.zst", "--file", "b.txt", "-o", "b.txt"])
+        .assert()
+        .success();
+    assert_eq!(b"b", &fs::read(sub.join("b.txt")).unwrap()[..]);
+
+    // Names must be unique
+    cargo_bin_cmd!("zeekstd")
+        .current_dir(&sub)
+        .args(["compress", "a.txt", "../a.txt", "-o", "duplicate.zst"])
+        .assert()
+        .failure();
+    assert!(!sub.join("duplicate.zst").exists());
+}
+
+#[test]
+fn remove_input_on_success() {
+    let input = fs::read(test_input()).unwrap();
+    let dir = TempDir::new().unwrap();
+    let plain = dir.path().join("input.txt");
+    let compressed = dir.path().join("input.txt.zst");
+    fs::write(&plain, &input).unwrap();
+
+    cargo_bin_cmd!("zeekstd")
+        .arg("compress")
+        .arg(&plain)
+        .arg("--rm")
+        .assert()
+        .success();
+    assert!(!plain.exists());
+
+    cargo_bin_cmd!("zeekstd")
+        .arg("decompress")
+        .arg(&compressed)
+        .arg("--rm")
+        .assert()
+        .success();
+    assert!(!compressed.exists());
+    assert_eq!(input, fs::read(&plain).unwrap());
+
+    // Input is kept when writing to stdout
+    cargo_bin_cmd!("zeekstd")
+        .arg("compress")
+        .arg(&plain)
+        .arg("--rm")
+        .arg("--stdout")
+        .arg("--force")
+        .assert()
+        .success();
+    assert!(plain.exists());
+}
+
+#[test]
+fn keep_input_on_partial_decompression() {
+    let dir = TempDir::new().unwrap();
+    let compressed = dir.path().join("input.zst");
+    let part = dir.path().join("part.txt");
+    compress_test_input(&compressed, "64K");
+
+    for range in [
+        &["--from", "100", "--to", "200"][..],
+        &["--from-frame", "1"],
+        &["--to-frame", "0"],
+    ] {
+        cargo_bin_cmd!("zeekstd")
+            .arg("decompress")
+            .arg(&compressed)
+            .args(range)
+            .arg("--rm")
+            .arg("-o")
+            .arg(&part)
+            .assert()
+            .failure();
+        assert!(compressed.exists());
+        assert!(!part.exists());
+    }
+
+    // A single file of a bundle is only a part as well
+    fs::write(dir.path().join("a.txt"), "a").unwrap();
+    fs::write(dir.path().join("b.txt"), "b").unwrap();
+    cargo_bin_cmd!("zeekstd")
+        .current_dir(dir.path())
+        .args(["compress", "a.txt", "b.txt", "-o", "bundle.zst"])
+        .assert()
+        .success();
+    cargo_bin_cmd!("zeekstd")
+        .current_dir(dir.path())
+        .args([
+            "decompress",
+            "bundle.zst",
+            "--file",
+            "a.txt",
+            "--rm",
+            "-o",
+            "out.txt",
+        ])
+        .assert()
+        .failure();
+    assert!(dir.path().join("bundle.zst").exists());
+
+    // The whole range can be selected explicitly
+    cargo_bin_cmd!("zeekstd")
+        .arg("decompress")
+        .arg(&compressed)
+        .args(["--from-frame", "0", "--to-frame", "end", "--rm", "-o"])
+        .arg(&part)
+        .assert()
+        .success();
+    assert!(!compressed.exists());
+    assert_eq!(fs::read(test_input()).unwrap(), fs::read(&part).unwrap());
+}
+
+#[test]
+fn keep_input_on_failure() {
+    let dir = TempDir::new().unwrap();
+    let corrupted = dir.path().join("corrupted.zst");
+    fs::write(&corrupted, b"not a seekable archive").unwrap();
+
+    cargo_bin_cmd!("zeekstd")
+        .arg("decompress")
+        .arg(&corrupted)
+        .arg("--rm")
+        .assert()
+        .failure();
+    assert!(corrupted.exists());
+
+    // Decompression fails in the middle of the data
+    let compressed = dir.path().join("damaged.zst");
+    compress_test_input(&compressed, "3K");
+    let mut data = fs::read(&compressed).unwrap();
+    data[100..200].fill(0);
+    fs::write(&compressed, &data).unwrap();
+    cargo_bin_cmd!("zeekstd")
+        .arg("decompress")
+        .arg(&compressed)
+        .arg("--rm")
+        .write_stdin("y")
+        .assert()
+        .failure();
+    assert!(compressed.exists());
+}
@@ -1,6 +1,6 @@
 use criterion::{Criterion, Throughput, criterion_group, criterion_main};
 use std::{hint::black_box, io::Write};
-use zeekstd::{EncodeOptions, Encoder, RawEncoder};
+use zeekstd::{EncodeOptions, Encoder, FrameSizePolicy, RawEncoder};
 use zstd::stream::raw::Operation;
 
 const DICKENS: &[u8] = include_bytes!("../../assets/dickens.txt");
@@ -93,5 +93,120 @@ fn zstd_rs_compression(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(benches, raw_compression, compression, zstd_rs_compression,);
+#[cfg(feature = "zstdmt")]
+fn workers_compression(c: &mut Criterion) {
+    use criterion::BenchmarkId;
+
+    let mut output = vec![0; zstd_safe::CCtx::out_size()];
+    let mut group = c.benchmark_group("workers_compression");
+    group.throughput(Throughput::Bytes(DICKENS.len() as u64));
+
+    for workers in [0, 4] {
+        let mut enc = EncodeOptions::new()
+            .compression_level(1)
+            .workers(workers)
+            .into_raw_encoder()
+            .unwrap();
+        group.bench_with_input(BenchmarkId::new("dickens", workers), &workers, |b, _| {
+            b.iter(|| {
+                raw_compress(&mut enc, black_box(DICKENS), black_box(&mut output));
+            });
+        });
+    }
+
+    group.finish();
+}
+
+fn parallel_compression(c: &mut Criterion) {
+    use criterion::BenchmarkId;
+
+    let mut group = c.benchmark_group("parallel_compression");
+    group.throughput(Throughput::Bytes(DICKENS.len() as u64));
+
+    for threads in [1, 4] {
+        group.bench_with_input(
+            BenchmarkId::new("dickens", threads),
+            &threads,
+            |b, &threads| {
+                b.iter(|| {
+                    // The parallel encoder is single-use
+                    let mut enc = EncodeOptions::new()
+                        .compression_level(1)
+                        .frame_size_policy(FrameSizePolicy::Uncompressed(1024 * 1024))
+                        .into_parallel_encoder(Vec::new(), threads)
+                        .unwrap();
+                    enc.write_all(black_box(DICKENS)).unwrap();
+                    enc.finish().unwrap();
+                });
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn prefix_compression(c: &mut Criterion) {
+    let prefix = &DICKENS[..DICKENS.len() / 4];
+    let input = &DICKENS[DICKENS.len() / 4..];
+    let mut output = vec![0; zstd_safe::CCtx::out_size()];
+    let mut group = c.benchmark_group("prefix_compression");
+    group.throughput(Throughput::Bytes(input.len() as u64));
+
+    // Many small frames, every frame references the prefix
+    let opts = || {
+        EncodeOptions::new()
+            .compression_level(1)
+            .frame_size_policy(FrameSizePolicy::Uncompressed(4096))
+    };
+
+    let mut enc = opts().into_raw_encoder().unwrap();
+    group.bench_function("ref_prefix", |b| {
+        b.iter(|| {
+            let mut in_prog = 0;
+            while in_prog != input.len() {
+                let prog = enc
+                    .compress_with_prefix(
+                        black_box(&input[in_prog..]),
+                        black_box(&mut output),
+                        Some(prefix),
+                    )
+                    .unwrap();
+                in_prog += prog.in_progress();
+            }
+            while enc.end_frame(&mut output).unwrap().data_left() != 0 {}
+        });
+    });
+
+    let mut enc = opts()
+        .prefix_as_dictionary(prefix)
+        .into_raw_encoder()
+        .unwrap();
+    group.bench_function("prefix_as_dictionary", |b| {
+        b.iter(|| {
+            raw_compress(&mut enc, black_box(input), black_box(&mut output));
+        });
+    });
+
+    group.finish();
+}
+
+#[cfg(not(feature = "zstdmt"))]
+criterion_group!(
+    benches,
+    raw_compression,
+    compression,
+    zstd_rs_compression,
+    prefix_compression,
+    parallel_compression,
+);
+#[cfg(feature = "zstdmt")]
+criterion_group!(
+    benches,
+    raw_compression,
+    compression,
+    zstd_rs_compression,
+    prefix_compression,
+    parallel_compression,
+    workers_compression,
+);
 criterion_main!(benches);
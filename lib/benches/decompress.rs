@@ -1,6 +1,6 @@
 use criterion::{Criterion, Throughput, criterion_group, criterion_main};
 use std::{hint::black_box, io::Write};
-use zeekstd::{BytesWrapper, Decoder, Encoder};
+use zeekstd::{BytesWrapper, Decoder, EncodeOptions, Encoder, FrameSizePolicy};
 use zstd::stream::raw::Operation;
 
 const DICKENS: &[u8] = include_bytes!("../../assets/dickens.txt");
@@ -40,6 +40,38 @@ fn decompression(c: &mut Criterion) {
     });
 }
 
+fn range_decompression(c: &mut Criterion) {
+    let mut comp = vec![];
+    let mut enc = EncodeOptions::new()
+        .frame_size_policy(FrameSizePolicy::Uncompressed(64 * 1024))
+        .into_encoder(&mut comp)
+        .unwrap();
+    enc.write_all(DICKENS).unwrap();
+    enc.finish().unwrap();
+
+    let mut dec = Decoder::new(BytesWrapper::new(&comp)).unwrap();
+    let mut out = vec![0; DICKENS.len()];
+    let size = DICKENS.len() as u64;
+
+    let mut group = c.benchmark_group("range_decompression");
+    group.throughput(Throughput::Bytes(size));
+    group.bench_function("sequential", |b| {
+        b.iter(|| {
+            let mut pos = 0;
+            while pos < out.len() {
+                pos += dec.decompress(black_box(&mut out[pos..])).unwrap();
+            }
+            dec.reset();
+        });
+    });
+    group.bench_function("parallel", |b| {
+        b.iter(|| {
+            dec.decompress_range_parallel(0, size, black_box(&mut out))
+                .unwrap();
+        });
+    });
+}
+
 fn zstd_rs_decompress(dec: &mut zstd::stream::raw::Decoder, input: &[u8], output: &mut [u8]) {
     let mut in_prog = 0;
 
@@ -63,5 +95,10 @@ fn zstd_rs_decompression(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, decompression, zstd_rs_decompression);
+criterion_group!(
+    benches,
+    decompression,
+    range_decompression,
+    zstd_rs_decompression
+);
 criterion_main!(benches);
@@ -0,0 +1,402 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::{
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+use std::io::{self, SeekFrom};
+
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt, ReadBuf};
+use zstd_safe::{DCtx, InBuffer, OutBuffer, ResetDirective, zstd_sys::ZSTD_ErrorCode};
+
+use crate::{
+    Error, SEEK_TABLE_INTEGRITY_SIZE,
+    error::Result,
+    seek_table::{self, Format, SeekTable},
+};
+
+/// Decompresses data from an asynchronous seekable source.
+///
+/// This is the async counterpart of [`Decoder`] for sources where reading may block, e.g. objects
+/// in a remote storage. The decoder implements [`AsyncRead`] and yields whenever the source
+/// isn't ready, decompression itself happens on the polling task. Seeking with [`AsyncSeek`]
+/// sets the decompression offset, the source is repositioned on the next read.
+///
+/// Frame checksums in the seek table are not verified.
+///
+/// [`Decoder`]: crate::Decoder
+///
+/// # Examples
+///
+/// ```no_run
+/// # async fn example() -> zeekstd::Result<()> {
+/// use tokio::{fs::File, io::AsyncReadExt};
+/// use zeekstd::AsyncDecoder;
+///
+/// let seekable = File::open("seekable.zst").await?;
+/// let mut decoder = AsyncDecoder::new(seekable).await?;
+///
+/// let mut decompressed = Vec::new();
+/// decoder.read_to_end(&mut decompressed).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct AsyncDecoder<'a, R> {
+    dctx: DCtx<'a>,
+    seek_table: SeekTable,
+    src: R,
+    decomp_pos: u64,
+    offset: u64,
+    offset_limit: u64,
+    in_buf: Vec<u8>,
+    in_buf_pos: usize,
+    in_buf_limit: usize,
+    out_buf: Vec<u8>,
+    /// The source must be positioned at the start of the frame containing the offset.
+    needs_seek: bool,
+    /// A seek of the source was started but not completed yet.
+    seeking: bool,
+}
+
+impl<R: AsyncRead + AsyncSeek + Unpin> AsyncDecoder<'_, R> {
+    /// Creates a new `AsyncDecoder`, reading the seek table from the end of `src`.
+    ///
+    /// The seek table is expected in [`Foot`] format.
+    ///
+    /// [`Foot`]: Format#variant.Foot
+    ///
+    /// # Errors
+    ///
+    /// Fails if the seek table cannot be read or the decoder cannot be created.
+    pub async fn new(mut src: R) -> Result<Self> {
+        let mut integrity = [0; SEEK_TABLE_INTEGRITY_SIZE];
+        src.seek(SeekFrom::End(-i64::try_from(SEEK_TABLE_INTEGRITY_SIZE)?))
+            .await?;
+        src.read_exact(&mut integrity).await?;
+
+        let mut buf = vec![0; seek_table::foot_size(&integrity)?];
+        src.seek(SeekFrom::End(-i64::try_from(buf.len())?)).await?;
+        src.read_exact(&mut buf).await?;
+        let seek_table = SeekTable::from_bytes(&buf, Format::Foot)?;
+
+        Self::with_seek_table(src, seek_table)
+    }
+
+    /// Creates a new `AsyncDecoder` with a known seek table.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the decoder cannot be created.
+    pub fn with_seek_table(src: R, seek_table: SeekTable) -> Result<Self> {
+        let dctx =
+            DCtx::try_create().ok_or(Error::zstd(ZSTD_ErrorCode::ZSTD_error_memory_allocation))?;
+
+        Ok(Self {
+            dctx,
+            offset_limit: seek_table.size_decomp(),
+            seek_table,
+            src,
+            decomp_pos: 0,
+            offset: 0,
+            in_buf: vec![0; DCtx::in_size()],
+            in_buf_pos: 0,
+            in_buf_limit: 0,
+            out_buf: vec![0; DCtx::out_size()],
+            needs_seek: true,
+            seeking: false,
+        })
+    }
+}
+
+impl<R> AsyncDecoder<'_, R> {
+    /// Gets a reference to the internal [`SeekTable`].
+    pub fn seek_table(&self) -> &SeekTable {
+        &self.seek_table
+    }
+
+    /// Gets the current offset of this decoder.
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Gets the offset limit of this decoder.
+    pub fn offset_limit(&self) -> u64 {
+        self.offset_limit
+    }
+
+    /// Sets the decompression offset.
+    ///
+    /// The offset is the position in the _decompressed_ data at which decompression continues.
+    ///
+    /// # Errors
+    ///
+    /// When the passed offset is out of range.
+    pub fn set_offset(&mut self, offset: u64) -> Result<()> {
+        if offset > self.seek_table.size_decomp() {
+            return Err(Error::offset_out_of_range_with(
+                offset,
+                self.seek_table.size_decomp(),
+            ));
+        }
+
+        let current_frame = self.seek_table.frame_index_decomp(self.offset);
+        let target_frame = self.seek_table.frame_index_decomp(offset);
+        // Only reset if we cannot continue from previous decompression
+        if current_frame != target_frame || offset < self.offset {
+            self.reset_dctx();
+        }
+        self.offset = offset;
+
+        Ok(())
+    }
+
+    /// Sets a limit for the decompression offset.
+    ///
+    /// The limit is the position in the _decompressed_ data at which decompression stops.
+    ///
+    /// # Errors
+    ///
+    /// When the passed limit is out of range.
+    pub fn set_offset_limit(&mut self, limit: u64) -> Result<()> {
+        if limit > self.seek_table.size_decomp() {
+            return Err(Error::offset_out_of_range_with(
+                limit,
+                self.seek_table.size_decomp(),
+            ));
+        }
+        self.offset_limit = limit;
+
+        Ok(())
+    }
+
+    /// Consumes the decoder, returning the source.
+    pub fn into_inner(self) -> R {
+        self.src
+    }
+
+    fn reset_dctx(&mut self) {
+        self.needs_seek = true;
+        self.dctx
+            .reset(ResetDirective::SessionOnly)
+            .expect("Resetting session never fails");
+    }
+}
+
+impl<R: AsyncRead + AsyncSeek + Unpin> AsyncRead for AsyncDecoder<'_, R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+
+        while this.offset < this.offset_limit && buf.remaining() > 0 {
+            // Finish a seek that was interrupted, its target may be outdated
+            if this.seeking {
+                ready!(Pin::new(&mut this.src).poll_complete(cx))?;
+                this.seeking = false;
+            }
+
+            if this.needs_seek {
+                let frame_idx = this.seek_table.frame_index_decomp(this.offset);
+                let start_pos = this.seek_table.frame_start_comp(frame_idx)?;
+                Pin::new(&mut this.src).start_seek(SeekFrom::Start(start_pos))?;
+                this.needs_seek = false;
+                this.seeking = true;
+                // No dummy decompression is needed if the offset is at the start of the frame
+                this.decomp_pos = this.seek_table.frame_start_decomp(frame_idx)?;
+                // Trigger reading from src
+                this.in_buf_pos = 0;
+                this.in_buf_limit = 0;
+                continue;
+            }
+
+            let mut src_eof = false;
+            if this.in_buf_pos == this.in_buf_limit {
+                let mut read_buf = ReadBuf::new(&mut this.in_buf);
+                ready!(Pin::new(&mut this.src).poll_read(cx, &mut read_buf))?;
+                this.in_buf_pos = 0;
+                this.in_buf_limit = read_buf.filled().len();
+                src_eof = this.in_buf_limit == 0;
+            }
+
+            let mut in_buffer = InBuffer::around(&this.in_buf[this.in_buf_pos..this.in_buf_limit]);
+            let is_dummy = this.decomp_pos < this.offset;
+            let out = if is_dummy {
+                // Dummy decompression until we get to offset
+                let limit = (this.offset - this.decomp_pos).min(this.out_buf.len() as u64);
+                &mut this.out_buf[..limit as usize]
+            } else {
+                // Bytes we still need to decompress, capped at usize::MAX
+                let remaining: usize = (this.offset_limit - this.decomp_pos)
+                    .try_into()
+                    .unwrap_or(usize::MAX);
+                buf.initialize_unfilled_to(buf.remaining().min(remaining))
+            };
+            let mut out_buffer = OutBuffer::around(out);
+            this.dctx
+                .decompress_stream(&mut out_buffer, &mut in_buffer)
+                .map_err(Error::from)?;
+            let n = out_buffer.pos();
+
+            if src_eof && n == 0 {
+                // The source ended before the offset limit was reached, no further progress is
+                // possible
+                return Poll::Ready(Err(io::ErrorKind::UnexpectedEof.into()));
+            }
+
+            this.decomp_pos += n as u64;
+            this.in_buf_pos += in_buffer.pos();
+
+            if !is_dummy {
+                this.offset += n as u64;
+                buf.advance(n);
+                // Return as soon as there is output, the source may not be ready next time
+                if n > 0 {
+                    break;
+                }
+            }
+        }
+
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<R: AsyncRead + AsyncSeek + Unpin> AsyncSeek for AsyncDecoder<'_, R> {
+    fn start_seek(self: Pin<&mut Self>, position: SeekFrom) -> io::Result<()> {
+        let this = self.get_mut();
+        let offset = match position {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(n) => this.seek_table.size_decomp().checked_add_signed(n),
+            SeekFrom::Current(n) => this.offset.checked_add_signed(n),
+        };
+        let offset = offset.ok_or(io::Error::other(Error::offset_out_of_range()))?;
+
+        Ok(this.set_offset(offset)?)
+    }
+
+    fn poll_complete(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<u64>> {
+        // The source is positioned lazily on the next read
+        Poll::Ready(Ok(self.offset))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use crate::{EncodeOptions, FrameSizePolicy, tests::INPUT};
+
+    use super::*;
+
+    /// A source that is only ready on every other poll.
+    struct Yielding<R> {
+        inner: R,
+        ready: bool,
+    }
+
+    impl<R> Yielding<R> {
+        fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+            self.ready = !self.ready;
+            if self.ready {
+                Poll::Ready(())
+            } else {
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }
+    }
+
+    impl<R: AsyncRead + Unpin> AsyncRead for Yielding<R> {
+        fn poll_read(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut ReadBuf<'_>,
+        ) -> Poll<io::Result<()>> {
+            ready!(self.poll_ready(cx));
+            Pin::new(&mut self.inner).poll_read(cx, buf)
+        }
+    }
+
+    impl<R: AsyncSeek + Unpin> AsyncSeek for Yielding<R> {
+        fn start_seek(mut self: Pin<&mut Self>, position: SeekFrom) -> io::Result<()> {
+            Pin::new(&mut self.inner).start_seek(position)
+        }
+
+        fn poll_complete(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<u64>> {
+            ready!(self.poll_ready(cx));
+            Pin::new(&mut self.inner).poll_complete(cx)
+        }
+    }
+
+    fn new_seekable() -> Vec<u8> {
+        let mut seekable = vec![];
+        let mut encoder = EncodeOptions::new()
+            .frame_size_policy(FrameSizePolicy::Uncompressed(1024))
+            .into_encoder(&mut seekable)
+            .unwrap();
+        std::io::Write::write_all(&mut encoder, INPUT.as_bytes()).unwrap();
+        encoder.finish().unwrap();
+
+        seekable
+    }
+
+    #[tokio::test]
+    async fn decompress_all() {
+        let seekable = new_seekable();
+        let src = Yielding {
+            inner: Cursor::new(&seekable),
+            ready: false,
+        };
+        let mut decoder = AsyncDecoder::new(src).await.unwrap();
+        assert!(decoder.seek_table().num_frames() > 1);
+
+        let mut output = vec![];
+        decoder.read_to_end(&mut output).await.unwrap();
+        assert_eq!(output, INPUT.as_bytes());
+    }
+
+    #[tokio::test]
+    async fn seek_and_decompress() {
+        let seekable = new_seekable();
+        let mut decoder = AsyncDecoder::new(Cursor::new(&seekable)).await.unwrap();
+
+        let mut output = vec![0; 2000];
+        assert_eq!(decoder.seek(SeekFrom::Start(3000)).await.unwrap(), 3000);
+        decoder.read_exact(&mut output).await.unwrap();
+        assert_eq!(output, INPUT.as_bytes()[3000..5000]);
+
+        // Backwards in the same frame
+        decoder.seek(SeekFrom::Current(-100)).await.unwrap();
+        decoder.read_exact(&mut output[..50]).await.unwrap();
+        assert_eq!(output[..50], INPUT.as_bytes()[4900..4950]);
+
+        decoder.set_offset_limit(6000).unwrap();
+        let mut rest = vec![];
+        decoder.read_to_end(&mut rest).await.unwrap();
+        assert_eq!(rest, INPUT.as_bytes()[4950..6000]);
+
+        let end = INPUT.len() as i64;
+        assert!(decoder.seek(SeekFrom::End(1)).await.is_err());
+        assert!(decoder.seek(SeekFrom::Current(-end)).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn decompress_truncated_source() {
+        let seekable = new_seekable();
+        let st = SeekTable::from_seekable(&mut crate::BytesWrapper::new(&seekable)).unwrap();
+        // Cut off the end of the last frame, the seek table is passed separately
+        let end = st.size_comp() as usize - 4;
+        let src = Yielding {
+            inner: Cursor::new(&seekable[..end]),
+            ready: false,
+        };
+        let mut decoder = AsyncDecoder::with_seek_table(src, st).unwrap();
+
+        let mut output = vec![];
+        let err = decoder.read_to_end(&mut output).await.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+        assert!(output.len() < INPUT.len());
+        assert_eq!(output, INPUT.as_bytes()[..output.len()]);
+    }
+}
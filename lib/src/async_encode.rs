@@ -0,0 +1,285 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::{
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+use std::io;
+
+use tokio::io::AsyncWrite;
+use zstd_safe::{CCtx, zstd_sys::ZSTD_ErrorCode};
+
+use crate::{
+    EncodeOptions, Error, RawEncoder,
+    error::Result,
+    seek_table::{SeekTable, Serializer},
+};
+
+enum State {
+    Compressing,
+    EndingFrame,
+    WritingSeekTable(Serializer),
+    Finished,
+}
+
+/// A single-use seekable encoder that writes to an asynchronous writer.
+///
+/// This is the async counterpart of [`Encoder`], it implements [`AsyncWrite`] and yields whenever
+/// the writer isn't ready. Compression itself happens on the polling task. New frames are started
+/// according to the [`FrameSizePolicy`] of the [`EncodeOptions`]. Shutting the encoder down ends
+/// the current frame and writes the seek table in `Foot` format, before the writer is shut down.
+///
+/// Frames are never length prefixed, [`EncodeOptions::length_prefixed`] has no effect.
+///
+/// [`Encoder`]: crate::Encoder
+/// [`FrameSizePolicy`]: crate::FrameSizePolicy
+///
+/// # Examples
+///
+/// ```no_run
+/// # async fn example() -> std::io::Result<()> {
+/// use tokio::{fs::File, io::AsyncWriteExt};
+/// use zeekstd::AsyncEncoder;
+///
+/// let output = File::create("data.zst").await?;
+/// let mut encoder = AsyncEncoder::new(output)?;
+///
+/// encoder.write_all(b"Hello, World!").await?;
+/// // Writes the seek table
+/// encoder.shutdown().await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct AsyncEncoder<'a, W> {
+    raw: RawEncoder<'a>,
+    out_buf: Vec<u8>,
+    // Start of the data in the output buffer that wasn't written yet
+    out_buf_start: usize,
+    out_buf_pos: usize,
+    writer: W,
+    written_compressed: u64,
+    written_uncompressed: u64,
+    state: State,
+}
+
+impl<'a, W> AsyncEncoder<'a, W> {
+    /// Creates a new `AsyncEncoder` with default parameters.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the encoder could not be created.
+    pub fn new(writer: W) -> Result<Self> {
+        Self::with_opts(writer, EncodeOptions::new())
+    }
+
+    /// Creates a new `AsyncEncoder` with the given [`EncodeOptions`].
+    ///
+    /// # Errors
+    ///
+    /// Fails if the encoder could not be created.
+    pub fn with_opts(writer: W, opts: EncodeOptions<'a>) -> Result<Self> {
+        Ok(Self {
+            raw: opts.into_raw_encoder()?,
+            out_buf: vec![0; CCtx::out_size()],
+            out_buf_start: 0,
+            out_buf_pos: 0,
+            writer,
+            written_compressed: 0,
+            written_uncompressed: 0,
+            state: State::Compressing,
+        })
+    }
+}
+
+impl<W> AsyncEncoder<'_, W> {
+    /// Returns a reference to the internal [`SeekTable`].
+    pub fn seek_table(&self) -> &SeekTable {
+        self.raw.seek_table()
+    }
+
+    /// The total number of compressed bytes that have been written to the internal writer.
+    pub fn written_compressed(&self) -> u64 {
+        self.written_compressed
+    }
+
+    /// The total number of uncompressed bytes that have been consumed by this encoder.
+    pub fn written_uncompressed(&self) -> u64 {
+        self.written_uncompressed
+    }
+
+    /// Consumes the encoder, returning the writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: AsyncWrite + Unpin> AsyncEncoder<'_, W> {
+    /// Writes all buffered compressed data to the internal writer.
+    fn poll_write_out_buf(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        while self.out_buf_start < self.out_buf_pos {
+            let buf = &self.out_buf[self.out_buf_start..self.out_buf_pos];
+            let n = ready!(Pin::new(&mut self.writer).poll_write(cx, buf))?;
+            if n == 0 {
+                return Poll::Ready(Err(io::ErrorKind::WriteZero.into()));
+            }
+            self.out_buf_start += n;
+            self.written_compressed += n as u64;
+        }
+        self.out_buf_start = 0;
+        self.out_buf_pos = 0;
+
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<W: AsyncWrite + Unpin> AsyncWrite for AsyncEncoder<'_, W> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        if !matches!(this.state, State::Compressing) {
+            return Poll::Ready(Err(io::Error::other(Error::zstd(
+                ZSTD_ErrorCode::ZSTD_error_stage_wrong,
+            ))));
+        }
+
+        loop {
+            // Compress only into an empty output buffer, so no input is consumed if the writer
+            // isn't ready
+            ready!(this.poll_write_out_buf(cx))?;
+            if buf.is_empty() {
+                return Poll::Ready(Ok(0));
+            }
+
+            let prog = this.raw.compress(buf, &mut this.out_buf)?;
+            this.out_buf_pos = prog.out_progress();
+            if prog.in_progress() > 0 {
+                this.written_uncompressed += prog.in_progress() as u64;
+                return Poll::Ready(Ok(prog.in_progress()));
+            }
+            if prog.out_progress() == 0 {
+                return Poll::Ready(Ok(0));
+            }
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        ready!(this.poll_write_out_buf(cx))?;
+
+        Pin::new(&mut this.writer).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+
+        loop {
+            ready!(this.poll_write_out_buf(cx))?;
+
+            match &mut this.state {
+                State::Compressing => this.state = State::EndingFrame,
+                State::EndingFrame => {
+                    let prog = this.raw.end_frame(&mut this.out_buf)?;
+                    this.out_buf_pos = prog.out_progress();
+                    if prog.data_left() == 0 {
+                        let ser = this.raw.seek_table().clone().into_serializer()?;
+                        this.state = State::WritingSeekTable(ser);
+                    }
+                }
+                State::WritingSeekTable(ser) => {
+                    this.out_buf_pos = ser.write_into(&mut this.out_buf);
+                    if this.out_buf_pos == 0 {
+                        this.state = State::Finished;
+                    }
+                }
+                State::Finished => return Pin::new(&mut this.writer).poll_shutdown(cx),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::io::AsyncWriteExt;
+
+    use crate::{BytesWrapper, Decoder, FrameSizePolicy, tests::INPUT};
+
+    use super::*;
+
+    /// A writer that accepts at most 7 bytes per write and is only ready on every other poll.
+    struct Yielding {
+        inner: Vec<u8>,
+        ready: bool,
+    }
+
+    impl Yielding {
+        fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+            self.ready = !self.ready;
+            if self.ready {
+                Poll::Ready(())
+            } else {
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }
+    }
+
+    impl AsyncWrite for Yielding {
+        fn poll_write(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &[u8],
+        ) -> Poll<io::Result<usize>> {
+            ready!(self.poll_ready(cx));
+            let len = buf.len().min(7);
+            self.inner.extend_from_slice(&buf[..len]);
+            Poll::Ready(Ok(len))
+        }
+
+        fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            ready!(self.poll_ready(cx));
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            self.poll_flush(cx)
+        }
+    }
+
+    #[tokio::test]
+    async fn compress_and_decompress() {
+        let writer = Yielding {
+            inner: vec![],
+            ready: false,
+        };
+        let opts = EncodeOptions::new().frame_size_policy(FrameSizePolicy::Uncompressed(1024));
+        let mut encoder = AsyncEncoder::with_opts(writer, opts).unwrap();
+
+        for chunk in INPUT.as_bytes().chunks(1000) {
+            encoder.write_all(chunk).await.unwrap();
+        }
+        encoder.shutdown().await.unwrap();
+        assert_eq!(encoder.written_uncompressed(), INPUT.len() as u64);
+        assert_eq!(
+            encoder.seek_table().num_frames() as usize,
+            INPUT.len().div_ceil(1024)
+        );
+
+        // Writing after shutdown fails
+        assert!(encoder.write_all(b"Hello").await.is_err());
+
+        let written = encoder.written_compressed();
+        let seekable = encoder.into_inner().inner;
+        assert_eq!(written, seekable.len() as u64);
+
+        let mut decoder = Decoder::new(BytesWrapper::new(&seekable)).unwrap();
+        let mut output = vec![0; INPUT.len()];
+        let mut pos = 0;
+        while pos < output.len() {
+            pos += decoder.decompress(&mut output[pos..]).unwrap();
+        }
+        assert_eq!(output, INPUT.as_bytes());
+    }
+}
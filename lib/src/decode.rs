@@ -1,14 +1,50 @@
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
 use alloc::vec;
 use alloc::vec::Vec;
-use zstd_safe::{DCtx, InBuffer, OutBuffer, ResetDirective};
+use core::mem::MaybeUninit;
+use zstd_safe::{DCtx, InBuffer, OutBuffer, ResetDirective, WriteBuf, zstd_sys::ZSTD_ErrorCode};
 
 use crate::{
     Error,
     error::Result,
-    seek_table::SeekTable,
-    seekable::{OffsetFrom, Seekable},
+    seek_table::{self, ChecksumAlgo, Format, FrameHasher, SeekTable},
+    seekable::{self, ArchiveMember, OffsetFrom, Seekable, WithoutTrailing},
 };
 
+/// Returns the recommended sizes of the input and output buffers for decompression.
+///
+/// The [`Decoder`] uses an input buffer of the recommended size internally. An output buffer of
+/// the recommended size is large enough to always hold at least one complete decompressed block.
+///
+/// # Examples
+///
+/// ```
+/// let (_, out_size) = zeekstd::recommended_decompress_buffer_sizes();
+///
+/// let output = vec![0u8; out_size];
+/// ```
+pub fn recommended_decompress_buffer_sizes() -> (usize, usize) {
+    (DCtx::in_size(), DCtx::out_size())
+}
+
+/// An access to the seekable source of a [`Decoder`].
+///
+/// Passed to the observer set with [`DecodeOptions::observer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceAccess {
+    /// The offset of the source was set to `offset`.
+    Seek { offset: u64 },
+    /// `len` bytes were read from the source, starting at `offset`.
+    Read { offset: u64, len: usize },
+}
+
+/// Observes every access to the seekable source of a [`Decoder`].
+type Observer<'a> = Box<dyn FnMut(SourceAccess) + Send + 'a>;
+
+/// Called with the index of every frame a [`Decoder`] completes.
+type FrameCallback<'a> = Box<dyn FnMut(u32) + Send + 'a>;
+
 /// Options that configure how data is decompressed.
 pub struct DecodeOptions<'a, S> {
     dctx: DCtx<'a>,
@@ -18,6 +54,15 @@ pub struct DecodeOptions<'a, S> {
     offset: Option<u64>,
     upper_frame: Option<u32>,
     offset_limit: Option<u64>,
+    assume_positioned: bool,
+    trailing_bytes: u64,
+    checksum_algo: ChecksumAlgo,
+    verify_checksums: bool,
+    observer: Option<Observer<'a>>,
+    on_frame: Option<FrameCallback<'a>>,
+    dictionary: Option<&'a [u8]>,
+    prefix: Option<Box<dyn Seekable + Send + 'a>>,
+    max_decode_step: Option<usize>,
 }
 
 impl<'a, S> DecodeOptions<'a, S> {
@@ -49,6 +94,15 @@ impl<'a, S> DecodeOptions<'a, S> {
             offset: None,
             upper_frame: None,
             offset_limit: None,
+            assume_positioned: false,
+            trailing_bytes: 0,
+            checksum_algo: ChecksumAlgo::default(),
+            verify_checksums: true,
+            observer: None,
+            on_frame: None,
+            dictionary: None,
+            prefix: None,
+            max_decode_step: None,
         }
     }
 
@@ -61,7 +115,9 @@ impl<'a, S> DecodeOptions<'a, S> {
     /// Sets the [`SeekTable`] for this options.
     ///
     /// If a seek table is set, it will be used directly during decompression instead of reading
-    /// the seek table from the seekable `src`.
+    /// the seek table from the seekable `src`. The footer of `src` isn't parsed then and `src` is
+    /// never seeked relative to its end, i.e. `src` may contain only the compressed frames
+    /// without an appended seek table.
     pub fn seek_table(mut self, seek_table: SeekTable) -> Self {
         self.seek_table = Some(seek_table);
         self
@@ -100,6 +156,167 @@ impl<'a, S> DecodeOptions<'a, S> {
         self.offset_limit = Some(limit);
         self
     }
+
+    /// Sets the [`ChecksumAlgo`] that was used to compute the frame checksums in the seek table.
+    ///
+    /// The default is XXH64, as defined by the seekable format.
+    pub fn checksum_algo(mut self, algo: ChecksumAlgo) -> Self {
+        self.checksum_algo = algo;
+        self
+    }
+
+    /// Whether to verify the frame checksums in the seek table during decompression.
+    ///
+    /// Enabled by default, has no effect if the seek table doesn't contain frame checksums. A
+    /// frame is verified when decompression reaches its end, decompression fails with a checksum
+    /// mismatch error if the checksum of the decompressed data differs. Frames that are only
+    /// partially decompressed because of the offset limit are not verified.
+    pub fn verify_checksums(mut self, verify: bool) -> Self {
+        self.verify_checksums = verify;
+        self
+    }
+
+    /// Sets the number of bytes that follow the seek table at the end of `src`.
+    ///
+    /// Use this for archives with trailing data, e.g. padding or container metadata, after the
+    /// seek table. Has no effect if a seek table is set with [`Self::seek_table`].
+    pub fn seek_table_trailing_bytes(mut self, trailing_bytes: u64) -> Self {
+        self.trailing_bytes = trailing_bytes;
+        self
+    }
+
+    /// Whether `src` is already positioned at the start of the compressed data.
+    ///
+    /// If set, the decoder skips the initial seek when decompression starts at the first frame.
+    /// Any later decompression start, e.g. after changing the offset, seeks as usual. This is
+    /// useful for sources where seeking is expensive.
+    ///
+    /// Only has an effect if a seek table is set with [`Self::seek_table`], reading the seek
+    /// table from `src` moves its position.
+    pub fn assume_positioned(mut self, positioned: bool) -> Self {
+        self.assume_positioned = positioned;
+        self
+    }
+
+    /// Sets the dictionary that was used to compress the frames.
+    ///
+    /// This is the counterpart of [`EncodeOptions::dictionary`]. The dictionary is loaded into
+    /// the decompression context once and applies to all frames. A prefix set with
+    /// [`Self::prefix_seekable`] replaces the dictionary.
+    ///
+    /// [`EncodeOptions::dictionary`]: crate::EncodeOptions::dictionary
+    pub fn dictionary(mut self, dict: &'a [u8]) -> Self {
+        self.dictionary = Some(dict);
+        self
+    }
+
+    /// Sets a prefix that is referenced at the beginning of every frame, read from a seekable.
+    ///
+    /// This is the counterpart of [`RawEncoder::compress_with_prefix`] for large prefixes, e.g.
+    /// the base of a binary patch, that are not in memory. The prefix is read entirely when the
+    /// decoder is created and copied into the decompression context, where it remains for the
+    /// lifetime of the decoder. Frames can then be decompressed with [`Decoder::decompress`].
+    ///
+    /// The prefix is loaded as raw content dictionary. It must not start with the zstd
+    /// dictionary magic number, such a prefix is interpreted as zstd dictionary.
+    ///
+    /// [`RawEncoder::compress_with_prefix`]: crate::RawEncoder::compress_with_prefix
+    pub fn prefix_seekable(mut self, prefix: impl Seekable + Send + 'a) -> Self {
+        self.prefix = Some(Box::new(prefix));
+        self
+    }
+
+    /// Bounds the work done by a single call to [`Decoder::decompress_bounded`].
+    ///
+    /// A call returns after decompressing at most `step` bytes, including the bytes that are
+    /// decompressed and discarded to reach an offset in the middle of a frame. This gives control
+    /// back to the caller predictably, e.g. in an event loop. A value of 0 is treated as 1.
+    /// Unbounded by default, other decompression methods always ignore the step.
+    pub fn max_decode_step(mut self, step: usize) -> Self {
+        self.max_decode_step = Some(step.max(1));
+        self
+    }
+
+    /// Sets an observer that is called on every read from and seek in `src` during
+    /// decompression.
+    ///
+    /// This reveals the I/O pattern of the decoder, e.g. to tune `Seekable` implementations that
+    /// fetch ranges of a remote file, or to report progress on the consumed compressed data.
+    /// Accessing `src` to read the seek table is not observed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use zeekstd::{BytesWrapper, RawEncoder};
+    /// # let mut encoder = RawEncoder::new()?;
+    /// # let mut seekable = [0u8; 128];
+    /// # let prog = encoder.compress(b"Hello, World!", &mut seekable)?;
+    /// # let end_prog = encoder.end_frame(&mut seekable[prog.out_progress()..])?;
+    /// # let mut ser = encoder.into_seek_table().into_serializer()?;
+    /// # let mut n = prog.out_progress() + end_prog.out_progress();
+    /// # n += ser.write_into(&mut seekable[n..]);
+    /// # let seekable = BytesWrapper::new(&seekable[..n]);
+    /// use zeekstd::{DecodeOptions, SourceAccess};
+    ///
+    /// let mut bytes_read = 0;
+    /// let mut decoder = DecodeOptions::new(seekable)
+    ///     .observer(|access| {
+    ///         if let SourceAccess::Read { len, .. } = access {
+    ///             bytes_read += len;
+    ///         }
+    ///     })
+    ///     .into_decoder()?;
+    ///
+    /// let mut buf = [0u8; 13];
+    /// decoder.decompress(&mut buf)?;
+    /// drop(decoder);
+    /// assert!(bytes_read > 0);
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    pub fn observer(mut self, observer: impl FnMut(SourceAccess) + Send + 'a) -> Self {
+        self.observer = Some(Box::new(observer));
+        self
+    }
+
+    /// Sets a callback that is called with the frame index whenever decompression reaches the end
+    /// of a frame.
+    ///
+    /// Use this to update metrics without polling [`Decoder::current_frame`]. Frames that end
+    /// outside the decompressed range, e.g. because of the offset limit, are not reported. The
+    /// callback runs during decompression and must not block for long.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use zeekstd::{BytesWrapper, EncodeOptions, FrameSizePolicy};
+    /// # let mut encoder = EncodeOptions::new()
+    /// #     .frame_size_policy(FrameSizePolicy::Uncompressed(5))
+    /// #     .into_raw_encoder()?;
+    /// # let mut seekable = vec![];
+    /// # encoder.compress_all(b"Hello, World!", &mut seekable)?;
+    /// # let mut buf = [0u8; 128];
+    /// # let n = encoder.end_frame(&mut buf)?.out_progress();
+    /// # seekable.extend(&buf[..n]);
+    /// # let mut ser = encoder.into_seek_table().into_serializer()?;
+    /// # let n = ser.write_into(&mut buf);
+    /// # seekable.extend(&buf[..n]);
+    /// use zeekstd::DecodeOptions;
+    ///
+    /// let mut frames = Vec::new();
+    /// let mut decoder = DecodeOptions::new(BytesWrapper::new(&seekable))
+    ///     .on_frame(|index| frames.push(index))
+    ///     .into_decoder()?;
+    ///
+    /// let mut buf = [0u8; 13];
+    /// decoder.decompress(&mut buf)?;
+    /// drop(decoder);
+    /// assert_eq!(frames, [0, 1, 2]);
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    pub fn on_frame(mut self, callback: impl FnMut(u32) + Send + 'a) -> Self {
+        self.on_frame = Some(Box::new(callback));
+        self
+    }
 }
 
 impl<'a, S: Seekable> DecodeOptions<'a, S> {
@@ -113,6 +330,72 @@ impl<'a, S: Seekable> DecodeOptions<'a, S> {
     }
 }
 
+/// Statistics of a complete decompression run.
+///
+/// Returned by [`Decoder::decompress_all`].
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecodeStats {
+    frames: u32,
+    bytes_in: u64,
+    bytes_out: u64,
+    elapsed: std::time::Duration,
+}
+
+#[cfg(feature = "std")]
+impl DecodeStats {
+    /// The number of frames that were (partly) decompressed.
+    pub fn frames(&self) -> u32 {
+        self.frames
+    }
+
+    /// The number of compressed bytes that were read from the source.
+    pub fn bytes_in(&self) -> u64 {
+        self.bytes_in
+    }
+
+    /// The number of decompressed bytes that were written to the output.
+    pub fn bytes_out(&self) -> u64 {
+        self.bytes_out
+    }
+
+    /// The time it took to decompress the data.
+    pub fn elapsed(&self) -> std::time::Duration {
+        self.elapsed
+    }
+}
+
+/// The result of a salvage decompression run.
+///
+/// Returned by [`Decoder::salvage`]. Frames are listed together with the range of decompressed
+/// data they cover.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SalvageReport {
+    recovered: Vec<(u32, core::ops::Range<u64>)>,
+    lost: Vec<(u32, core::ops::Range<u64>)>,
+}
+
+#[cfg(feature = "std")]
+impl SalvageReport {
+    /// The frames that were decompressed successfully.
+    pub fn recovered(&self) -> &[(u32, core::ops::Range<u64>)] {
+        &self.recovered
+    }
+
+    /// The frames that could not be decompressed.
+    pub fn lost(&self) -> &[(u32, core::ops::Range<u64>)] {
+        &self.lost
+    }
+
+    /// Whether every frame was decompressed successfully.
+    pub fn is_complete(&self) -> bool {
+        self.lost.is_empty()
+    }
+}
+
 /// Decompresses data from a seekable source.
 ///
 /// A decoder reads compressed data from a seekable source. By default, it decompresses
@@ -130,6 +413,14 @@ pub struct Decoder<'a, S> {
     in_buf_limit: usize,
     out_buf: Vec<u8>,
     read_compressed: u64,
+    src_positioned: bool,
+    src_pos: u64,
+    checksum_algo: ChecksumAlgo,
+    hasher: Option<FrameHasher>,
+    frame_index: u32,
+    observer: Option<Observer<'a>>,
+    on_frame: Option<FrameCallback<'a>>,
+    max_decode_step: usize,
 }
 
 impl<'a, S: Seekable> Decoder<'a, S> {
@@ -150,9 +441,11 @@ impl<'a, S: Seekable> Decoder<'a, S> {
     ///
     /// Fails if the decoder cannot be created.
     pub fn with_opts(mut opts: DecodeOptions<'a, S>) -> Result<Self> {
-        let seek_table = opts
-            .seek_table
-            .map_or_else(|| SeekTable::from_seekable(&mut opts.src), Ok)?;
+        let src_positioned = opts.assume_positioned && opts.seek_table.is_some();
+        let seek_table = opts.seek_table.map_or_else(
+            || SeekTable::from_seekable_trailing(&mut opts.src, opts.trailing_bytes),
+            Ok,
+        )?;
 
         let offset = if let Some(index) = opts.lower_frame {
             seek_table.frame_start_decomp(index)?
@@ -171,6 +464,18 @@ impl<'a, S: Seekable> Decoder<'a, S> {
 
         Self::check_offset(offset_limit, &seek_table)?;
 
+        let hasher = (opts.verify_checksums && seek_table.stats().has_checksums())
+            .then(|| FrameHasher::new(opts.checksum_algo));
+
+        if let Some(dict) = opts.dictionary {
+            opts.dctx.load_dictionary(dict)?;
+        }
+        // Loading the prefix as dictionary makes it persist across frames
+        if let Some(mut prefix) = opts.prefix {
+            let prefix = seekable::read_all(prefix.as_mut())?;
+            opts.dctx.load_dictionary(&prefix)?;
+        }
+
         Ok(Self {
             dctx: opts.dctx,
             seek_table,
@@ -183,6 +488,14 @@ impl<'a, S: Seekable> Decoder<'a, S> {
             in_buf_limit: 0,
             out_buf: vec![0; DCtx::out_size()],
             read_compressed: 0,
+            src_positioned,
+            src_pos: 0,
+            checksum_algo: opts.checksum_algo,
+            hasher,
+            frame_index: 0,
+            observer: opts.observer,
+            on_frame: opts.on_frame,
+            max_decode_step: opts.max_decode_step.unwrap_or(usize::MAX),
         })
     }
 
@@ -197,17 +510,100 @@ impl<'a, S: Seekable> Decoder<'a, S> {
     /// # Errors
     ///
     /// If decompression fails or any parameter is invalid.
-    #[allow(clippy::missing_panics_doc)]
     pub fn decompress_with_prefix<'b: 'a>(
         &mut self,
         buf: &mut [u8],
         prefix: Option<&'b [u8]>,
     ) -> Result<usize> {
+        self.decompress_step(as_uninit(buf), prefix, usize::MAX)
+    }
+
+    /// Decompresses data from the internal source into a possibly uninitialized buffer.
+    ///
+    /// Works like [`Self::decompress`], but doesn't require `buf` to be initialized, which saves
+    /// zeroing the buffer before decompression. Returns the number of bytes written to `buf`.
+    ///
+    /// # Safety
+    ///
+    /// This function is safe to call. The first `n` bytes of `buf`, where `n` is the returned
+    /// value, are guaranteed to be initialized afterwards, and only those bytes may be assumed to
+    /// be initialized.
+    ///
+    /// # Errors
+    ///
+    /// If decompression fails or any parameter is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use zeekstd::{BytesWrapper, RawEncoder};
+    /// # let mut encoder = RawEncoder::new()?;
+    /// # let mut seekable = [0u8; 128];
+    /// # let prog = encoder.compress(b"Hello, World!", &mut seekable)?;
+    /// # let end_prog = encoder.end_frame(&mut seekable[prog.out_progress()..])?;
+    /// # let mut ser = encoder.into_seek_table().into_serializer()?;
+    /// # let mut n = prog.out_progress() + end_prog.out_progress();
+    /// # n += ser.write_into(&mut seekable[n..]);
+    /// # let seekable = BytesWrapper::new(&seekable[..n]);
+    /// use std::mem::MaybeUninit;
+    /// use zeekstd::Decoder;
+    ///
+    /// let mut decoder = Decoder::new(seekable)?;
+    /// let mut buf = [MaybeUninit::uninit(); 128];
+    ///
+    /// let n = decoder.decompress_uninit(&mut buf)?;
+    /// // SAFETY: The decoder initialized the first n bytes
+    /// let data = unsafe { std::slice::from_raw_parts(buf.as_ptr().cast::<u8>(), n) };
+    ///
+    /// assert_eq!(b"Hello, World!", data);
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    pub fn decompress_uninit(&mut self, buf: &mut [MaybeUninit<u8>]) -> Result<usize> {
+        self.decompress_step(buf, None, usize::MAX)
+    }
+
+    /// Decompresses data from the internal source, bounded by the step set with
+    /// [`DecodeOptions::max_decode_step`].
+    ///
+    /// Works like [`Self::decompress`], but returns after decompressing at most `step` bytes,
+    /// including the bytes that are discarded to reach the offset. If the step is exhausted by
+    /// skipping data, this returns 0 although decompression isn't finished yet. Use
+    /// [`Self::is_finished`] to check if decompression is finished.
+    ///
+    /// # Errors
+    ///
+    /// If decompression fails or any parameter is invalid.
+    pub fn decompress_bounded(&mut self, buf: &mut [u8]) -> Result<usize> {
+        self.decompress_step(as_uninit(buf), None, self.max_decode_step)
+    }
+
+    /// Decompresses into `buf` until it is full, the offset limit is reached or `max_step` bytes
+    /// (including dummy bytes) were decompressed.
+    fn decompress_step<'b: 'a>(
+        &mut self,
+        buf: &mut [MaybeUninit<u8>],
+        prefix: Option<&'b [u8]>,
+        max_step: usize,
+    ) -> Result<usize> {
+        // Nothing to decompress, don't touch the source
+        if self.offset >= self.offset_limit {
+            return Ok(0);
+        }
+
         if self.read_compressed == 0 {
             let frame_idx = self.seek_table.frame_index_decomp(self.offset);
             let start_pos = self.seek_table.frame_start_comp(frame_idx)?;
-            self.src.set_offset(OffsetFrom::Start(start_pos))?;
+            // The source can only be positioned before anything was read from it
+            if !(self.src_positioned && start_pos == 0) {
+                self.seek_src(start_pos)?;
+            }
+            self.src_positioned = false;
+            // No dummy decompression is needed if the offset is at the start of the frame
             self.decomp_pos = self.seek_table.frame_start_decomp(frame_idx)?;
+            self.frame_index = frame_idx;
+            if let Some(hasher) = &mut self.hasher {
+                hasher.reset();
+            }
             // Reference prefix at the beginning of decompression
             if let Some(pref) = prefix {
                 self.dctx.ref_prefix(pref)?;
@@ -218,34 +614,57 @@ impl<'a, S: Seekable> Decoder<'a, S> {
         }
 
         let mut output_progress = 0;
-        while self.offset < self.offset_limit && output_progress < buf.len() {
+        let mut step = 0;
+        while self.offset < self.offset_limit && output_progress < buf.len() && step < max_step {
+            let mut src_eof = false;
             if self.in_buf_pos == self.in_buf_limit {
-                self.in_buf_limit = self.src.read(&mut self.in_buf)?;
-                self.in_buf_pos = 0;
+                src_eof = self.read_src(self.in_buf.len())? == 0;
             }
 
             let mut in_buffer = InBuffer::around(&self.in_buf[self.in_buf_pos..self.in_buf_limit]);
-            let mut out_buffer = if self.decomp_pos < self.offset {
+            let mut dst = if self.decomp_pos < self.offset {
                 // Dummy decompression until we get to offset
-                let limit = (self.offset - self.decomp_pos).min(self.out_buf.len() as u64) as usize;
-                OutBuffer::around(&mut self.out_buf[..limit])
+                let limit = (self.offset - self.decomp_pos)
+                    .min(self.out_buf.len() as u64)
+                    .min((max_step - step) as u64) as usize;
+                UninitSlice::new(as_uninit(&mut self.out_buf[..limit]))
             } else {
                 // Bytes we still need to decompress, capped at usize::MAX
                 let remaining: usize = (self.offset_limit - self.decomp_pos)
                     .try_into()
                     .unwrap_or(usize::MAX);
-                let limit = buf.len().min(output_progress + remaining);
-                OutBuffer::around(&mut buf[output_progress..limit])
+                let limit = buf
+                    .len()
+                    .min(output_progress + remaining.min(max_step - step));
+                UninitSlice::new(&mut buf[output_progress..limit])
             };
+            let mut out_buffer = OutBuffer::around(&mut dst);
 
             let in_len = self.in_buf_limit - self.in_buf_pos;
-            while in_buffer.pos() < in_len && out_buffer.pos() < out_buffer.capacity() {
+            // Decompress at least once, the context may hold pending output even if there is no
+            // more input
+            loop {
+                let out_pos = out_buffer.pos();
                 let n = self
                     .dctx
                     .decompress_stream(&mut out_buffer, &mut in_buffer)?;
+                if let Some(hasher) = &mut self.hasher {
+                    hasher.update(&out_buffer.as_slice()[out_pos..]);
+                }
                 // Frame end
-                // TODO: chain when stable
                 if n == 0 {
+                    if let Some(hasher) = &mut self.hasher {
+                        let checksum = self.seek_table.frame_checksum(self.frame_index)?;
+                        if checksum.is_some_and(|c| c != hasher.checksum()) {
+                            return Err(Error::checksum_mismatch(self.frame_index));
+                        }
+                        hasher.reset();
+                    }
+                    if let Some(on_frame) = &mut self.on_frame {
+                        on_frame(self.frame_index);
+                    }
+                    self.frame_index += 1;
+
                     if let Some(pref) = prefix {
                         self.dctx
                             .reset(ResetDirective::SessionOnly)
@@ -253,9 +672,20 @@ impl<'a, S: Seekable> Decoder<'a, S> {
                         self.dctx.ref_prefix(pref)?;
                     }
                 }
+
+                if in_buffer.pos() == in_len || out_buffer.pos() == out_buffer.capacity() {
+                    break;
+                }
+            }
+
+            if src_eof && out_buffer.pos() == 0 {
+                // The source ended before the offset limit was reached, no further progress is
+                // possible
+                return Err(Error::zstd(ZSTD_ErrorCode::ZSTD_error_corruption_detected));
             }
 
             self.decomp_pos += out_buffer.pos() as u64;
+            step += out_buffer.pos();
             self.in_buf_pos += in_buffer.pos();
             self.read_compressed += in_buffer.pos() as u64;
 
@@ -270,6 +700,75 @@ impl<'a, S: Seekable> Decoder<'a, S> {
     }
 }
 
+/// Views an initialized buffer as a possibly uninitialized one.
+fn as_uninit(buf: &mut [u8]) -> &mut [MaybeUninit<u8>] {
+    // SAFETY: MaybeUninit<u8> has the same layout as u8, and only initialized bytes are ever
+    // written to the returned slice
+    unsafe { &mut *(core::ptr::from_mut::<[u8]>(buf) as *mut [MaybeUninit<u8>]) }
+}
+
+/// An output buffer for zstd that may be uninitialized and keeps track of the written bytes.
+struct UninitSlice<'b> {
+    buf: &'b mut [MaybeUninit<u8>],
+    filled: usize,
+}
+
+impl<'b> UninitSlice<'b> {
+    fn new(buf: &'b mut [MaybeUninit<u8>]) -> Self {
+        Self { buf, filled: 0 }
+    }
+}
+
+// SAFETY: `as_slice` only covers bytes that zstd has written to
+unsafe impl WriteBuf for UninitSlice<'_> {
+    fn as_slice(&self) -> &[u8] {
+        // SAFETY: The first `filled` bytes have been initialized by zstd
+        unsafe { core::slice::from_raw_parts(self.buf.as_ptr().cast::<u8>(), self.filled) }
+    }
+
+    fn capacity(&self) -> usize {
+        self.buf.len()
+    }
+
+    fn as_mut_ptr(&mut self) -> *mut u8 {
+        self.buf.as_mut_ptr().cast::<u8>()
+    }
+
+    unsafe fn filled_until(&mut self, n: usize) {
+        self.filled = n;
+    }
+}
+
+impl<S: Seekable> Decoder<'_, S> {
+    /// Sets the offset of `src` and notifies the observer.
+    fn seek_src(&mut self, pos: u64) -> Result<()> {
+        self.src_pos = self.src.set_offset(OffsetFrom::Start(pos))?;
+        if let Some(observer) = &mut self.observer {
+            observer(SourceAccess::Seek {
+                offset: self.src_pos,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Refills the input buffer with up to `len` bytes from `src` and notifies the observer.
+    fn read_src(&mut self, len: usize) -> Result<usize> {
+        let len = len.min(self.in_buf.len());
+        self.in_buf_limit = self.src.read(&mut self.in_buf[..len])?;
+        self.in_buf_pos = 0;
+        if let Some(observer) = &mut self.observer {
+            observer(SourceAccess::Read {
+                offset: self.src_pos,
+                len: self.in_buf_limit,
+            });
+        }
+        self.src_pos += self.in_buf_limit as u64;
+
+        Ok(self.in_buf_limit)
+    }
+}
+
 impl<S: Seekable> Decoder<'_, S> {
     /// Decompresses data from the internal source.
     ///
@@ -288,7 +787,7 @@ impl<S: Seekable> Decoder<'_, S> {
     /// # let mut seekable = [0u8; 128];
     /// # let prog = encoder.compress(b"Hello, World!", &mut seekable)?;
     /// # let end_prog = encoder.end_frame(&mut seekable[prog.out_progress()..])?;
-    /// # let mut ser = encoder.into_seek_table().into_serializer();
+    /// # let mut ser = encoder.into_seek_table().into_serializer()?;
     /// # let mut n = prog.out_progress() + end_prog.out_progress();
     /// # n += ser.write_into(&mut seekable[n..]);
     /// # let seekable = BytesWrapper::new(&seekable[..n]);
@@ -315,11 +814,90 @@ impl<S: Seekable> Decoder<'_, S> {
         self.decompress_with_prefix(buf, None)
     }
 
-    /// Resets the current decompresion status.
+    /// Decompresses all remaining data into `out`, skipping frames that cannot be decompressed.
     ///
-    /// This resets the internal decompression context as well as decompression offset and limit.
-    /// The next decompression operation after this function will start from the beginning of the
-    /// seekable source.
+    /// Decompresses frame by frame from the current offset until the offset limit is reached.
+    /// Frames are independent of each other, if decompression of a frame fails, it is recorded as
+    /// lost and decompression continues with the next frame. Only data of successfully
+    /// decompressed frames is written to `out`, i.e. lost frames leave no gap in the output. The
+    /// returned [`SalvageReport`] lists recovered and lost frames.
+    ///
+    /// Note that corruption can only be detected reliably if the frames contain checksums.
+    ///
+    /// # Errors
+    ///
+    /// If reading from the source or writing to `out` fails. Frames are only recorded as lost if
+    /// they cannot be decompressed, IO errors of the source are returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::fs::File;
+    /// use zeekstd::Decoder;
+    ///
+    /// let seekable = File::open("damaged.zst")?;
+    /// let mut output = File::create("recovered")?;
+    /// let mut decoder = Decoder::new(seekable)?;
+    /// let report = decoder.salvage(&mut output)?;
+    ///
+    /// for (index, range) in report.lost() {
+    ///     eprintln!("Lost frame {index} ({range:?})");
+    /// }
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn salvage(&mut self, out: &mut impl std::io::Write) -> Result<SalvageReport> {
+        let mut report = SalvageReport::default();
+        let (start, end) = (self.offset, self.offset_limit);
+        if start >= end {
+            return Ok(report);
+        }
+
+        let first = self.seek_table.frame_index_decomp(start);
+        let last = self.seek_table.frame_index_decomp(end - 1);
+        let mut buf = vec![];
+        for index in first..=last {
+            let range = start.max(self.seek_table.frame_start_decomp(index)?)
+                ..end.min(self.seek_table.frame_end_decomp(index)?);
+            buf.resize(usize::try_from(range.end - range.start)?, 0);
+            self.set_offset(range.start)?;
+            self.set_offset_limit(range.end)?;
+
+            match self.decompress_frame_into(&mut buf) {
+                Ok(()) => {
+                    out.write_all(&buf)?;
+                    report.recovered.push((index, range));
+                }
+                Err(err) => {
+                    // The decompression context is in an undefined state
+                    self.reset_dctx();
+                    if err.is_io() {
+                        self.offset_limit = end;
+                        return Err(err);
+                    }
+                    report.lost.push((index, range));
+                }
+            }
+        }
+
+        self.offset_limit = end;
+        self.set_offset(end)?;
+
+        Ok(report)
+    }
+
+    /// Validates the integrity of the entire archive.
+    ///
+    /// Decompresses all frames and discards the output. Fails with the first error, e.g. if a
+    /// frame cannot be decompressed, its checksum in the seek table does not match, or the
+    /// decompressed size differs from the seek table. Checksums are verified even if disabled
+    /// with [`DecodeOptions::verify_checksums`]. The decoder is [reset](Self::reset) before and
+    /// after validation.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the archive is invalid or reading from the source fails.
     ///
     /// # Examples
     ///
@@ -329,41 +907,377 @@ impl<S: Seekable> Decoder<'_, S> {
     /// # let mut seekable = [0u8; 128];
     /// # let prog = encoder.compress(b"Hello, World!", &mut seekable)?;
     /// # let end_prog = encoder.end_frame(&mut seekable[prog.out_progress()..])?;
-    /// # let mut ser = encoder.into_seek_table().into_serializer();
+    /// # let mut ser = encoder.into_seek_table().into_serializer()?;
     /// # let mut n = prog.out_progress() + end_prog.out_progress();
     /// # n += ser.write_into(&mut seekable[n..]);
     /// # let seekable = BytesWrapper::new(&seekable[..n]);
     /// use zeekstd::Decoder;
     ///
     /// let mut decoder = Decoder::new(seekable)?;
-    /// decoder.reset();
-    ///
-    /// assert_eq!(decoder.read_compressed(), 0);
-    /// assert_eq!(decoder.offset(), 0);
-    /// assert_eq!(decoder.offset_limit(), decoder.seek_table().size_decomp());
+    /// decoder.validate()?;
     /// # Ok::<(), zeekstd::Error>(())
     /// ```
-    pub fn reset(&mut self) {
-        self.reset_dctx();
-        self.offset = 0;
-        self.offset_limit = self.seek_table().size_decomp();
+    pub fn validate(&mut self) -> Result<()> {
+        let verify = self.hasher.is_none() && self.seek_table.stats().has_checksums();
+        if verify {
+            self.hasher = Some(FrameHasher::new(self.checksum_algo));
+        }
+
+        self.reset();
+        let mut buf = vec![0; DCtx::out_size()];
+        let mut written = 0;
+        let res = loop {
+            match self.decompress(&mut buf) {
+                Ok(0) if written == self.seek_table.size_decomp() => break Ok(()),
+                Ok(0) => break Err(Error::zstd(ZSTD_ErrorCode::ZSTD_error_corruption_detected)),
+                Ok(n) => written += n as u64,
+                Err(e) => break Err(e),
+            }
+        };
+
+        if verify {
+            self.hasher = None;
+        }
+        self.reset();
+
+        res
     }
 
-    fn reset_dctx(&mut self) {
-        self.read_compressed = 0;
-        self.dctx
-            .reset(ResetDirective::SessionOnly)
-            .expect("Resetting session never fails");
+    /// Fills `buf` entirely with decompressed data.
+    #[cfg(feature = "std")]
+    fn decompress_frame_into(&mut self, buf: &mut [u8]) -> Result<()> {
+        let mut pos = 0;
+        while pos < buf.len() {
+            let n = self.decompress(&mut buf[pos..])?;
+            if n == 0 {
+                return Err(Error::zstd(ZSTD_ErrorCode::ZSTD_error_corruption_detected));
+            }
+            pos += n;
+        }
+
+        Ok(())
     }
 
-    /// Sets the decompression offset to the beginning of the frame at `index`.
+    /// Decompresses all remaining data into `out`.
     ///
-    /// This has the same effect as calling [`Self::set_offset`] with the decompressed start
-    /// position of the frame at `index`.
+    /// Decompresses from the current offset until the offset limit is reached and returns
+    /// [`DecodeStats`] about this run.
     ///
     /// # Errors
     ///
-    /// When the the passed frame index is out of range.
+    /// If decompression fails or writing to `out` fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use zeekstd::{BytesWrapper, RawEncoder};
+    /// # let mut encoder = RawEncoder::new()?;
+    /// # let mut seekable = [0u8; 128];
+    /// # let prog = encoder.compress(b"Hello, World!", &mut seekable)?;
+    /// # let end_prog = encoder.end_frame(&mut seekable[prog.out_progress()..])?;
+    /// # let mut ser = encoder.into_seek_table().into_serializer()?;
+    /// # let mut n = prog.out_progress() + end_prog.out_progress();
+    /// # n += ser.write_into(&mut seekable[n..]);
+    /// # let seekable = BytesWrapper::new(&seekable[..n]);
+    /// use zeekstd::Decoder;
+    ///
+    /// let mut decoder = Decoder::new(seekable)?;
+    /// let mut output = vec![];
+    /// let stats = decoder.decompress_all(&mut output)?;
+    ///
+    /// assert_eq!(stats.frames(), 1);
+    /// assert_eq!(stats.bytes_out(), 13);
+    /// assert_eq!(b"Hello, World!", output.as_slice());
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn decompress_all(&mut self, out: &mut impl std::io::Write) -> Result<DecodeStats> {
+        let start = std::time::Instant::now();
+        let start_offset = self.offset;
+        let start_read = self.read_compressed;
+        let mut buf = vec![0; DCtx::out_size()];
+
+        loop {
+            let n = self.decompress(&mut buf)?;
+            if n == 0 {
+                break;
+            }
+            out.write_all(&buf[..n])?;
+        }
+
+        let bytes_out = self.offset - start_offset;
+        let frames = if bytes_out == 0 {
+            0
+        } else {
+            self.seek_table.frame_index_decomp(self.offset - 1)
+                - self.seek_table.frame_index_decomp(start_offset)
+                + 1
+        };
+
+        Ok(DecodeStats {
+            frames,
+            bytes_in: self.read_compressed - start_read,
+            bytes_out,
+            elapsed: start.elapsed(),
+        })
+    }
+
+    /// Decompresses up to `n` frames into `out`.
+    ///
+    /// Decompresses from the current offset until the end of the `n`th frame or until the offset
+    /// limit is reached, whichever comes first. A frame that has already been decompressed
+    /// partially counts as one frame. Returns the number of frames that were decompressed, which
+    /// is 0 if nothing is left to decompress. Calling this repeatedly pages through the frames of
+    /// an archive.
+    ///
+    /// # Errors
+    ///
+    /// If decompression fails or writing to `out` fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::fs::File;
+    /// use zeekstd::Decoder;
+    ///
+    /// let seekable = File::open("records.zst")?;
+    /// let mut decoder = Decoder::new(seekable)?;
+    /// let mut page = vec![];
+    ///
+    /// // Decompress 10 frames at a time
+    /// while decoder.decompress_n_frames(10, &mut page)? > 0 {
+    ///     page.clear();
+    /// }
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn decompress_n_frames(&mut self, n: u32, out: &mut impl std::io::Write) -> Result<u32> {
+        let limit = self.offset_limit;
+        if n == 0 || self.offset >= limit {
+            return Ok(0);
+        }
+
+        let first = self.seek_table.frame_index_decomp(self.offset);
+        let last = self
+            .seek_table
+            .frame_index_decomp(limit - 1)
+            .min(first.saturating_add(n - 1));
+        self.offset_limit = limit.min(self.seek_table.frame_end_decomp(last)?);
+
+        let mut buf = vec![0; DCtx::out_size()];
+        let res = loop {
+            match self.decompress(&mut buf) {
+                Ok(0) => break Ok(last - first + 1),
+                Ok(n) => {
+                    if let Err(e) = out.write_all(&buf[..n]) {
+                        break Err(e.into());
+                    }
+                }
+                Err(e) => break Err(e),
+            }
+        };
+        self.offset_limit = limit;
+
+        res
+    }
+
+    /// Decompresses the entire frame at `index` into `buf`.
+    ///
+    /// Returns the number of bytes written to `buf`, which is the decompressed size of the frame.
+    /// Decompression starts at the beginning of the frame, no data has to be skipped. Afterwards,
+    /// the decompression offset is at the end of the frame, the offset limit remains unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the frame index is out of range, `buf` is smaller than the decompressed size of
+    /// the frame or decompression fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use zeekstd::{BytesWrapper, RawEncoder};
+    /// # let mut encoder = RawEncoder::new()?;
+    /// # let mut seekable = [0u8; 128];
+    /// # let prog = encoder.compress(b"Hello, World!", &mut seekable)?;
+    /// # let end_prog = encoder.end_frame(&mut seekable[prog.out_progress()..])?;
+    /// # let mut ser = encoder.into_seek_table().into_serializer()?;
+    /// # let mut n = prog.out_progress() + end_prog.out_progress();
+    /// # n += ser.write_into(&mut seekable[n..]);
+    /// # let seekable = BytesWrapper::new(&seekable[..n]);
+    /// use zeekstd::Decoder;
+    ///
+    /// let mut decoder = Decoder::new(seekable)?;
+    /// let mut buf = [0u8; 128];
+    /// let n = decoder.decompress_frame(0, &mut buf)?;
+    ///
+    /// assert_eq!(b"Hello, World!", &buf[..n]);
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    pub fn decompress_frame(&mut self, index: u32, buf: &mut [u8]) -> Result<usize> {
+        let start = self.seek_table.frame_start_decomp(index)?;
+        let end = self.seek_table.frame_end_decomp(index)?;
+        let size = usize::try_from(end - start)?;
+        if buf.len() < size {
+            return Err(Error::zstd(ZSTD_ErrorCode::ZSTD_error_dstSize_tooSmall));
+        }
+
+        let limit = self.offset_limit;
+        self.set_offset(start)?;
+        self.offset_limit = end;
+        let mut pos = 0;
+        let res = loop {
+            match self.decompress(&mut buf[pos..size]) {
+                Ok(0) => break Ok(pos),
+                Ok(n) => pos += n,
+                Err(e) => break Err(e),
+            }
+        };
+        self.offset_limit = limit;
+
+        res
+    }
+
+    /// Verifies the integrity of the frame at `index`.
+    ///
+    /// The frame is decompressed into an internal buffer and the output is discarded. Returns
+    /// `false` if the frame cannot be decompressed, its size does not match the seek table, or a
+    /// checksum does not match. Both the content checksum of the zstd frame and the frame
+    /// checksum in the seek table are verified, if present. This is much cheaper than verifying
+    /// the whole archive, e.g. to spot-check specific frames.
+    ///
+    /// The decompression offset and limit remain unchanged, but the next decompression starts
+    /// anew at the current offset.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the frame index is out of range or reading from the source fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use zeekstd::{BytesWrapper, RawEncoder};
+    /// # let mut encoder = RawEncoder::new()?;
+    /// # let mut seekable = [0u8; 128];
+    /// # let prog = encoder.compress(b"Hello, World!", &mut seekable)?;
+    /// # let end_prog = encoder.end_frame(&mut seekable[prog.out_progress()..])?;
+    /// # let mut ser = encoder.into_seek_table().into_serializer()?;
+    /// # let mut n = prog.out_progress() + end_prog.out_progress();
+    /// # n += ser.write_into(&mut seekable[n..]);
+    /// # let seekable = BytesWrapper::new(&seekable[..n]);
+    /// use zeekstd::Decoder;
+    ///
+    /// let mut decoder = Decoder::new(seekable)?;
+    ///
+    /// assert!(decoder.verify_frame(0)?);
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    pub fn verify_frame(&mut self, index: u32) -> Result<bool> {
+        let start = self.seek_table.frame_start_comp(index)?;
+        let size_comp = self.seek_table.frame_size_comp(index)?;
+        let size_decomp = self.seek_table.frame_size_decomp(index)?;
+        let mut hasher = self
+            .seek_table
+            .frame_checksum(index)?
+            .map(|checksum| (checksum, FrameHasher::new(self.checksum_algo)));
+
+        // Decompression continues at the current offset afterwards
+        self.reset_dctx();
+        self.seek_src(start)?;
+        self.in_buf_pos = 0;
+        self.in_buf_limit = 0;
+
+        let mut read = 0;
+        let mut written = 0;
+        loop {
+            if self.in_buf_pos == self.in_buf_limit {
+                let remaining = usize::try_from(size_comp - read).unwrap_or(usize::MAX);
+                if self.read_src(remaining)? == 0 {
+                    // The frame is truncated or larger than recorded in the seek table
+                    self.reset_dctx();
+                    return Ok(false);
+                }
+                read += self.in_buf_limit as u64;
+            }
+
+            let mut in_buffer = InBuffer::around(&self.in_buf[self.in_buf_pos..self.in_buf_limit]);
+            let mut out_buffer = OutBuffer::around(&mut self.out_buf[..]);
+            let res = self.dctx.decompress_stream(&mut out_buffer, &mut in_buffer);
+            self.in_buf_pos += in_buffer.pos();
+            written += out_buffer.pos() as u64;
+            if let Some((_, hasher)) = &mut hasher {
+                hasher.update(out_buffer.as_slice());
+            }
+
+            match res {
+                // Frame end
+                Ok(0) => break,
+                Ok(_) => {}
+                Err(_) => {
+                    self.reset_dctx();
+                    return Ok(false);
+                }
+            }
+        }
+
+        self.reset_dctx();
+        let size_matches = read == size_comp && self.in_buf_pos == self.in_buf_limit;
+
+        Ok(size_matches
+            && written == size_decomp
+            && hasher.is_none_or(|(checksum, hasher)| checksum == hasher.checksum()))
+    }
+
+    /// Resets the current decompresion status.
+    ///
+    /// This resets the internal decompression context as well as decompression offset and limit.
+    /// The next decompression operation after this function will start from the beginning of the
+    /// seekable source.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use zeekstd::{BytesWrapper, RawEncoder};
+    /// # let mut encoder = RawEncoder::new()?;
+    /// # let mut seekable = [0u8; 128];
+    /// # let prog = encoder.compress(b"Hello, World!", &mut seekable)?;
+    /// # let end_prog = encoder.end_frame(&mut seekable[prog.out_progress()..])?;
+    /// # let mut ser = encoder.into_seek_table().into_serializer()?;
+    /// # let mut n = prog.out_progress() + end_prog.out_progress();
+    /// # n += ser.write_into(&mut seekable[n..]);
+    /// # let seekable = BytesWrapper::new(&seekable[..n]);
+    /// use zeekstd::Decoder;
+    ///
+    /// let mut decoder = Decoder::new(seekable)?;
+    /// decoder.reset();
+    ///
+    /// assert_eq!(decoder.read_compressed(), 0);
+    /// assert_eq!(decoder.offset(), 0);
+    /// assert_eq!(decoder.offset_limit(), decoder.seek_table().size_decomp());
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    pub fn reset(&mut self) {
+        self.reset_dctx();
+        self.offset = 0;
+        self.offset_limit = self.seek_table().size_decomp();
+    }
+
+    fn reset_dctx(&mut self) {
+        self.read_compressed = 0;
+        self.dctx
+            .reset(ResetDirective::SessionOnly)
+            .expect("Resetting session never fails");
+    }
+
+    /// Sets the decompression offset to the beginning of the frame at `index`.
+    ///
+    /// This has the same effect as calling [`Self::set_offset`] with the decompressed start
+    /// position of the frame at `index`.
+    ///
+    /// # Errors
+    ///
+    /// When the the passed frame index is out of range.
     pub fn set_lower_frame(&mut self, index: u32) -> Result<u64> {
         let offset = self.seek_table.frame_start_decomp(index)?;
         self.set_offset(offset)?;
@@ -424,7 +1338,7 @@ impl<S: Seekable> Decoder<'_, S> {
     ///
     /// **Note**: The decoder will immediately stop decompression at the specified limit. The
     /// frame checksum of the last decompressed frame will not be verified, if the limit isn't at
-    /// the end of a frame.
+    /// the end of a frame. Use [`Self::set_offset_limit_frame_aligned`] to verify it.
     ///
     /// # Errors
     ///
@@ -436,9 +1350,127 @@ impl<S: Seekable> Decoder<'_, S> {
         Ok(())
     }
 
+    /// Sets a limit for the decompression offset at the end of the frame that contains `limit`.
+    ///
+    /// Works like [`Self::set_offset_limit`], but rounds the limit up to the next frame boundary,
+    /// so the last frame is decompressed entirely and its checksum is verified. A limit that
+    /// already is at the end of a frame is kept. Returns the limit that is actually used.
+    ///
+    /// # Errors
+    ///
+    /// When the passed limit is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use zeekstd::{BytesWrapper, EncodeOptions, FrameSizePolicy};
+    /// # let mut encoder = EncodeOptions::new()
+    /// #     .frame_size_policy(FrameSizePolicy::Uncompressed(100))
+    /// #     .into_raw_encoder()?;
+    /// # let mut seekable = vec![];
+    /// # encoder.compress_all(&[0; 250], &mut seekable)?;
+    /// # let mut buf = [0u8; 128];
+    /// # let n = encoder.end_frame(&mut buf)?.out_progress();
+    /// # seekable.extend(&buf[..n]);
+    /// # let mut ser = encoder.into_seek_table().into_serializer()?;
+    /// # let n = ser.write_into(&mut buf);
+    /// # seekable.extend(&buf[..n]);
+    /// use zeekstd::Decoder;
+    ///
+    /// // Frames of 100 bytes
+    /// let mut decoder = Decoder::new(BytesWrapper::new(&seekable))?;
+    /// assert_eq!(200, decoder.set_offset_limit_frame_aligned(150)?);
+    /// assert_eq!(100, decoder.set_offset_limit_frame_aligned(100)?);
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    pub fn set_offset_limit_frame_aligned(&mut self, limit: u64) -> Result<u64> {
+        Self::check_offset(limit, self.seek_table())?;
+        // A limit at the start of a frame is the end of the previous frame
+        let aligned = match limit.checked_sub(1) {
+            Some(last) => {
+                let index = self.seek_table().frame_index_decomp(last);
+                self.seek_table().frame_end_decomp(index)?
+            }
+            None => 0,
+        };
+        self.offset_limit = aligned;
+
+        Ok(aligned)
+    }
+
+    /// Decompresses data starting at `offset` into `buf`, then restores the offset of the
+    /// decoder.
+    ///
+    /// Works like `pread`: fills `buf` with decompressed data from `offset` until `buf` is full
+    /// or the end of the decompressed data is reached, and returns the number of bytes written.
+    /// The offset limit of the decoder is ignored. Afterwards, offset and offset limit are
+    /// restored, so the next call to [`Self::decompress`] returns the data at the previous
+    /// offset.
+    ///
+    /// The decompression state is not restored, this takes `&mut self` because the decompression
+    /// context and the source are used to decompress the requested data. The next decompression
+    /// continues from the state left behind by this call, which usually means that the frame of
+    /// the previous offset is decompressed from its start again. [`Self::read_compressed`]
+    /// reflects that state as well.
+    ///
+    /// # Errors
+    ///
+    /// If `offset` is out of range or decompression fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use zeekstd::{BytesWrapper, RawEncoder};
+    /// # let mut encoder = RawEncoder::new()?;
+    /// # let mut seekable = [0u8; 128];
+    /// # let prog = encoder.compress(b"Hello, World!", &mut seekable)?;
+    /// # let end_prog = encoder.end_frame(&mut seekable[prog.out_progress()..])?;
+    /// # let mut ser = encoder.into_seek_table().into_serializer()?;
+    /// # let mut n = prog.out_progress() + end_prog.out_progress();
+    /// # n += ser.write_into(&mut seekable[n..]);
+    /// # let seekable = BytesWrapper::new(&seekable[..n]);
+    /// use zeekstd::Decoder;
+    ///
+    /// let mut decoder = Decoder::new(seekable)?;
+    /// let mut buf = [0u8; 5];
+    ///
+    /// let n = decoder.read_at(7, &mut buf)?;
+    ///
+    /// assert_eq!(b"World", &buf[..n]);
+    /// assert_eq!(0, decoder.offset());
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    pub fn read_at(&mut self, offset: u64, buf: &mut [u8]) -> Result<usize> {
+        Self::check_offset(offset, self.seek_table())?;
+        let (prev_offset, prev_limit) = (self.offset, self.offset_limit);
+
+        self.set_offset(offset)?;
+        self.offset_limit = self.seek_table.size_decomp();
+        let mut progress = 0;
+        let res = loop {
+            match self.decompress(&mut buf[progress..]) {
+                Ok(0) => break Ok(progress),
+                Ok(n) => progress += n,
+                Err(e) => break Err(e),
+            }
+        };
+
+        // The decompression context is in an unknown state after an error
+        if res.is_err() {
+            self.reset_dctx();
+        }
+        self.set_offset(prev_offset)?;
+        self.offset_limit = prev_limit;
+
+        res
+    }
+
     fn check_offset(offset: u64, seek_table: &SeekTable) -> Result<()> {
         if offset > seek_table.size_decomp() {
-            Err(Error::offset_out_of_range())
+            Err(Error::offset_out_of_range_with(
+                offset,
+                seek_table.size_decomp(),
+            ))
         } else {
             Ok(())
         }
@@ -459,14 +1491,496 @@ impl<S: Seekable> Decoder<'_, S> {
         self.offset
     }
 
+    /// Gets the index of the frame that contains the current offset.
+    ///
+    /// At the end of the decompressed data, this is the index of the last frame.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use zeekstd::{BytesWrapper, EncodeOptions, FrameSizePolicy};
+    /// # let mut encoder = EncodeOptions::new()
+    /// #     .frame_size_policy(FrameSizePolicy::Uncompressed(5))
+    /// #     .into_raw_encoder()?;
+    /// # let mut seekable = vec![];
+    /// # encoder.compress_all(b"Hello, World!", &mut seekable)?;
+    /// # let mut buf = [0u8; 128];
+    /// # let n = encoder.end_frame(&mut buf)?.out_progress();
+    /// # seekable.extend(&buf[..n]);
+    /// # let mut ser = encoder.into_seek_table().into_serializer()?;
+    /// # let n = ser.write_into(&mut buf);
+    /// # seekable.extend(&buf[..n]);
+    /// use zeekstd::Decoder;
+    ///
+    /// let mut decoder = Decoder::new(BytesWrapper::new(&seekable))?;
+    /// decoder.set_offset(7)?;
+    ///
+    /// assert_eq!(1, decoder.current_frame());
+    /// assert_eq!(3, decoder.num_frames());
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    pub fn current_frame(&self) -> u32 {
+        self.seek_table.frame_index_decomp(self.offset)
+    }
+
+    /// Gets the number of frames in the seek table.
+    pub fn num_frames(&self) -> u32 {
+        self.seek_table.num_frames()
+    }
+
     /// Gets the offset limit of this decoder.
     pub fn offset_limit(&self) -> u64 {
         self.offset_limit
     }
+
+    /// Whether the offset reached the offset limit, i.e. there is nothing left to decompress.
+    pub fn is_finished(&self) -> bool {
+        self.offset >= self.offset_limit
+    }
+
+    /// Gets the [`ChecksumAlgo`] of the frame checksums in the seek table.
+    pub fn checksum_algo(&self) -> ChecksumAlgo {
+        self.checksum_algo
+    }
 }
 
-/// Allows to read decompressed data from a `Decoder`.
-///
+impl<'a, S: Seekable> Decoder<'a, S> {
+    /// Returns an iterator over the decompressed data in chunks of `size` bytes.
+    ///
+    /// Every chunk has exactly `size` bytes, independent of frame boundaries, except the last
+    /// chunk which may be shorter. Decompression starts at the current offset and stops at the
+    /// offset limit. The iterator ends after the first error.
+    ///
+    /// # Panics
+    ///
+    /// If `size` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use zeekstd::{BytesWrapper, RawEncoder};
+    /// # let mut encoder = RawEncoder::new()?;
+    /// # let mut seekable = [0u8; 128];
+    /// # let prog = encoder.compress(b"Hello, World!", &mut seekable)?;
+    /// # let end_prog = encoder.end_frame(&mut seekable[prog.out_progress()..])?;
+    /// # let mut ser = encoder.into_seek_table().into_serializer()?;
+    /// # let mut n = prog.out_progress() + end_prog.out_progress();
+    /// # n += ser.write_into(&mut seekable[n..]);
+    /// # let seekable = BytesWrapper::new(&seekable[..n]);
+    /// use zeekstd::Decoder;
+    ///
+    /// let mut decoder = Decoder::new(seekable)?;
+    /// let chunks = decoder.chunks(5).collect::<Result<Vec<_>, _>>()?;
+    ///
+    /// assert_eq!(chunks, [&b"Hello"[..], b", Wor", b"ld!"]);
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    pub fn chunks(&mut self, size: usize) -> Chunks<'_, 'a, S> {
+        assert!(size != 0, "chunk size must be non-zero");
+
+        Chunks {
+            decoder: self,
+            size,
+            done: false,
+        }
+    }
+
+    /// Returns an iterator over the decompressed frames.
+    ///
+    /// Every item holds the complete decompressed data of one frame. The iterator yields the
+    /// frames that overlap the range between the current offset and the offset limit, starting
+    /// at the frame that contains the offset. The iterator ends after the first error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use zeekstd::{BytesWrapper, RawEncoder};
+    /// # let mut encoder = RawEncoder::new()?;
+    /// # let mut seekable = [0u8; 128];
+    /// # let mut n = 0;
+    /// # for data in [&b"Hello, "[..], b"World!"] {
+    /// #     n += encoder.compress(data, &mut seekable[n..])?.out_progress();
+    /// #     n += encoder.end_frame(&mut seekable[n..])?.out_progress();
+    /// # }
+    /// # let mut ser = encoder.into_seek_table().into_serializer()?;
+    /// # n += ser.write_into(&mut seekable[n..]);
+    /// # let seekable = BytesWrapper::new(&seekable[..n]);
+    /// use zeekstd::Decoder;
+    ///
+    /// let mut decoder = Decoder::new(seekable)?;
+    /// let frames = decoder.frames().collect::<Result<Vec<_>, _>>()?;
+    ///
+    /// assert_eq!(frames, [&b"Hello, "[..], b"World!"]);
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    pub fn frames(&mut self) -> FrameDecoder<'_, 'a, S> {
+        let (next, end) = if self.offset < self.offset_limit {
+            (
+                self.seek_table.frame_index_decomp(self.offset),
+                self.seek_table.frame_index_decomp(self.offset_limit - 1) + 1,
+            )
+        } else {
+            (0, 0)
+        };
+
+        FrameDecoder {
+            decoder: self,
+            next,
+            end,
+        }
+    }
+}
+
+/// An iterator over the decompressed data of a [`Decoder`] in fixed size chunks.
+///
+/// Created by [`Decoder::chunks`].
+pub struct Chunks<'d, 'a, S> {
+    decoder: &'d mut Decoder<'a, S>,
+    size: usize,
+    done: bool,
+}
+
+impl<S: Seekable> Iterator for Chunks<'_, '_, S> {
+    type Item = Result<Vec<u8>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let mut chunk = vec![0; self.size];
+        let mut filled = 0;
+        while filled < self.size {
+            match self.decoder.decompress(&mut chunk[filled..]) {
+                Ok(0) => break,
+                Ok(n) => filled += n,
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+
+        if filled < self.size {
+            // Decompression is finished
+            self.done = true;
+            if filled == 0 {
+                return None;
+            }
+            chunk.truncate(filled);
+        }
+
+        Some(Ok(chunk))
+    }
+}
+
+/// An iterator over the decompressed frames of a [`Decoder`].
+///
+/// Created by [`Decoder::frames`].
+pub struct FrameDecoder<'d, 'a, S> {
+    decoder: &'d mut Decoder<'a, S>,
+    next: u32,
+    end: u32,
+}
+
+impl<S: Seekable> Iterator for FrameDecoder<'_, '_, S> {
+    type Item = Result<Vec<u8>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next >= self.end {
+            return None;
+        }
+
+        let index = self.next;
+        let res = self
+            .decoder
+            .seek_table
+            .frame_size_decomp(index)
+            .and_then(|size| Ok(vec![0; usize::try_from(size)?]))
+            .and_then(|mut frame| {
+                let n = self.decoder.decompress_frame(index, &mut frame)?;
+                frame.truncate(n);
+                Ok(frame)
+            });
+
+        self.next = if res.is_ok() { index + 1 } else { self.end };
+
+        Some(res)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<S: Seekable + Clone + Send> Decoder<'_, S> {
+    /// Decompresses the data between the offsets `from` and `to` into `out` using multiple
+    /// threads.
+    ///
+    /// Frames are independent of each other, the range is split into groups of consecutive frames
+    /// that are decompressed in parallel, one group per available CPU. Every thread uses a clone
+    /// of the source and the seek table and its own decompression context. Ranges within a single
+    /// frame are decompressed on the current thread. Returns the number of bytes written to
+    /// `out`, i.e. `to - from`.
+    ///
+    /// This decoder itself is not modified, its offset, offset limit and decompression state
+    /// remain unchanged. Frame checksums are verified if enabled. Note that a prefix or dictionary
+    /// that was loaded into the decompression context of this decoder is not used.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the range is out of bounds, `out` is smaller than the range or decompression
+    /// fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use zeekstd::{BytesWrapper, Decoder};
+    ///
+    /// let seekable = std::fs::read("seekable.zst")?;
+    /// let decoder = Decoder::new(BytesWrapper::new(&seekable))?;
+    /// let mut out = vec![0; 1 << 30];
+    /// let n = decoder.decompress_range_parallel(1 << 30, 2 << 30, &mut out)?;
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    #[allow(clippy::missing_panics_doc)]
+    pub fn decompress_range_parallel(&self, from: u64, to: u64, out: &mut [u8]) -> Result<usize> {
+        let threads = std::thread::available_parallelism().map_or(1, core::num::NonZero::get);
+        self.decompress_range_threads(from, to, out, threads)
+    }
+
+    /// Decompresses the range with at most `threads` threads.
+    fn decompress_range_threads(
+        &self,
+        from: u64,
+        to: u64,
+        out: &mut [u8],
+        threads: usize,
+    ) -> Result<usize> {
+        Self::check_offset(to, &self.seek_table)?;
+        if from > to {
+            return Err(Error::offset_out_of_range());
+        }
+        let len = usize::try_from(to - from)?;
+        if out.len() < len {
+            return Err(Error::zstd(ZSTD_ErrorCode::ZSTD_error_dstSize_tooSmall));
+        }
+        if len == 0 {
+            return Ok(0);
+        }
+
+        let first = self.seek_table.frame_index_decomp(from);
+        let last = self.seek_table.frame_index_decomp(to - 1);
+        let num_frames = last - first + 1;
+        let threads = threads.clamp(1, num_frames as usize);
+        // Cast is fine, the number of threads is at most the number of frames
+        let frames_per_thread = num_frames.div_ceil(threads as u32);
+
+        let mut ranges = vec![];
+        let mut index = first;
+        while index <= last {
+            let group_last = last.min(index + frames_per_thread - 1);
+            let start = from.max(self.seek_table.frame_start_decomp(index)?);
+            let end = to.min(self.seek_table.frame_end_decomp(group_last)?);
+            ranges.push(start..end);
+            index = group_last + 1;
+        }
+
+        let mut out = &mut out[..len];
+        if ranges.len() == 1 {
+            self.range_decoder(from..to)?.decompress_frame_into(out)?;
+            return Ok(len);
+        }
+
+        std::thread::scope(|scope| {
+            let mut handles = Vec::with_capacity(ranges.len());
+            for range in ranges {
+                let (buf, rest) = out.split_at_mut(usize::try_from(range.end - range.start)?);
+                out = rest;
+                let mut decoder = self.range_decoder(range)?;
+                handles.push(scope.spawn(move || decoder.decompress_frame_into(buf)));
+            }
+
+            handles
+                .into_iter()
+                .try_for_each(|handle| handle.join().expect("Decompression thread does not panic"))
+        })?;
+
+        Ok(len)
+    }
+
+    /// Creates an independent decoder for `range` with a clone of the source.
+    fn range_decoder(&self, range: core::ops::Range<u64>) -> Result<Decoder<'static, S>> {
+        DecodeOptions::try_new(self.src.clone())
+            .ok_or(Error::zstd(ZSTD_ErrorCode::ZSTD_error_memory_allocation))?
+            .seek_table(self.seek_table.clone())
+            .checksum_algo(self.checksum_algo)
+            .verify_checksums(self.hasher.is_some())
+            .offset(range.start)
+            .offset_limit(range.end)
+            .into_decoder()
+    }
+}
+
+impl<S> Decoder<'_, S> {
+    /// Gets a reference to the underlying source.
+    pub fn get_ref(&self) -> &S {
+        &self.src
+    }
+
+    /// Gets a mutable reference to the underlying source.
+    ///
+    /// The decoder buffers compressed data read from the source. Changing the position of the
+    /// source in the middle of a decompression operation leads to corrupted output, unless the
+    /// decoder is [reset] or its offset is set to another frame afterwards.
+    ///
+    /// [reset]: Decoder::reset
+    pub fn get_mut(&mut self) -> &mut S {
+        &mut self.src
+    }
+
+    /// Consumes this decoder, returning the underlying source.
+    ///
+    /// The position of the source is unspecified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use zeekstd::{BytesWrapper, RawEncoder};
+    /// # let mut encoder = RawEncoder::new()?;
+    /// # let mut seekable = [0u8; 128];
+    /// # let prog = encoder.compress(b"Hello, World!", &mut seekable)?;
+    /// # let end_prog = encoder.end_frame(&mut seekable[prog.out_progress()..])?;
+    /// # let mut ser = encoder.into_seek_table().into_serializer()?;
+    /// # let mut n = prog.out_progress() + end_prog.out_progress();
+    /// # n += ser.write_into(&mut seekable[n..]);
+    /// # let seekable = BytesWrapper::new(&seekable[..n]);
+    /// use zeekstd::{Decoder, OffsetFrom, Seekable};
+    ///
+    /// let mut decoder = Decoder::new(seekable)?;
+    /// let mut buf = [0u8; 13];
+    /// decoder.decompress(&mut buf)?;
+    ///
+    /// // Recover the source and rewind it to reuse it
+    /// let mut src = decoder.into_inner();
+    /// assert_eq!(src.set_offset(OffsetFrom::Start(0))?, 0);
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    pub fn into_inner(self) -> S {
+        self.src
+    }
+}
+
+/// Strips the length prefixes of an archive that was written with length prefixed frames.
+///
+/// Reading from a `LengthPrefixedReader` yields the archive without length prefixes, i.e. a
+/// regular seekable archive. Alternatively, [`Self::read_frame`] returns complete frames one by
+/// one. See [`EncodeOptions::length_prefixed`] for details.
+///
+/// **Note**: Length prefixed frames are a zeekstd extension and not part of the seekable format.
+///
+/// [`EncodeOptions::length_prefixed`]: crate::EncodeOptions::length_prefixed
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Read;
+/// use zeekstd::{EncodeOptions, LengthPrefixedReader};
+///
+/// let mut prefixed = vec![];
+/// let mut encoder = EncodeOptions::new()
+///     .length_prefixed(true)
+///     .into_encoder(&mut prefixed)?;
+/// encoder.compress(b"Hello, World!")?;
+/// encoder.finish()?;
+///
+/// let mut reader = LengthPrefixedReader::new(prefixed.as_slice());
+/// let mut seekable = vec![];
+/// reader.read_to_end(&mut seekable)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Debug)]
+pub struct LengthPrefixedReader<R> {
+    reader: R,
+    remaining: usize,
+}
+
+#[cfg(feature = "std")]
+impl<R: std::io::Read> LengthPrefixedReader<R> {
+    /// Creates a new `LengthPrefixedReader` that reads length prefixed frames from `reader`.
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            remaining: 0,
+        }
+    }
+
+    /// Reads the next complete frame.
+    ///
+    /// The last frame contains the seek table. If the current frame was partly read already,
+    /// returns the remaining data of the frame. Returns `None` if the reader reached its end.
+    ///
+    /// # Errors
+    ///
+    /// If reading fails or the data ends within a frame.
+    pub fn read_frame(&mut self) -> std::io::Result<Option<Vec<u8>>> {
+        if self.remaining == 0 && !self.next_prefix()? {
+            return Ok(None);
+        }
+
+        let mut frame = vec![0; self.remaining];
+        self.reader.read_exact(&mut frame)?;
+        self.remaining = 0;
+
+        Ok(Some(frame))
+    }
+
+    /// Consumes this reader, returning the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Reads the next length prefix, returns `false` if the reader reached its end.
+    fn next_prefix(&mut self) -> std::io::Result<bool> {
+        let mut prefix = [0u8; 4];
+        let mut read = 0;
+        while read < prefix.len() {
+            match self.reader.read(&mut prefix[read..])? {
+                0 if read == 0 => return Ok(false),
+                0 => return Err(std::io::ErrorKind::UnexpectedEof.into()),
+                n => read += n,
+            }
+        }
+        self.remaining = usize::try_from(u32::from_le_bytes(prefix))
+            .map_err(|_| std::io::Error::from(std::io::ErrorKind::InvalidData))?;
+
+        Ok(true)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: std::io::Read> std::io::Read for LengthPrefixedReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        // Skip empty frames
+        while self.remaining == 0 {
+            if !self.next_prefix()? {
+                return Ok(0);
+            }
+        }
+
+        let len = buf.len().min(self.remaining);
+        let n = self.reader.read(&mut buf[..len])?;
+        if n == 0 && len > 0 {
+            return Err(std::io::ErrorKind::UnexpectedEof.into());
+        }
+        self.remaining -= n;
+
+        Ok(n)
+    }
+}
+
+/// Allows to read decompressed data from a `Decoder`.
+///
 /// # Examples
 ///
 /// ```no_run
@@ -509,7 +2023,7 @@ impl<S: Seekable> Decoder<'_, S> {
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 impl<S: Seekable> std::io::Read for Decoder<'_, S> {
     fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
-        self.decompress(buf).map_err(std::io::Error::other)
+        Ok(self.decompress(buf)?)
     }
 }
 
@@ -523,7 +2037,7 @@ impl<S: Seekable> std::io::Read for Decoder<'_, S> {
 /// # let mut seekable = [0u8; 128];
 /// # let prog = encoder.compress(b"Hello, World!", &mut seekable)?;
 /// # let end_prog = encoder.end_frame(&mut seekable[prog.out_progress()..])?;
-/// # let mut ser = encoder.into_seek_table().into_serializer();
+/// # let mut ser = encoder.into_seek_table().into_serializer()?;
 /// # let mut n = prog.out_progress() + end_prog.out_progress();
 /// # n += ser.write_into(&mut seekable[n..]);
 /// # let seekable = BytesWrapper::new(&seekable[..n]);
@@ -548,12 +2062,16 @@ impl<S: Seekable> std::io::Seek for Decoder<'_, S> {
 
         match pos {
             SeekFrom::Start(offset) => {
-                self.set_offset(offset).map_err(io::Error::other)?;
+                self.set_offset(offset)?;
                 Ok(offset)
             }
             SeekFrom::End(n) => {
                 if n > 0 {
-                    return Err(io::Error::other(Error::offset_out_of_range()));
+                    let size = self.seek_table().size_decomp();
+                    return Err(io::Error::other(Error::offset_out_of_range_with(
+                        size.saturating_add(n.unsigned_abs()),
+                        size,
+                    )));
                 }
 
                 let offset = self
@@ -561,7 +2079,7 @@ impl<S: Seekable> std::io::Seek for Decoder<'_, S> {
                     .size_decomp()
                     .checked_add_signed(n)
                     .ok_or(io::Error::other(Error::offset_out_of_range()))?;
-                self.set_offset(offset).map_err(io::Error::other)?;
+                self.set_offset(offset)?;
 
                 Ok(offset)
             }
@@ -570,7 +2088,7 @@ impl<S: Seekable> std::io::Seek for Decoder<'_, S> {
                     .offset
                     .checked_add_signed(n)
                     .ok_or(io::Error::other(Error::offset_out_of_range()))?;
-                self.set_offset(offset).map_err(io::Error::other)?;
+                self.set_offset(offset)?;
 
                 Ok(offset)
             }
@@ -578,9 +2096,160 @@ impl<S: Seekable> std::io::Seek for Decoder<'_, S> {
     }
 }
 
+/// Returns an iterator over the concatenated archives of `src`.
+///
+/// A source may hold several complete seekable archives that were appended to each other. The
+/// archives are located by walking the seek tables from the end of `src`: the size of the last
+/// archive follows from its seek table, which leads to the end of the previous archive, and so
+/// on. The iterator then yields a [`Decoder`] for every archive, in the order the archives appear
+/// in `src`.
+///
+/// Every decoder reads from its own clone of `src`. For files, pass a shared reference, i.e.
+/// `&File`. As the clones then share the file position, use one decoder at a time.
+///
+/// If locating the archives fails, e.g. because `src` contains data that is not part of an
+/// archive, the iterator yields a single error.
+///
+/// # Examples
+///
+/// ```no_run
+/// # #[cfg(feature = "std")] {
+/// use std::fs::File;
+///
+/// let file = File::open("multiple.zst")?;
+/// for decoder in zeekstd::archives(&file) {
+///     let mut decoder = decoder?;
+///     std::io::copy(&mut decoder, &mut std::io::stdout())?;
+/// }
+/// # }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn archives<S: Seekable + Clone>(src: S) -> Archives<S> {
+    Archives { src, members: None }
+}
+
+/// An iterator over the concatenated archives of a seekable source.
+///
+/// Created by [`archives`].
+pub struct Archives<S> {
+    src: S,
+    members: Option<VecDeque<(u64, u64, SeekTable)>>,
+}
+
+impl<S: Seekable> Archives<S> {
+    /// Locates all archives of the source, starting from its end.
+    fn find_members(&mut self) -> Result<VecDeque<(u64, u64, SeekTable)>> {
+        let len = self.src.size()?;
+        let mut members = VecDeque::new();
+        let mut end = len;
+
+        while end > 0 {
+            let mut src = WithoutTrailing::new(&mut self.src, len - end)?;
+            let seek_table = SeekTable::from_seekable(&mut src)?;
+            let integrity = src.seek_table_integrity(Format::Foot)?;
+            let archive_len =
+                seek_table.size_comp() + seek_table::encoded_len_from_integrity(&integrity) as u64;
+            let start = end
+                .checked_sub(archive_len)
+                .ok_or(Error::offset_out_of_range())?;
+
+            members.push_front((start, end - start, seek_table));
+            end = start;
+        }
+
+        Ok(members)
+    }
+}
+
+impl<S: Seekable + Clone> Iterator for Archives<S> {
+    type Item = Result<Decoder<'static, ArchiveMember<S>>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.members.is_none() {
+            match self.find_members() {
+                Ok(members) => self.members = Some(members),
+                Err(e) => {
+                    self.members = Some(VecDeque::new());
+                    return Some(Err(e));
+                }
+            }
+        }
+
+        let (start, len, seek_table) = self.members.as_mut()?.pop_front()?;
+        let src = ArchiveMember::new(self.src.clone(), start, len);
+
+        Some(
+            DecodeOptions::new(src)
+                .seek_table(seek_table)
+                .into_decoder(),
+        )
+    }
+}
+
+/// Decompresses all data of `decoder` on a blocking thread and sends it to `sink`.
+///
+/// This bridges a [`Decoder`] into async applications without blocking the async runtime.
+/// Decompression is CPU bound and the seekable source is read with blocking calls, so the
+/// decoder is moved to a thread via [`tokio::task::spawn_blocking`]. The decompressed data is
+/// sent through the channel in chunks of up to [`DCtx::out_size()`] bytes. The returned handle
+/// resolves to the total number of decompressed bytes.
+///
+/// Decompression stops with an IO error of kind [`std::io::ErrorKind::BrokenPipe`] if the
+/// receiving half of the channel is dropped.
+///
+/// # Examples
+///
+/// ```no_run
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::fs::File;
+/// use tokio::sync::mpsc;
+/// use zeekstd::Decoder;
+///
+/// let decoder = Decoder::new(File::open("seekable.zst")?)?;
+/// let (tx, mut rx) = mpsc::channel(4);
+/// let handle = zeekstd::spawn_decompress(decoder, tx);
+///
+/// while let Some(chunk) = rx.recv().await {
+///     // Process decompressed data...
+/// }
+/// let total = handle.await??;
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+pub fn spawn_decompress<S>(
+    mut decoder: Decoder<'static, S>,
+    sink: tokio::sync::mpsc::Sender<Vec<u8>>,
+) -> tokio::task::JoinHandle<Result<u64>>
+where
+    S: Seekable + Send + 'static,
+{
+    tokio::task::spawn_blocking(move || {
+        let mut written = 0;
+
+        loop {
+            let mut buf = vec![0; DCtx::out_size()];
+            let n = decoder.decompress(&mut buf)?;
+            if n == 0 {
+                return Ok(written);
+            }
+
+            buf.truncate(n);
+            written += n as u64;
+            if sink.blocking_send(buf).is_err() {
+                return Err(std::io::Error::from(std::io::ErrorKind::BrokenPipe).into());
+            }
+        }
+    })
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{BytesWrapper, EncodeOptions, FrameSizePolicy, tests::INPUT};
+    use crate::{
+        BytesWrapper, EncodeOptions, FrameSizePolicy, RawEncoder,
+        tests::{INPUT, ShortReads},
+    };
 
     use super::*;
 
@@ -615,7 +2284,7 @@ mod tests {
         }
         assert_eq!(out_progress, seekable.len());
 
-        let mut ser = encoder.into_seek_table().into_serializer();
+        let mut ser = encoder.into_seek_table().into_serializer().unwrap();
         loop {
             let n = ser.write_into(&mut buf);
             if n == 0 {
@@ -704,9 +2373,10 @@ mod tests {
         let seekable = new_seekable(Some(FrameSizePolicy::Uncompressed(frame_size as u32)));
         let mut decoder = Decoder::new(BytesWrapper::new(&seekable)).unwrap();
 
-        // Decompress the last 4 frames
+        // Decompress the last frames, the input length might be a multiple of the frame size
+        let last_frame = decoder.seek_table().num_frames() - 1;
         decoder.set_lower_frame(5).unwrap();
-        decoder.set_upper_frame(9).unwrap();
+        decoder.set_upper_frame(last_frame).unwrap();
 
         let len = INPUT.len() - frame_size * 5;
         let mut output = vec![0; len];
@@ -796,6 +2466,8 @@ mod tests {
 
     #[test]
     fn set_offset_boundaries() {
+        use alloc::string::ToString;
+
         let seekable = new_seekable(None);
         let mut decoder = Decoder::new(BytesWrapper::new(&seekable)).unwrap();
 
@@ -816,26 +2488,103 @@ mod tests {
                 .unwrap_err()
                 .is_offset_out_of_range()
         );
+
+        // The error reports the requested and the maximum offset
+        let err = decoder.set_offset(offset).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            alloc::format!("offset {offset} out of range, max is {}", offset - 1)
+        );
     }
 
     #[test]
-    fn decompress_within_offset_boundaries() {
-        let frame_size = INPUT.len() / 34;
+    fn set_offset_limit_frame_aligned() {
+        let frame_size = INPUT.len() as u64 / 10;
         let seekable = new_seekable(Some(FrameSizePolicy::Uncompressed(frame_size as u32)));
         let mut decoder = Decoder::new(BytesWrapper::new(&seekable)).unwrap();
+        let size = decoder.seek_table().size_decomp();
+        let last_start = decoder
+            .seek_table()
+            .frame_start_decomp(decoder.seek_table().num_frames() - 1)
+            .unwrap();
 
-        let offset = INPUT.len() / 3;
-        let offset_limit = 2 * offset;
-        decoder.set_offset(offset as u64).unwrap();
-        decoder.set_offset_limit(offset_limit as u64).unwrap();
-
-        let mut output = vec![0; INPUT.len()];
-        let n = decoder.decompress(&mut output).unwrap();
-        assert_eq!(n, offset_limit - offset);
-        assert_eq!(INPUT.as_bytes()[offset..offset_limit], output[..n]);
+        for (limit, expected) in [
+            (0, 0),
+            (1, frame_size),
+            (frame_size, frame_size),
+            (frame_size + 1, 2 * frame_size),
+            (3 * frame_size - 1, 3 * frame_size),
+            // The last frame can be shorter than the others
+            (last_start + 1, size),
+            (size, size),
+        ] {
+            decoder.set_offset(0).unwrap();
+            let aligned = decoder.set_offset_limit_frame_aligned(limit).unwrap();
+            assert_eq!(aligned, expected);
+
+            // Decompression stops at the frame boundary
+            let mut output = vec![0; INPUT.len()];
+            let mut pos = 0;
+            loop {
+                let n = decoder.decompress(&mut output[pos..]).unwrap();
+                if n == 0 {
+                    break;
+                }
+                pos += n;
+            }
+            assert_eq!(&output[..pos], &INPUT.as_bytes()[..aligned as usize]);
+        }
 
-        // Limit stays unchanged
-        decoder.set_offset(3).unwrap();
+        assert!(
+            decoder
+                .set_offset_limit_frame_aligned(size + 1)
+                .unwrap_err()
+                .is_offset_out_of_range()
+        );
+    }
+
+    #[test]
+    fn current_frame() {
+        let frame_size = INPUT.len() / 10;
+        let seekable = new_seekable(Some(FrameSizePolicy::Uncompressed(frame_size as u32)));
+        let mut decoder = Decoder::new(BytesWrapper::new(&seekable)).unwrap();
+        let size = decoder.seek_table().size_decomp();
+
+        assert_eq!(decoder.num_frames(), decoder.seek_table().num_frames());
+        assert_eq!(decoder.current_frame(), 0);
+
+        for offset in [0, 1, frame_size as u64, size / 2, size - 1, size, 17, 0] {
+            decoder.set_offset(offset).unwrap();
+            assert_eq!(
+                decoder.current_frame(),
+                decoder.seek_table().frame_index_decomp(offset)
+            );
+        }
+
+        // Decompression advances the current frame
+        let mut buf = vec![0; frame_size + 1];
+        decoder.decompress(&mut buf).unwrap();
+        assert_eq!(decoder.current_frame(), 1);
+    }
+
+    #[test]
+    fn decompress_within_offset_boundaries() {
+        let frame_size = INPUT.len() / 34;
+        let seekable = new_seekable(Some(FrameSizePolicy::Uncompressed(frame_size as u32)));
+        let mut decoder = Decoder::new(BytesWrapper::new(&seekable)).unwrap();
+
+        let offset = INPUT.len() / 3;
+        let offset_limit = 2 * offset;
+        decoder.set_offset(offset as u64).unwrap();
+        decoder.set_offset_limit(offset_limit as u64).unwrap();
+
+        let mut output = vec![0; INPUT.len()];
+        let n = decoder.decompress(&mut output).unwrap();
+        assert_eq!(n, offset_limit - offset);
+        assert_eq!(INPUT.as_bytes()[offset..offset_limit], output[..n]);
+
+        // Limit stays unchanged
+        decoder.set_offset(3).unwrap();
         let n = decoder.decompress(&mut output).unwrap();
         assert_eq!(n, offset_limit - 3);
         assert_eq!(INPUT.as_bytes()[3..offset_limit], output[..n]);
@@ -937,4 +2686,1172 @@ mod tests {
         assert_eq!(n, INPUT.len() - 101);
         assert_eq!(INPUT.as_bytes()[101..], output[..n]);
     }
+
+    #[test]
+    fn decompress_uninit() {
+        let seekable = new_seekable(Some(FrameSizePolicy::Uncompressed(1024)));
+        let mut decoder = Decoder::new(BytesWrapper::new(&seekable)).unwrap();
+        decoder.set_offset(1000).unwrap();
+        let mut expected = vec![0; INPUT.len()];
+        let n = decoder.decompress(&mut expected).unwrap();
+        expected.truncate(n);
+
+        let mut decoder = Decoder::new(BytesWrapper::new(&seekable)).unwrap();
+        decoder.set_offset(1000).unwrap();
+        let mut output = Vec::with_capacity(INPUT.len());
+        loop {
+            // Small buffers to decompress across frame boundaries
+            let spare = &mut output.spare_capacity_mut()[..100];
+            let n = decoder.decompress_uninit(spare).unwrap();
+            if n == 0 {
+                break;
+            }
+            // SAFETY: The decoder initialized the first n bytes of the spare capacity
+            unsafe { output.set_len(output.len() + n) };
+        }
+
+        assert_eq!(output, expected);
+        assert_eq!(output, INPUT.as_bytes()[1000..]);
+    }
+
+    #[test]
+    fn read_at() {
+        let input = INPUT.as_bytes();
+        let seekable = new_seekable(Some(FrameSizePolicy::Uncompressed(1024)));
+        let mut decoder = Decoder::new(BytesWrapper::new(&seekable)).unwrap();
+        let mut output = vec![0; input.len()];
+        let mut progress = 0;
+        let mut buf = vec![0; 700];
+
+        for at in [5000, 0, 1, 2048, 100, input.len() as u64 - 10]
+            .into_iter()
+            .cycle()
+        {
+            let end = output.len().min(progress + 300);
+            let n = decoder.decompress(&mut output[progress..end]).unwrap();
+            if n == 0 {
+                break;
+            }
+            progress += n;
+            assert_eq!(decoder.offset(), progress as u64);
+
+            let at_usize = at as usize;
+            let n = decoder.read_at(at, &mut buf).unwrap();
+            assert_eq!(n, buf.len().min(input.len() - at_usize));
+            assert_eq!(buf[..n], input[at_usize..at_usize + n]);
+            assert_eq!(decoder.offset(), progress as u64);
+        }
+        assert_eq!(output, input);
+
+        // The offset limit is ignored and restored
+        decoder.set_offset(10).unwrap();
+        decoder.set_offset_limit(20).unwrap();
+        let n = decoder.read_at(30, &mut buf).unwrap();
+        assert_eq!(buf[..n], input[30..30 + buf.len()]);
+        assert_eq!(decoder.offset_limit(), 20);
+        let n = decoder.decompress(&mut buf).unwrap();
+        assert_eq!(buf[..n], input[10..20]);
+
+        let size = decoder.seek_table().size_decomp();
+        assert_eq!(decoder.read_at(size, &mut buf).unwrap(), 0);
+        assert!(
+            decoder
+                .read_at(size + 1, &mut buf)
+                .unwrap_err()
+                .is_offset_out_of_range()
+        );
+    }
+
+    #[test]
+    fn decompress_from_frame_start() {
+        let seekable = new_seekable(Some(FrameSizePolicy::Uncompressed(1024)));
+        let mut decoder = Decoder::new(BytesWrapper::new(&seekable)).unwrap();
+        let mut output = vec![0; 1024];
+
+        let start = decoder.set_lower_frame(3).unwrap();
+        let n = decoder.decompress(&mut output).unwrap();
+        assert_eq!(INPUT.as_bytes()[3 * 1024..4 * 1024], output[..n]);
+        let at_boundary = decoder.read_compressed();
+        assert!(at_boundary <= decoder.seek_table().frame_size_comp(3).unwrap());
+
+        decoder.set_offset(start + 1).unwrap();
+        let n = decoder.decompress(&mut output).unwrap();
+        assert_eq!(INPUT.as_bytes()[start as usize + 1..=4 * 1024], output[..n]);
+        assert!(at_boundary < decoder.read_compressed());
+    }
+
+    #[test]
+    fn on_frame() {
+        let seekable = new_seekable(Some(FrameSizePolicy::Uncompressed(1024)));
+        let mut output = vec![0; INPUT.len()];
+
+        let mut frames = vec![];
+        let mut decoder = DecodeOptions::new(BytesWrapper::new(&seekable))
+            .on_frame(|index| frames.push(index))
+            .into_decoder()
+            .unwrap();
+        let num_frames = decoder.num_frames();
+        while decoder.decompress(&mut output).unwrap() > 0 {}
+        drop(decoder);
+        assert_eq!(frames, (0..num_frames).collect::<Vec<_>>());
+
+        // The frame that contains the offset limit isn't completed
+        let mut frames = vec![];
+        let mut decoder = DecodeOptions::new(BytesWrapper::new(&seekable))
+            .offset(1500)
+            .offset_limit(5000)
+            .on_frame(|index| frames.push(index))
+            .into_decoder()
+            .unwrap();
+        while decoder.decompress(&mut output).unwrap() > 0 {}
+        drop(decoder);
+        assert_eq!(frames, [1, 2, 3]);
+    }
+
+    #[test]
+    fn decompress_with_max_decode_step() {
+        let seekable = new_seekable(Some(FrameSizePolicy::Uncompressed(1024)));
+        let mut decoder = DecodeOptions::new(BytesWrapper::new(&seekable))
+            .offset(1000)
+            .max_decode_step(64)
+            .into_decoder()
+            .unwrap();
+        let mut output = vec![0; INPUT.len()];
+
+        // The first call only skips to the offset
+        let n = decoder.decompress_bounded(&mut output).unwrap();
+        assert_eq!(n, 0);
+        assert!(!decoder.is_finished());
+
+        let mut progress = 0;
+        let mut calls = 1;
+        while !decoder.is_finished() {
+            let n = decoder.decompress_bounded(&mut output[progress..]).unwrap();
+            assert!(n <= 64);
+            progress += n;
+            calls += 1;
+        }
+        assert_eq!(INPUT.as_bytes()[1000..], output[..progress]);
+        assert_eq!(calls, INPUT.len().div_ceil(64));
+        assert_eq!(decoder.decompress_bounded(&mut output).unwrap(), 0);
+
+        // Unbounded decompression returns 0 only when finished
+        decoder.set_offset(1000).unwrap();
+        let n = decoder.decompress(&mut output).unwrap();
+        assert_eq!(n, INPUT.len() - 1000);
+        assert!(decoder.is_finished());
+    }
+
+    #[test]
+    fn decompress_with_short_reads() {
+        let seekable = new_seekable(Some(FrameSizePolicy::Uncompressed(1024)));
+        let mut decoder = Decoder::new(ShortReads(BytesWrapper::new(&seekable))).unwrap();
+
+        let mut output = vec![0; INPUT.len()];
+        let mut progress = 0;
+        loop {
+            // Small output buffers to leave pending output in the decompression context
+            let end = output.len().min(progress + 7);
+            let n = decoder.decompress(&mut output[progress..end]).unwrap();
+            if n == 0 {
+                break;
+            }
+            progress += n;
+        }
+        assert_eq!(progress, INPUT.len());
+        assert_eq!(INPUT.as_bytes(), output);
+
+        decoder.set_offset(1500).unwrap();
+        let n = decoder.decompress(&mut output).unwrap();
+        assert_eq!(n, INPUT.len() - 1500);
+        assert_eq!(INPUT.as_bytes()[1500..], output[..n]);
+    }
+
+    #[test]
+    fn decompress_truncated_source() {
+        let seekable = new_seekable(Some(FrameSizePolicy::Uncompressed(1024)));
+        let st = SeekTable::from_seekable(&mut BytesWrapper::new(&seekable)).unwrap();
+        // Cut off the end of the last frame, the seek table is passed separately
+        let end = st.size_comp() as usize - 4;
+        let mut decoder = DecodeOptions::new(ShortReads(BytesWrapper::new(&seekable[..end])))
+            .seek_table(st)
+            .into_decoder()
+            .unwrap();
+
+        let mut output = vec![0; INPUT.len()];
+        assert!(decoder.decompress(&mut output).is_err());
+    }
+
+    /// A source that only supports seeking from the start.
+    struct StartSeeksOnly<'a>(BytesWrapper<'a>);
+
+    impl Seekable for StartSeeksOnly<'_> {
+        fn set_offset(&mut self, offset: OffsetFrom) -> Result<u64> {
+            assert!(
+                matches!(offset, OffsetFrom::Start(_)),
+                "seeked relative to the end"
+            );
+            self.0.set_offset(offset)
+        }
+
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+            self.0.read(buf)
+        }
+
+        fn seek_table_integrity(
+            &mut self,
+            _format: crate::seek_table::Format,
+        ) -> Result<[u8; crate::SEEK_TABLE_INTEGRITY_SIZE]> {
+            panic!("seek table integrity requested");
+        }
+    }
+
+    #[test]
+    fn decompress_without_appended_seek_table() {
+        let seekable = new_seekable(Some(FrameSizePolicy::Uncompressed(1024)));
+        let st = SeekTable::from_seekable(&mut BytesWrapper::new(&seekable)).unwrap();
+        // Only the compressed frames, the seek table is shipped separately
+        let frames = &seekable[..st.size_comp() as usize];
+
+        let mut decoder = DecodeOptions::new(StartSeeksOnly(BytesWrapper::new(frames)))
+            .seek_table(st)
+            .into_decoder()
+            .unwrap();
+
+        let mut output = vec![0; INPUT.len()];
+        let n = decoder.decompress(&mut output).unwrap();
+        assert_eq!(INPUT.as_bytes(), &output[..n]);
+
+        decoder.set_offset(3000).unwrap();
+        let n = decoder.decompress(&mut output).unwrap();
+        assert_eq!(INPUT.as_bytes()[3000..], output[..n]);
+
+        // Reading the seek table from the source fails without a footer
+        assert!(Decoder::new(BytesWrapper::new(frames)).is_err());
+    }
+
+    /// A source that counts how often the offset was set.
+    struct CountSeeks<'a> {
+        inner: BytesWrapper<'a>,
+        seeks: usize,
+    }
+
+    impl Seekable for CountSeeks<'_> {
+        fn set_offset(&mut self, offset: OffsetFrom) -> Result<u64> {
+            self.seeks += 1;
+            self.inner.set_offset(offset)
+        }
+
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+            self.inner.read(buf)
+        }
+
+        fn seek_table_integrity(
+            &mut self,
+            format: crate::seek_table::Format,
+        ) -> Result<[u8; crate::SEEK_TABLE_INTEGRITY_SIZE]> {
+            self.inner.seek_table_integrity(format)
+        }
+    }
+
+    #[test]
+    fn assume_positioned() {
+        let seekable = new_seekable(Some(FrameSizePolicy::Uncompressed(1024)));
+        let st = SeekTable::from_seekable(&mut BytesWrapper::new(&seekable)).unwrap();
+        let src = CountSeeks {
+            inner: BytesWrapper::new(&seekable),
+            seeks: 0,
+        };
+        let mut decoder = DecodeOptions::new(src)
+            .seek_table(st.clone())
+            .assume_positioned(true)
+            .into_decoder()
+            .unwrap();
+
+        let mut output = vec![0; INPUT.len()];
+        let n = decoder.decompress(&mut output).unwrap();
+        assert_eq!(INPUT.as_bytes(), &output[..n]);
+        assert_eq!(decoder.src.seeks, 0);
+
+        // Subsequent decompression starts still seek
+        decoder.reset();
+        let n = decoder.decompress(&mut output).unwrap();
+        assert_eq!(INPUT.as_bytes(), &output[..n]);
+        assert_eq!(decoder.src.seeks, 1);
+
+        decoder.set_offset(1500).unwrap();
+        let n = decoder.decompress(&mut output).unwrap();
+        assert_eq!(INPUT.as_bytes()[1500..], output[..n]);
+        assert_eq!(decoder.src.seeks, 2);
+
+        // Starting at another frame always seeks
+        let src = CountSeeks {
+            inner: BytesWrapper::new(&seekable),
+            seeks: 0,
+        };
+        let mut decoder = DecodeOptions::new(src)
+            .seek_table(st)
+            .lower_frame(1)
+            .assume_positioned(true)
+            .into_decoder()
+            .unwrap();
+        let n = decoder.decompress(&mut output).unwrap();
+        assert_eq!(INPUT.as_bytes()[1024..], output[..n]);
+        assert_eq!(decoder.src.seeks, 1);
+    }
+
+    #[test]
+    fn observe_source_access() {
+        let seekable = new_seekable(Some(FrameSizePolicy::Uncompressed(1024)));
+        let st = SeekTable::from_seekable(&mut BytesWrapper::new(&seekable)).unwrap();
+        let mut accesses = vec![];
+        let mut decoder = DecodeOptions::new(BytesWrapper::new(&seekable))
+            .offset(1500)
+            .observer(|access| accesses.push(access))
+            .into_decoder()
+            .unwrap();
+
+        let mut output = vec![0; INPUT.len()];
+        let n = decoder.decompress(&mut output).unwrap();
+        assert_eq!(INPUT.as_bytes()[1500..], output[..n]);
+        drop(decoder);
+
+        let start = st.frame_start_comp(1).unwrap();
+        assert_eq!(accesses[0], SourceAccess::Seek { offset: start });
+        // Reads are contiguous and cover all remaining frames, the input buffer may also be
+        // filled with parts of the seek table
+        let mut pos = start;
+        for access in &accesses[1..] {
+            let SourceAccess::Read { offset, len } = *access else {
+                panic!("unexpected access: {access:?}");
+            };
+            assert_eq!(offset, pos);
+            pos += len as u64;
+        }
+        assert!(pos >= st.size_comp());
+    }
+
+    #[test]
+    fn concatenated_archives() {
+        let first = new_seekable(Some(FrameSizePolicy::Uncompressed(1024)));
+        let second = new_seekable(None);
+        let mut seekable = first.clone();
+        seekable.extend(&second);
+        seekable.extend(&first);
+
+        let mut output = vec![0; INPUT.len()];
+        let mut starts = vec![];
+        for decoder in crate::archives(BytesWrapper::new(&seekable)) {
+            let mut decoder = decoder.unwrap();
+            let n = decoder.decompress(&mut output).unwrap();
+            assert_eq!(INPUT.as_bytes(), &output[..n]);
+            assert_eq!(decoder.decompress(&mut output).unwrap(), 0);
+            starts.push(decoder.get_ref().start());
+        }
+        let second_start = first.len() as u64;
+        let third_start = second_start + second.len() as u64;
+        assert_eq!(starts, [0, second_start, third_start]);
+
+        assert_eq!(crate::archives(BytesWrapper::new(&[])).count(), 0);
+
+        // Data in front of the first archive
+        let mut seekable = vec![0xAB; 10];
+        seekable.extend(&first);
+        let mut archives = crate::archives(BytesWrapper::new(&seekable));
+        assert!(archives.next().unwrap().is_err());
+        assert!(archives.next().is_none());
+    }
+
+    /// Compresses `INPUT` with `encoder` and returns the frames, without seek table.
+    fn compress_all(encoder: &mut RawEncoder) -> Vec<u8> {
+        let mut buf = vec![0; INPUT.len()];
+        let mut frames = vec![];
+
+        let mut in_progress = 0;
+        while in_progress < INPUT.len() {
+            let prog = encoder
+                .compress(&INPUT.as_bytes()[in_progress..], &mut buf)
+                .unwrap();
+            frames.extend(&buf[..prog.out_progress()]);
+            in_progress += prog.in_progress();
+        }
+        loop {
+            let prog = encoder.end_frame(&mut buf).unwrap();
+            frames.extend(&buf[..prog.out_progress()]);
+            if prog.data_left() == 0 {
+                break;
+            }
+        }
+
+        frames
+    }
+
+    #[test]
+    fn verify_frames() {
+        let seekable = new_seekable(Some(FrameSizePolicy::Uncompressed(1024)));
+        let st = SeekTable::from_seekable(&mut BytesWrapper::new(&seekable)).unwrap();
+        let last = st.num_frames() - 1;
+
+        let mut decoder = Decoder::new(BytesWrapper::new(&seekable)).unwrap();
+        let mut output = vec![0; 100];
+        decoder.decompress(&mut output).unwrap();
+        for index in 0..st.num_frames() {
+            assert!(decoder.verify_frame(index).unwrap());
+        }
+        assert!(decoder.verify_frame(last + 1).is_err());
+
+        // Decompression continues at the offset where it stopped
+        let n = decoder.decompress(&mut output).unwrap();
+        assert_eq!(INPUT.as_bytes()[100..200], output[..n]);
+
+        // Corrupt the content checksum of the last frame
+        let mut encoder = EncodeOptions::new()
+            .frame_size_policy(FrameSizePolicy::Uncompressed(1024))
+            .content_checksum(true)
+            .into_raw_encoder()
+            .unwrap();
+        let mut with_checksum = compress_all(&mut encoder);
+        let st = encoder.into_seek_table();
+        let pos = usize::try_from(st.frame_end_comp(last).unwrap()).unwrap() - 1;
+        with_checksum[pos] ^= 0xFF;
+        let mut decoder = DecodeOptions::new(BytesWrapper::new(&with_checksum))
+            .seek_table(st)
+            .into_decoder()
+            .unwrap();
+        assert!(decoder.verify_frame(0).unwrap());
+        assert!(!decoder.verify_frame(last).unwrap());
+
+        // Corrupt the data of the last frame, detected by the seek table checksum
+        let mut encoder = EncodeOptions::new()
+            .frame_size_policy(FrameSizePolicy::Uncompressed(1024))
+            .seek_table_checksum(true)
+            .into_raw_encoder()
+            .unwrap();
+        let mut with_checksum = compress_all(&mut encoder);
+        let st = encoder.into_seek_table();
+        let pos = usize::try_from(st.frame_end_comp(last).unwrap()).unwrap() - 1;
+        with_checksum[pos] ^= 0xFF;
+        let mut decoder = DecodeOptions::new(BytesWrapper::new(&with_checksum))
+            .seek_table(st.clone())
+            .into_decoder()
+            .unwrap();
+        assert!(decoder.verify_frame(0).unwrap());
+        assert!(!decoder.verify_frame(last).unwrap());
+
+        // Frame sizes don't match the seek table
+        let mut wrong = SeekTable::new();
+        wrong
+            .log_frame(
+                u32::try_from(st.frame_size_comp(0).unwrap()).unwrap(),
+                u32::try_from(st.frame_size_decomp(0).unwrap()).unwrap() + 1,
+            )
+            .unwrap();
+        let mut decoder = DecodeOptions::new(BytesWrapper::new(&seekable))
+            .seek_table(wrong)
+            .into_decoder()
+            .unwrap();
+        assert!(!decoder.verify_frame(0).unwrap());
+    }
+
+    #[test]
+    fn empty_range_does_not_access_source() {
+        let seekable = new_seekable(Some(FrameSizePolicy::Uncompressed(1024)));
+        let st = SeekTable::from_seekable(&mut BytesWrapper::new(&seekable)).unwrap();
+        let mut accesses = 0;
+        let mut decoder = DecodeOptions::new(BytesWrapper::new(&seekable))
+            .seek_table(st)
+            .offset_limit(0)
+            .observer(|_| accesses += 1)
+            .into_decoder()
+            .unwrap();
+
+        let mut output = vec![0; INPUT.len()];
+        assert_eq!(decoder.decompress(&mut output).unwrap(), 0);
+        assert_eq!(decoder.read_compressed(), 0);
+
+        // Empty range in the middle of the data
+        decoder.set_offset_limit(1500).unwrap();
+        decoder.set_offset(1500).unwrap();
+        assert_eq!(decoder.decompress(&mut output).unwrap(), 0);
+        assert_eq!(decoder.read_compressed(), 0);
+        drop(decoder);
+        assert_eq!(accesses, 0);
+    }
+
+    #[test]
+    fn decompress_with_seekable_prefix() {
+        let old = INPUT.as_bytes();
+        let new = alloc::format!("{INPUT}\nThe End");
+        let mut encoder = EncodeOptions::new()
+            .frame_size_policy(FrameSizePolicy::Uncompressed(1024))
+            .into_raw_encoder()
+            .unwrap();
+        let mut patch = vec![];
+        let mut buf = vec![0; INPUT.len()];
+
+        let mut in_progress = 0;
+        while in_progress < new.len() {
+            let prog = encoder
+                .compress_with_prefix(&new.as_bytes()[in_progress..], &mut buf, Some(old))
+                .unwrap();
+            patch.extend(&buf[..prog.out_progress()]);
+            in_progress += prog.in_progress();
+        }
+        loop {
+            let prog = encoder.end_frame(&mut buf).unwrap();
+            patch.extend(&buf[..prog.out_progress()]);
+            if prog.data_left() == 0 {
+                break;
+            }
+        }
+        let mut ser = encoder.into_seek_table().into_serializer().unwrap();
+        let mut st_buf = vec![0; ser.encoded_len()];
+        ser.write_into(&mut st_buf);
+        patch.extend(&st_buf);
+
+        let mut decoder = DecodeOptions::new(BytesWrapper::new(&patch))
+            .prefix_seekable(ShortReads(BytesWrapper::new(old)))
+            .into_decoder()
+            .unwrap();
+        assert!(decoder.seek_table().num_frames() > 1);
+        let mut output = vec![0; new.len()];
+        let mut out_pos = 0;
+        while out_pos < output.len() {
+            out_pos += decoder.decompress(&mut output[out_pos..]).unwrap();
+        }
+        assert_eq!(new.as_bytes(), output);
+
+        // Seeking to a later frame uses the prefix as well
+        decoder.set_offset(2000).unwrap();
+        let n = decoder.decompress(&mut output).unwrap();
+        assert_eq!(new.as_bytes()[2000..], output[..n]);
+    }
+
+    #[test]
+    fn decompress_with_persistent_prefix() {
+        let old = INPUT.as_bytes();
+        let new = alloc::format!("{INPUT}\nThe End");
+        let compress = |opts: EncodeOptions<'static>| {
+            let mut encoder = opts
+                .frame_size_policy(FrameSizePolicy::Uncompressed(1024))
+                .into_raw_encoder()
+                .unwrap();
+            let mut patch = vec![];
+            let mut buf = vec![0; INPUT.len()];
+
+            let mut in_progress = 0;
+            while in_progress < new.len() {
+                let prog = encoder
+                    .compress(&new.as_bytes()[in_progress..], &mut buf)
+                    .unwrap();
+                patch.extend(&buf[..prog.out_progress()]);
+                in_progress += prog.in_progress();
+            }
+            loop {
+                let prog = encoder.end_frame(&mut buf).unwrap();
+                patch.extend(&buf[..prog.out_progress()]);
+                if prog.data_left() == 0 {
+                    break;
+                }
+            }
+            let mut ser = encoder.into_seek_table().into_serializer().unwrap();
+            let mut st_buf = vec![0; ser.encoded_len()];
+            ser.write_into(&mut st_buf);
+            patch.extend(&st_buf);
+            patch
+        };
+
+        let plain = compress(EncodeOptions::new());
+        let patch = compress(EncodeOptions::new().persistent_prefix(old));
+        assert!(patch.len() < plain.len());
+
+        let mut decoder = DecodeOptions::new(BytesWrapper::new(&patch))
+            .prefix_seekable(BytesWrapper::new(old))
+            .into_decoder()
+            .unwrap();
+        assert!(decoder.seek_table().num_frames() > 1);
+        let mut output = vec![0; new.len()];
+        let mut out_pos = 0;
+        while out_pos < output.len() {
+            out_pos += decoder.decompress(&mut output[out_pos..]).unwrap();
+        }
+        assert_eq!(new.as_bytes(), output);
+    }
+
+    #[test]
+    fn verify_checksums_while_decompressing() {
+        let mut encoder = EncodeOptions::new()
+            .frame_size_policy(FrameSizePolicy::Uncompressed(1024))
+            .seek_table_checksum(true)
+            .into_raw_encoder()
+            .unwrap();
+        let seekable = compress_all(&mut encoder);
+        let st = encoder.into_seek_table();
+        let last = st.num_frames() - 1;
+
+        // Same frames, but the checksum of the last frame is wrong
+        let mut wrong = SeekTable::new();
+        for index in 0..=last {
+            let checksum = st.frame_checksum(index).unwrap().unwrap();
+            wrong
+                .log_frame_with_checksum(
+                    u32::try_from(st.frame_size_comp(index).unwrap()).unwrap(),
+                    u32::try_from(st.frame_size_decomp(index).unwrap()).unwrap(),
+                    Some(if index == last { !checksum } else { checksum }),
+                )
+                .unwrap();
+        }
+        let decode = |verify: bool, offset_limit: u64| {
+            let mut decoder = DecodeOptions::new(ShortReads(BytesWrapper::new(&seekable)))
+                .seek_table(wrong.clone())
+                .verify_checksums(verify)
+                .offset_limit(offset_limit)
+                .into_decoder()
+                .unwrap();
+            let mut output = vec![0; INPUT.len()];
+            let mut out_pos = 0;
+            loop {
+                let n = decoder.decompress(&mut output[out_pos..])?;
+                if n == 0 {
+                    break;
+                }
+                out_pos += n;
+            }
+            output.truncate(out_pos);
+            Ok::<_, Error>(output)
+        };
+
+        let size = INPUT.len() as u64;
+        assert!(decode(true, size).unwrap_err().is_checksum_mismatch());
+        assert_eq!(INPUT.as_bytes(), decode(false, size).unwrap());
+        // The last frame is only partially decompressed
+        assert_eq!(
+            INPUT.as_bytes()[..INPUT.len() - 1],
+            decode(true, size - 1).unwrap()
+        );
+        // The correct checksums are verified
+        let frame_start = st.frame_start_decomp(last).unwrap();
+        assert_eq!(
+            INPUT.as_bytes()[..usize::try_from(frame_start).unwrap()],
+            decode(true, frame_start).unwrap()
+        );
+    }
+
+    #[test]
+    fn decompress_single_frames() {
+        let seekable = new_seekable(Some(FrameSizePolicy::Uncompressed(1024)));
+        let mut decoder = Decoder::new(ShortReads(BytesWrapper::new(&seekable))).unwrap();
+        let num_frames = decoder.seek_table().num_frames();
+        assert!(num_frames > 2);
+
+        let mut buf = vec![0; 1024];
+        let mut output = vec![];
+        // Decompress in reverse order to exercise seeking
+        for index in (0..num_frames).rev() {
+            let n = decoder.decompress_frame(index, &mut buf).unwrap();
+            assert_eq!(
+                n as u64,
+                decoder.seek_table().frame_size_decomp(index).unwrap()
+            );
+            assert_eq!(
+                decoder.offset(),
+                decoder.seek_table().frame_end_decomp(index).unwrap()
+            );
+            output.splice(0..0, buf[..n].iter().copied());
+        }
+        assert_eq!(INPUT.as_bytes(), output);
+        assert_eq!(decoder.offset_limit(), INPUT.len() as u64);
+
+        // Decompression continues after the frame
+        let n = decoder.decompress(&mut buf).unwrap();
+        assert_eq!(INPUT.as_bytes()[1024..1024 + n], buf[..n]);
+
+        assert!(decoder.decompress_frame(0, &mut buf[..1023]).is_err());
+        assert!(
+            decoder
+                .decompress_frame(num_frames, &mut buf)
+                .unwrap_err()
+                .is_frame_index_too_large()
+        );
+    }
+
+    #[test]
+    fn decode_from_slices() {
+        let seekable = new_seekable(None);
+        let mut output = vec![0; INPUT.len()];
+
+        let mut decoder = Decoder::new(BytesWrapper::from(seekable.as_slice())).unwrap();
+        let n = decoder.decompress(&mut output).unwrap();
+        assert_eq!(INPUT.as_bytes(), &output[..n]);
+
+        output.fill(0);
+        let src: BytesWrapper = (&seekable).into();
+        let mut decoder = Decoder::new(src).unwrap();
+        let n = decoder.decompress(&mut output).unwrap();
+        assert_eq!(INPUT.as_bytes(), &output[..n]);
+    }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn decode_memory_mapped() {
+        use std::io::Write;
+
+        let seekable = new_seekable(Some(FrameSizePolicy::Uncompressed(1024)));
+        let mut file = tempfile::tempfile().unwrap();
+        file.write_all(&seekable).unwrap();
+
+        let mmap = unsafe { memmap2::Mmap::map(&file).unwrap() };
+        let mut decoder = Decoder::new(BytesWrapper::from(&mmap)).unwrap();
+        let index = decoder.seek_table().num_frames() / 2;
+        let start = decoder.seek_table().frame_start_decomp(index).unwrap() as usize;
+        let end = decoder.seek_table().frame_end_decomp(index).unwrap() as usize;
+
+        let mut buf = vec![0; 1024];
+        let n = decoder.decompress_frame(index, &mut buf).unwrap();
+        assert_eq!(INPUT.as_bytes()[start..end], buf[..n]);
+
+        let mut mmap = mmap.make_mut().unwrap();
+        mmap[0] ^= 0xFF;
+        let mut decoder = Decoder::new(BytesWrapper::from(&mmap)).unwrap();
+        assert!(decoder.decompress_frame(0, &mut buf).is_err());
+        let n = decoder.decompress_frame(index, &mut buf).unwrap();
+        assert_eq!(INPUT.as_bytes()[start..end], buf[..n]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn recover_source() {
+        let seekable = new_seekable(None);
+        let ptr = seekable.as_ptr();
+        let mut decoder = Decoder::new(std::io::Cursor::new(seekable)).unwrap();
+
+        let mut output = vec![0; INPUT.len()];
+        let n = decoder.decompress(&mut output).unwrap();
+        assert_eq!(INPUT.as_bytes(), &output[..n]);
+        assert_eq!(decoder.get_ref().get_ref().as_ptr(), ptr);
+
+        let src = decoder.into_inner().into_inner();
+        assert_eq!(src.as_ptr(), ptr);
+        assert!(SeekTable::from_seekable(&mut BytesWrapper::new(&src)).is_ok());
+    }
+
+    /// Returns pseudo random input and an archive of it with frame checksums in the seek table.
+    ///
+    /// Pseudo random data is stored in raw blocks, corrupting it goes unnoticed by zstd.
+    #[cfg(feature = "std")]
+    fn random_seekable() -> (Vec<u8>, Vec<u8>) {
+        use std::io::Write;
+
+        let mut state = 0x2545_f491_u32;
+        let input = (0..8 * 1024)
+            .map(|_| {
+                state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                (state >> 24) as u8
+            })
+            .collect::<Vec<_>>();
+        let mut seekable = vec![];
+        let mut encoder = EncodeOptions::new()
+            .frame_size_policy(FrameSizePolicy::Uncompressed(1024))
+            .content_checksum(false)
+            .seek_table_checksum(true)
+            .into_encoder(&mut seekable)
+            .unwrap();
+        encoder.write_all(&input).unwrap();
+        encoder.finish().unwrap();
+
+        (input, seekable)
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn corrupted_frame_checksum_mismatch() {
+        use alloc::string::ToString;
+
+        let (input, mut seekable) = random_seekable();
+        let st = SeekTable::from_seekable(&mut BytesWrapper::new(&seekable)).unwrap();
+        let start = st.frame_start_comp(2).unwrap() + st.frame_size_comp(2).unwrap() / 2;
+        seekable[usize::try_from(start).unwrap()] ^= 0xFF;
+
+        let mut decoder = Decoder::new(BytesWrapper::new(&seekable)).unwrap();
+        let mut output = vec![0; input.len()];
+        let mut out_pos = 0;
+        let err = loop {
+            match decoder.decompress(&mut output[out_pos..]) {
+                Ok(n) => out_pos += n,
+                Err(err) => break err,
+            }
+        };
+        assert!(err.is_checksum_mismatch());
+        assert_eq!(err.to_string(), "checksum mismatch in frame 2");
+        assert_eq!(input[..2048], output[..2048]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn validate_archive() {
+        let seekable = new_seekable(Some(FrameSizePolicy::Uncompressed(1024)));
+        let mut decoder = Decoder::new(BytesWrapper::new(&seekable)).unwrap();
+        decoder.set_offset(100).unwrap();
+        decoder.validate().unwrap();
+
+        // The decoder is reset afterwards
+        assert_eq!(decoder.offset(), 0);
+        let mut output = vec![0; INPUT.len()];
+        let mut out_pos = 0;
+        while out_pos < output.len() {
+            out_pos += decoder.decompress(&mut output[out_pos..]).unwrap();
+        }
+        assert_eq!(INPUT.as_bytes(), output);
+
+        // Checksums are verified even if disabled for decompression
+        let (_, mut seekable) = random_seekable();
+        let st = SeekTable::from_seekable(&mut BytesWrapper::new(&seekable)).unwrap();
+        let pos = st.frame_start_comp(5).unwrap() + st.frame_size_comp(5).unwrap() / 2;
+        seekable[usize::try_from(pos).unwrap()] ^= 0xFF;
+        let mut decoder = DecodeOptions::new(BytesWrapper::new(&seekable))
+            .verify_checksums(false)
+            .into_decoder()
+            .unwrap();
+        assert!(decoder.validate().unwrap_err().is_checksum_mismatch());
+        assert_eq!(decoder.offset(), 0);
+        decoder.decompress(&mut output).unwrap();
+
+        // Corrupted frame data
+        let mut seekable = new_seekable(Some(FrameSizePolicy::Uncompressed(1024)));
+        let st = SeekTable::from_seekable(&mut BytesWrapper::new(&seekable)).unwrap();
+        let pos = st.frame_start_comp(3).unwrap();
+        seekable[usize::try_from(pos).unwrap()] ^= 0xFF;
+        let mut decoder = Decoder::new(BytesWrapper::new(&seekable)).unwrap();
+        assert!(decoder.validate().unwrap_err().is_zstd());
+    }
+
+    #[test]
+    fn decompress_in_chunks() {
+        let seekable = new_seekable(Some(FrameSizePolicy::Uncompressed(1024)));
+        let mut decoder = Decoder::new(BytesWrapper::new(&seekable)).unwrap();
+
+        let chunks = decoder.chunks(1000).collect::<Result<Vec<_>>>().unwrap();
+        assert_eq!(chunks.len(), INPUT.len().div_ceil(1000));
+        assert!(chunks[..chunks.len() - 1].iter().all(|c| c.len() == 1000));
+        assert_eq!(INPUT.as_bytes(), chunks.concat());
+        assert!(decoder.chunks(1000).next().is_none());
+
+        // Chunks within offset boundaries
+        decoder.set_offset_limit(3500).unwrap();
+        decoder.set_offset(1500).unwrap();
+        let chunks = decoder.chunks(1024).collect::<Result<Vec<_>>>().unwrap();
+        assert_eq!(chunks.iter().map(Vec::len).collect::<Vec<_>>(), [1024, 976]);
+        assert_eq!(INPUT.as_bytes()[1500..3500], chunks.concat());
+    }
+
+    #[test]
+    fn decompress_whole_frames() {
+        let seekable = new_seekable(Some(FrameSizePolicy::Uncompressed(1024)));
+        let mut decoder = Decoder::new(BytesWrapper::new(&seekable)).unwrap();
+        let num_frames = decoder.seek_table().num_frames();
+
+        let frames = decoder.frames().collect::<Result<Vec<_>>>().unwrap();
+        assert_eq!(frames.len(), num_frames as usize);
+        assert!(frames[..frames.len() - 1].iter().all(|f| f.len() == 1024));
+        assert_eq!(INPUT.as_bytes(), frames.concat());
+        assert!(decoder.frames().next().is_none());
+
+        // Restricted frame range
+        let mut decoder = DecodeOptions::new(BytesWrapper::new(&seekable))
+            .lower_frame(2)
+            .upper_frame(4)
+            .into_decoder()
+            .unwrap();
+        let frames = decoder.frames().collect::<Result<Vec<_>>>().unwrap();
+        assert_eq!(frames.len(), 3);
+        assert_eq!(INPUT.as_bytes()[2048..5120], frames.concat());
+
+        // Frames that overlap the offsets
+        decoder.set_offset_limit(3073).unwrap();
+        decoder.set_offset(1500).unwrap();
+        let frames = decoder.frames().collect::<Result<Vec<_>>>().unwrap();
+        assert_eq!(frames.len(), 3);
+        assert_eq!(INPUT.as_bytes()[1024..4096], frames.concat());
+    }
+
+    #[test]
+    fn seek_table_trailing_bytes() {
+        let mut seekable = new_seekable(Some(FrameSizePolicy::Uncompressed(1024)));
+        let st = SeekTable::from_seekable(&mut BytesWrapper::new(&seekable)).unwrap();
+        seekable.extend([0xAB; 100]);
+        assert!(Decoder::new(BytesWrapper::new(&seekable)).is_err());
+
+        let mut decoder = DecodeOptions::new(BytesWrapper::new(&seekable))
+            .seek_table_trailing_bytes(100)
+            .into_decoder()
+            .unwrap();
+        assert_eq!(decoder.seek_table(), &st);
+
+        let mut output = vec![0; INPUT.len()];
+        let n = decoder.decompress(&mut output).unwrap();
+        assert_eq!(INPUT.as_bytes(), &output[..n]);
+
+        // Trailing bytes larger than the source
+        assert!(
+            DecodeOptions::new(BytesWrapper::new(&seekable))
+                .seek_table_trailing_bytes(seekable.len() as u64 + 1)
+                .into_decoder()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn access_source() {
+        let seekable = new_seekable(None);
+        let mut decoder = Decoder::new(BytesWrapper::new(&seekable)).unwrap();
+        let mut output = vec![0; INPUT.len()];
+        decoder.decompress(&mut output).unwrap();
+
+        let st = SeekTable::from_seekable(&mut decoder.get_ref().clone()).unwrap();
+        assert_eq!(&st, decoder.seek_table());
+        decoder.get_mut().set_offset(OffsetFrom::Start(0)).unwrap();
+        decoder.reset();
+        let n = decoder.decompress(&mut output).unwrap();
+        assert_eq!(INPUT.as_bytes(), &output[..n]);
+
+        let mut src = decoder.into_inner();
+        assert_eq!(SeekTable::from_seekable(&mut src).unwrap(), st);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn salvage_corrupted_frame() {
+        let mut seekable = new_seekable(Some(FrameSizePolicy::Uncompressed(1024)));
+        let st = SeekTable::from_seekable(&mut BytesWrapper::new(&seekable)).unwrap();
+        let num_frames = st.num_frames();
+        assert!(num_frames > 3);
+
+        // Destroy the magic number of the second frame
+        let pos = st.frame_start_comp(1).unwrap() as usize;
+        seekable[pos..pos + 4].copy_from_slice(&[0; 4]);
+
+        let mut decoder = Decoder::new(BytesWrapper::new(&seekable)).unwrap();
+        let mut output = vec![];
+        let report = decoder.salvage(&mut output).unwrap();
+
+        assert!(!report.is_complete());
+        assert_eq!(report.lost(), &[(1, 1024..2048)]);
+        assert_eq!(report.recovered().len(), num_frames as usize - 1);
+        assert!(report.recovered().iter().all(|(i, _)| *i != 1));
+        assert_eq!(&INPUT.as_bytes()[..1024], &output[..1024]);
+        assert_eq!(&INPUT.as_bytes()[2048..], &output[1024..]);
+        assert_eq!(decoder.offset(), INPUT.len() as u64);
+
+        // Salvage within offset boundaries
+        decoder.set_offset(500).unwrap();
+        decoder.set_offset_limit(2500).unwrap();
+        output.clear();
+        let report = decoder.salvage(&mut output).unwrap();
+        assert_eq!(report.lost(), &[(1, 1024..2048)]);
+        assert_eq!(report.recovered(), &[(0, 500..1024), (2, 2048..2500)]);
+        assert_eq!(&INPUT.as_bytes()[500..1024], &output[..524]);
+        assert_eq!(&INPUT.as_bytes()[2048..2500], &output[524..]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn salvage_source_error() {
+        /// A source that reads at most 256 bytes at once and fails after `reads` reads.
+        struct FailingReads<'a> {
+            src: BytesWrapper<'a>,
+            reads: usize,
+        }
+
+        impl Seekable for FailingReads<'_> {
+            fn set_offset(&mut self, offset: OffsetFrom) -> Result<u64> {
+                self.src.set_offset(offset)
+            }
+
+            fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+                if self.reads == 0 {
+                    return Err(std::io::Error::other("broken source").into());
+                }
+                self.reads -= 1;
+                let len = buf.len().min(256);
+                self.src.read(&mut buf[..len])
+            }
+
+            fn seek_table_integrity(
+                &mut self,
+                format: Format,
+            ) -> Result<[u8; crate::SEEK_TABLE_INTEGRITY_SIZE]> {
+                self.src.seek_table_integrity(format)
+            }
+        }
+
+        let seekable = new_seekable(Some(FrameSizePolicy::Uncompressed(1024)));
+        let st = SeekTable::from_seekable(&mut BytesWrapper::new(&seekable)).unwrap();
+        let src = FailingReads {
+            src: BytesWrapper::new(&seekable),
+            reads: 4,
+        };
+        let mut decoder = DecodeOptions::new(src)
+            .seek_table(st)
+            .into_decoder()
+            .unwrap();
+
+        let err = decoder.salvage(&mut vec![]).unwrap_err();
+        assert!(err.is_io());
+        assert_eq!(decoder.offset_limit(), INPUT.len() as u64);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn decompress_all_stats() {
+        let seekable = new_seekable(Some(FrameSizePolicy::Uncompressed(1024)));
+        let mut decoder = Decoder::new(BytesWrapper::new(&seekable)).unwrap();
+        let num_frames = decoder.seek_table().num_frames();
+
+        let mut output = vec![];
+        let stats = decoder.decompress_all(&mut output).unwrap();
+        assert_eq!(INPUT.as_bytes(), output);
+        assert_eq!(stats.frames(), num_frames);
+        assert_eq!(stats.bytes_in(), decoder.seek_table().size_comp());
+        assert_eq!(stats.bytes_out(), INPUT.len() as u64);
+
+        // Starting in the middle of the second frame and ending in the third frame
+        decoder.set_offset(1500).unwrap();
+        decoder.set_offset_limit(2100).unwrap();
+        output.clear();
+        let stats = decoder.decompress_all(&mut output).unwrap();
+        assert_eq!(INPUT.as_bytes()[1500..2100], output);
+        assert_eq!(stats.frames(), 2);
+        assert_eq!(stats.bytes_out(), 600);
+
+        // Nothing left to decompress
+        let stats = decoder.decompress_all(&mut output).unwrap();
+        assert_eq!(stats.frames(), 0);
+        assert_eq!(stats.bytes_out(), 0);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn decompress_frame_pages() {
+        let seekable = new_seekable(Some(FrameSizePolicy::Uncompressed(1024)));
+        let mut decoder = Decoder::new(BytesWrapper::new(&seekable)).unwrap();
+        let num_frames = decoder.seek_table().num_frames();
+
+        let mut pages = vec![];
+        loop {
+            let mut page = vec![];
+            let n = decoder.decompress_n_frames(3, &mut page).unwrap();
+            if n == 0 {
+                assert!(page.is_empty());
+                break;
+            }
+            pages.push((n, page));
+        }
+        assert_eq!(pages.len(), num_frames.div_ceil(3) as usize);
+        assert!(
+            pages[..pages.len() - 1]
+                .iter()
+                .all(|(n, p)| *n == 3 && p.len() == 3 * 1024)
+        );
+        assert_eq!(pages.iter().map(|(n, _)| n).sum::<u32>(), num_frames);
+        assert_eq!(
+            INPUT.as_bytes(),
+            pages.into_iter().flat_map(|(_, p)| p).collect::<Vec<_>>()
+        );
+
+        // A partially decompressed frame counts as one, the offset limit is respected
+        decoder.set_offset(1500).unwrap();
+        decoder.set_offset_limit(2100).unwrap();
+        let mut page = vec![];
+        assert_eq!(decoder.decompress_n_frames(1, &mut page).unwrap(), 1);
+        assert_eq!(INPUT.as_bytes()[1500..2048], page);
+        page.clear();
+        assert_eq!(decoder.decompress_n_frames(5, &mut page).unwrap(), 1);
+        assert_eq!(INPUT.as_bytes()[2048..2100], page);
+        assert_eq!(decoder.offset_limit(), 2100);
+        assert_eq!(decoder.decompress_n_frames(5, &mut page).unwrap(), 0);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn decompress_range_in_parallel() {
+        let seekable = new_seekable(Some(FrameSizePolicy::Uncompressed(256)));
+        let mut decoder = Decoder::new(BytesWrapper::new(&seekable)).unwrap();
+        decoder.set_offset(100).unwrap();
+        let size = INPUT.len() as u64;
+        let mut out = vec![0; INPUT.len()];
+
+        for (from, to) in [(0, size), (1000, size - 1000), (300, 400), (10, 10)] {
+            out.fill(0);
+            let n = decoder
+                .decompress_range_parallel(from, to, &mut out)
+                .unwrap();
+            assert_eq!(n as u64, to - from);
+            assert_eq!(INPUT.as_bytes()[from as usize..to as usize], out[..n]);
+
+            // Independent of the available CPUs
+            for threads in [2, 3, 64] {
+                out.fill(0);
+                let n = decoder
+                    .decompress_range_threads(from, to, &mut out, threads)
+                    .unwrap();
+                assert_eq!(INPUT.as_bytes()[from as usize..to as usize], out[..n]);
+            }
+        }
+
+        // The decoder is unaffected
+        let n = decoder.decompress(&mut out).unwrap();
+        assert_eq!(INPUT.as_bytes()[100..100 + n], out[..n]);
+
+        assert!(
+            decoder
+                .decompress_range_parallel(0, size + 1, &mut out)
+                .unwrap_err()
+                .is_offset_out_of_range()
+        );
+        assert!(decoder.decompress_range_parallel(2, 1, &mut out).is_err());
+        assert!(
+            decoder
+                .decompress_range_parallel(0, 100, &mut out[..99])
+                .is_err()
+        );
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn spawn_decompress_into_channel() {
+        let seekable = new_seekable(Some(FrameSizePolicy::Uncompressed(1024)));
+        let decoder = Decoder::new(std::io::Cursor::new(seekable)).unwrap();
+        let (tx, mut rx) = tokio::sync::mpsc::channel(2);
+        let handle = spawn_decompress(decoder, tx);
+
+        let mut output = vec![];
+        while let Some(chunk) = rx.recv().await {
+            output.extend(chunk);
+        }
+
+        assert_eq!(handle.await.unwrap().unwrap(), INPUT.len() as u64);
+        assert_eq!(INPUT.as_bytes(), output);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn spawn_decompress_ignores_max_decode_step() {
+        let seekable = new_seekable(Some(FrameSizePolicy::Uncompressed(1024)));
+        // The first step is used up by skipping to the offset
+        let decoder = DecodeOptions::new(std::io::Cursor::new(seekable))
+            .offset(1000)
+            .max_decode_step(64)
+            .into_decoder()
+            .unwrap();
+        let (tx, mut rx) = tokio::sync::mpsc::channel(2);
+        let handle = spawn_decompress(decoder, tx);
+
+        let mut output = vec![];
+        while let Some(chunk) = rx.recv().await {
+            output.extend(chunk);
+        }
+
+        assert_eq!(handle.await.unwrap().unwrap(), INPUT.len() as u64 - 1000);
+        assert_eq!(INPUT.as_bytes()[1000..], output);
+    }
 }
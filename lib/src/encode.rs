@@ -1,23 +1,45 @@
-#[cfg(feature = "std")]
+use alloc::sync::Arc;
 use alloc::vec::Vec;
 use zstd_safe::{
-    CCtx, CParameter, CompressionLevel, InBuffer, OutBuffer, ResetDirective,
-    zstd_sys::ZSTD_EndDirective,
+    CCtx, CDict, CParameter, CompressionLevel, InBuffer, OutBuffer, ResetDirective,
+    zstd_sys::{ZSTD_EndDirective, ZSTD_ErrorCode},
 };
 
 #[cfg(feature = "std")]
-use crate::seek_table::Format;
-use crate::{SEEKABLE_MAX_FRAME_SIZE, SeekTable, error::Result};
+use crate::{ParallelEncoder, seek_table::Format};
+use crate::{
+    SEEKABLE_MAX_FRAME_SIZE, SeekTable,
+    error::{Error, Result},
+    seek_table::{ChecksumAlgo, FrameHasher},
+};
 
 // Constant value always can be casted
 const MAX_FRAME_SIZE: u32 = SEEKABLE_MAX_FRAME_SIZE as u32;
 
+/// Returns the recommended sizes of the input and output buffers for compression.
+///
+/// Buffers of these sizes reduce the amount of data that zstd needs to buffer internally. The
+/// output size is large enough to always flush at least one complete compressed block.
+///
+/// # Examples
+///
+/// ```
+/// let (in_size, out_size) = zeekstd::recommended_compress_buffer_sizes();
+///
+/// let input = vec![0u8; in_size];
+/// let output = vec![0u8; out_size];
+/// ```
+pub fn recommended_compress_buffer_sizes() -> (usize, usize) {
+    (CCtx::in_size(), CCtx::out_size())
+}
+
 /// A policy that controls when new frames are started automatically.
 ///
 /// The uncompressed frame size will never get greater than [`SEEKABLE_MAX_FRAME_SIZE`],
 /// independent of the frame size policy in use, i.e. a new frame will **always** be started if
-/// the uncompressed frame size reaches [`SEEKABLE_MAX_FRAME_SIZE`].
-#[derive(Debug, Clone)]
+/// the uncompressed frame size reaches [`SEEKABLE_MAX_FRAME_SIZE`]. Creating an encoder with a
+/// `Compressed` or `Uncompressed` size greater than [`SEEKABLE_MAX_FRAME_SIZE`] fails.
+#[derive(Clone)]
 pub enum FrameSizePolicy {
     /// Starts a new frame when the compressed size of the current frame exceeds the specified
     /// size.
@@ -28,6 +50,26 @@ pub enum FrameSizePolicy {
     /// Starts a new frame when the uncompressed data of the current frame reaches the specified
     /// size.
     Uncompressed(u32),
+    /// Starts a new frame when the callback returns `true`.
+    ///
+    /// The callback receives the compressed and uncompressed size of the current frame. It is
+    /// consulted before input is consumed, i.e. at the beginning of every compression step, and
+    /// only if the current frame contains data. A frame is therefore only cut between the inputs
+    /// of two compression steps, e.g. pass one record per step to start frames at record
+    /// boundaries. Clones of the policy share the callback.
+    ///
+    /// Like all other variants, this is available without the `std` feature.
+    Custom(Arc<dyn Fn(u32, u32) -> bool + Send + Sync>),
+}
+
+impl core::fmt::Debug for FrameSizePolicy {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Compressed(size) => f.debug_tuple("Compressed").field(size).finish(),
+            Self::Uncompressed(size) => f.debug_tuple("Uncompressed").field(size).finish(),
+            Self::Custom(_) => f.debug_tuple("Custom").finish_non_exhaustive(),
+        }
+    }
 }
 
 impl Default for FrameSizePolicy {
@@ -62,9 +104,22 @@ impl CompressionProgress {
     pub fn out_progress(&self) -> usize {
         self.out_progress
     }
+
+    /// The sum of input and output progress.
+    ///
+    /// A compression step made no progress at all if this is zero.
+    pub fn total(&self) -> usize {
+        self.in_progress + self.out_progress
+    }
 }
 
-/// The progress of writing the frame epilogue.
+impl core::fmt::Display for CompressionProgress {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "in={} out={}", self.in_progress, self.out_progress)
+    }
+}
+
+/// The progress of writing the frame epilogue or flushing the current frame.
 #[derive(Debug)]
 pub struct EpilogueProgress {
     out_progress: usize,
@@ -84,11 +139,24 @@ impl EpilogueProgress {
         self.out_progress
     }
 
-    /// A minimal estimation of the bytes left to flush. The epilogue is entirely written if this
-    /// value is zero.
+    /// A minimal estimation of the bytes left to flush. The epilogue, or the flushed data, is
+    /// entirely written if this value is zero.
     pub fn data_left(&self) -> usize {
         self.data_left
     }
+
+    /// Whether the epilogue, or the flushed data, is entirely written.
+    ///
+    /// This is the case if [`Self::data_left`] is zero.
+    pub fn is_finished(&self) -> bool {
+        self.data_left == 0
+    }
+}
+
+impl core::fmt::Display for EpilogueProgress {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "out={} left={}", self.out_progress, self.data_left)
+    }
 }
 
 /// Options that configure how data is compressed.
@@ -101,7 +169,8 @@ impl EpilogueProgress {
 /// use zeekstd::{EncodeOptions, FrameSizePolicy};
 ///
 /// let encoder = EncodeOptions::new()
-///     .checksum_flag(false)
+///     .content_checksum(false)
+///     .seek_table_checksum(true)
 ///     .compression_level(5)
 ///     .frame_size_policy(FrameSizePolicy::Uncompressed(8192))
 ///     .into_raw_encoder()?;
@@ -109,9 +178,32 @@ impl EpilogueProgress {
 /// ```
 pub struct EncodeOptions<'a> {
     cctx: CCtx<'a>,
+    seek_table: Option<SeekTable>,
+    settings: Settings<'a>,
+}
+
+/// All options that are not bound to a single encoder, i.e. everything except the compression
+/// context and the seek table.
+#[derive(Clone)]
+struct Settings<'a> {
     frame_policy: FrameSizePolicy,
-    checksum_flag: bool,
+    content_checksum: bool,
+    content_size: bool,
+    pledged_src_size: Option<u64>,
+    seek_table_checksum: bool,
+    seek_table_dictionary_id: bool,
+    checksum_algo: ChecksumAlgo,
     compression_level: CompressionLevel,
+    deterministic: bool,
+    dictionary: Option<&'a [u8]>,
+    prefix_dictionary: Option<&'a [u8]>,
+    long_distance_matching: bool,
+    window_log: Option<u32>,
+    #[cfg(feature = "zstdmt")]
+    workers: Option<u32>,
+    parameters: Vec<CParameter>,
+    #[cfg(feature = "std")]
+    length_prefixed: bool,
 }
 
 impl Default for EncodeOptions<'_> {
@@ -142,9 +234,27 @@ impl<'a> EncodeOptions<'a> {
     pub fn with_cctx(cctx: CCtx<'a>) -> Self {
         Self {
             cctx,
-            frame_policy: FrameSizePolicy::default(),
-            checksum_flag: false,
-            compression_level: CompressionLevel::default(),
+            seek_table: None,
+            settings: Settings {
+                frame_policy: FrameSizePolicy::default(),
+                content_checksum: false,
+                content_size: false,
+                pledged_src_size: None,
+                seek_table_checksum: false,
+                seek_table_dictionary_id: false,
+                checksum_algo: ChecksumAlgo::default(),
+                compression_level: CompressionLevel::default(),
+                deterministic: false,
+                dictionary: None,
+                prefix_dictionary: None,
+                long_distance_matching: false,
+                window_log: None,
+                #[cfg(feature = "zstdmt")]
+                workers: None,
+                parameters: Vec::new(),
+                #[cfg(feature = "std")]
+                length_prefixed: false,
+            },
         }
     }
 
@@ -156,19 +266,238 @@ impl<'a> EncodeOptions<'a> {
 
     /// Sets a [`FrameSizePolicy`].
     pub fn frame_size_policy(mut self, policy: FrameSizePolicy) -> Self {
-        self.frame_policy = policy;
+        self.settings.frame_policy = policy;
         self
     }
 
     /// Whether to write 32 bit checksums at the end of frames.
-    pub fn checksum_flag(mut self, flag: bool) -> Self {
-        self.checksum_flag = flag;
+    #[deprecated(note = "use `content_checksum` instead")]
+    pub fn checksum_flag(self, flag: bool) -> Self {
+        self.content_checksum(flag)
+    }
+
+    /// Whether to write 32 bit content checksums at the end of frames.
+    ///
+    /// This sets the content checksum flag in the zstd frame header of every frame, the checksum
+    /// is verified by zstd when a frame is decompressed completely. Independent of
+    /// [`seek_table_checksum`](Self::seek_table_checksum).
+    pub fn content_checksum(mut self, flag: bool) -> Self {
+        self.settings.content_checksum = flag;
+        self
+    }
+
+    /// Whether to write the decompressed size of frames into their zstd frame header.
+    ///
+    /// Some tools read the content size from the frame header, e.g. to allocate the output
+    /// buffer. The size of a frame must be known before it starts, so the content size is only
+    /// written with [`FrameSizePolicy::Uncompressed`] and only for frames whose data is passed to
+    /// a single compression call completely. Other frames, e.g. the last frame that is usually
+    /// shorter, are written without content size. Ending such a frame manually before it is
+    /// complete fails. Disabled by default.
+    pub fn content_size_flag(mut self, flag: bool) -> Self {
+        self.settings.content_size = flag;
+        self
+    }
+
+    /// Sets the total size of the uncompressed data, if it is known up front.
+    ///
+    /// zstd uses the size of a frame to select better compression parameters. The size is
+    /// pledged at the start of every frame, i.e. the remaining uncompressed bytes, capped at the
+    /// frame size. Only applies with [`FrameSizePolicy::Uncompressed`], the size of frames is not
+    /// known in advance with other policies. With the
+    /// [content size flag](Self::content_size_flag), the content size is written for every
+    /// frame.
+    ///
+    /// Compression fails if the input doesn't match the pledged size, either when more data is
+    /// passed, or when the last frame is ended too early.
+    pub fn pledged_src_size(mut self, size: u64) -> Self {
+        self.settings.pledged_src_size = Some(size);
+        self
+    }
+
+    /// Whether to store a 32 bit checksum of every frame in the seek table.
+    ///
+    /// The checksum is the lower 32 bits of the XXH64 digest of the decompressed frame data, as
+    /// defined by the seekable format. It increases the size of every seek table entry by 4 bytes.
+    /// Independent of [`content_checksum`](Self::content_checksum).
+    pub fn seek_table_checksum(mut self, flag: bool) -> Self {
+        self.settings.seek_table_checksum = flag;
+        self
+    }
+
+    /// Whether to record the id of the [dictionary](Self::dictionary) in the seek table.
+    ///
+    /// This lets decoders detect which dictionary an archive needs, see
+    /// [`SeekTable::dictionary_id`]. Only has an effect if a dictionary with an id is set, i.e. a
+    /// dictionary trained with zstd. The seek table is then serialized in an extended format that
+    /// readers without support for it reject, see [`SeekTable::set_dictionary_id`].
+    pub fn seek_table_dictionary_id(mut self, flag: bool) -> Self {
+        self.settings.seek_table_dictionary_id = flag;
+        self
+    }
+
+    /// Whether to force deterministic compression output.
+    ///
+    /// If set, compression is forced to be single-threaded, even if worker threads were configured
+    /// with [`workers`](Self::workers), with a [`CParameter::NbWorkers`]
+    /// [parameter](Self::set_parameter) or in the compression context. Compressing the same input with the same parameters then
+    /// produces byte-identical output across runs.
+    ///
+    /// Multi-threaded compression produces a different output than single-threaded compression.
+    /// Additionally, the output can change between zstd versions, parameters like the compression
+    /// level or frame size policy obviously change the output as well.
+    pub fn deterministic(mut self, deterministic: bool) -> Self {
+        self.settings.deterministic = deterministic;
+        self
+    }
+
+    /// Sets the number of worker threads used by zstd to compress frames.
+    ///
+    /// With one or more workers, zstd compresses in the background and compression calls return
+    /// as soon as the input was handed over. Frame boundaries are still controlled by the
+    /// encoder, every frame is flushed completely when it ends, so the frame sizes in the seek
+    /// table are exact. However, compressed output lags behind the consumed input, which makes
+    /// [`FrameSizePolicy::Compressed`] less accurate. Zero disables multi-threading, this is the
+    /// default.
+    ///
+    /// Overridden by [`deterministic`](Self::deterministic).
+    #[cfg(feature = "zstdmt")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "zstdmt")))]
+    pub fn workers(mut self, workers: u32) -> Self {
+        self.settings.workers = Some(workers);
+        self
+    }
+
+    /// Whether an [`Encoder`] prefixes every frame with its compressed length.
+    ///
+    /// If set, the `Encoder` writes the compressed size of every frame as 32 bit little endian
+    /// integer in front of the frame. The seek table is prefixed with its length as well. This
+    /// allows to send an archive over message based transports, a receiver can reassemble the
+    /// frames without knowing the seek table. Use a [`LengthPrefixedReader`] to strip the
+    /// prefixes.
+    ///
+    /// **Note**: This is a zeekstd extension, the output is not compatible with the seekable
+    /// format until the prefixes are removed. It has no effect on a [`RawEncoder`] or an
+    /// `AsyncEncoder`.
+    ///
+    /// [`LengthPrefixedReader`]: crate::LengthPrefixedReader
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn length_prefixed(mut self, prefixed: bool) -> Self {
+        self.settings.length_prefixed = prefixed;
+        self
+    }
+
+    /// Resumes encoding of an existing archive with the given seek table.
+    ///
+    /// The encoder starts with the frames of `seek_table`, new frames are appended to them. Use
+    /// this to add frames to a finished archive without rewriting it: parse its seek table,
+    /// position the output at the end of the last frame, i.e. at [`SeekTable::size_comp`], and
+    /// compress the new data. The old seek table is overwritten by the updated one.
+    ///
+    /// Frame checksums are only serialized if every frame has one. If the archive contains frame
+    /// checksums, enable [`Self::seek_table_checksum`] with the same [`ChecksumAlgo`] to keep
+    /// them.
+    pub fn resume_from(mut self, seek_table: SeekTable) -> Self {
+        self.seek_table = Some(seek_table);
+        self
+    }
+
+    /// Sets the [`ChecksumAlgo`] used for the frame checksums in the seek table.
+    ///
+    /// Only has an effect if [`seek_table_checksum`](Self::seek_table_checksum) is enabled. The
+    /// default is XXH64, as defined by the seekable format.
+    pub fn checksum_algo(mut self, algo: ChecksumAlgo) -> Self {
+        self.settings.checksum_algo = algo;
+        self
+    }
+
+    /// Sets a dictionary that is used to compress every frame.
+    ///
+    /// A dictionary improves the compression ratio of small frames, e.g. when compressing many
+    /// small, similar records. The dictionary is loaded into the compression context once and
+    /// applies to all frames. Frames can only be decompressed with the same dictionary.
+    ///
+    /// `dict` can be a dictionary trained with zstd or raw content, which is detected by the zstd
+    /// dictionary magic number at its beginning.
+    pub fn dictionary(mut self, dict: &'a [u8]) -> Self {
+        self.settings.dictionary = Some(dict);
+        self
+    }
+
+    /// Sets a prefix that is referenced by every frame.
+    ///
+    /// In contrast to [`RawEncoder::compress_with_prefix`], which references the prefix anew at
+    /// the beginning of every frame, the prefix is loaded into the compression context once as
+    /// raw content [dictionary](Self::dictionary) and survives the reset at the end of a frame.
+    /// This avoids building tables for every frame, which makes it considerably faster for small
+    /// frame sizes, at the cost of a lower compression ratio for large prefixes, as a dictionary
+    /// does not benefit from long distance matching.
+    ///
+    /// This replaces a dictionary. The prefix is loaded like a dictionary, so it must not start
+    /// with the zstd dictionary magic number, otherwise it is parsed as trained dictionary. Frames
+    /// can be decompressed with [`DecodeOptions::prefix_seekable`].
+    ///
+    /// [`DecodeOptions::prefix_seekable`]: crate::DecodeOptions::prefix_seekable
+    pub fn persistent_prefix(self, prefix: &'a [u8]) -> Self {
+        self.dictionary(prefix)
+    }
+
+    /// Sets a prefix that is digested into a dictionary once and referenced by every frame.
+    ///
+    /// The prefix is converted to a digested compression dictionary with the configured
+    /// [compression level](Self::compression_level) when the encoder is created. Every frame
+    /// references the digested dictionary instead of building tables from the raw prefix, like
+    /// [`RawEncoder::compress_with_prefix`] does. This trades the memory of the digested
+    /// dictionary for speed, which pays off for many small frames. The dictionary uses the
+    /// compression parameters it was created with, i.e. changing the compression level later on
+    /// has no effect.
+    ///
+    /// This replaces a [dictionary](Self::dictionary) or [persistent
+    /// prefix](Self::persistent_prefix) and must not start with the zstd dictionary magic number.
+    /// Frames can be decompressed with the prefix as [`DecodeOptions::dictionary`].
+    ///
+    /// [`DecodeOptions::dictionary`]: crate::DecodeOptions::dictionary
+    pub fn prefix_as_dictionary(mut self, prefix: &'a [u8]) -> Self {
+        self.settings.prefix_dictionary = Some(prefix);
+        self
+    }
+
+    /// Whether to enable long distance matching.
+    ///
+    /// Long distance matching finds matches far back in the input, at the cost of memory and
+    /// compression speed. Together with a large [`window_log`](Self::window_log), this allows
+    /// to reference large prefixes, e.g. when creating binary patches with
+    /// [`RawEncoder::compress_with_prefix`].
+    pub fn long_distance_matching(mut self, enable: bool) -> Self {
+        self.settings.long_distance_matching = enable;
+        self
+    }
+
+    /// Sets the maximum back-reference distance as a power of two.
+    ///
+    /// By default, the window log is derived from the compression level. To reference a prefix
+    /// entirely, the window must be at least as large as the prefix, i.e. `window_log` must be
+    /// greater than `log2(prefix.len())`. Decompressing data with a window log greater than 27
+    /// requires to raise the window log limit of the decompression context.
+    pub fn window_log(mut self, window_log: u32) -> Self {
+        self.settings.window_log = Some(window_log);
+        self
+    }
+
+    /// Sets an advanced compression parameter.
+    ///
+    /// Parameters are applied to the compression context in the order they were set, after all
+    /// other options, so they take precedence over e.g. the compression level or the window log.
+    /// Only [`deterministic`](Self::deterministic) is applied after them. An invalid parameter results in an error when creating the encoder.
+    pub fn set_parameter(mut self, param: CParameter) -> Self {
+        self.settings.parameters.push(param);
         self
     }
 
     /// Sets the compression level used by zstd.
     pub fn compression_level(mut self, level: CompressionLevel) -> Self {
-        self.compression_level = level;
+        self.settings.compression_level = level;
         self
     }
 
@@ -176,7 +505,8 @@ impl<'a> EncodeOptions<'a> {
     ///
     /// # Errors
     ///
-    /// Fails if the raw encoder cannot be created.
+    /// Fails if the raw encoder cannot be created or if the frame size of the
+    /// [`FrameSizePolicy`] is greater than [`SEEKABLE_MAX_FRAME_SIZE`].
     pub fn into_raw_encoder(self) -> Result<RawEncoder<'a>> {
         RawEncoder::with_opts(self)
     }
@@ -195,7 +525,7 @@ impl<'a> EncodeOptions<'a> {
     ///
     /// let output = File::create("data.zst").unwrap();
     /// let encoder = EncodeOptions::new()
-    ///     .checksum_flag(true)
+    ///     .content_checksum(true)
     ///     .into_encoder(output)
     ///     .unwrap();
     /// ```
@@ -204,6 +534,68 @@ impl<'a> EncodeOptions<'a> {
     pub fn into_encoder<W>(self, writer: W) -> Result<Encoder<'a, W>> {
         Encoder::with_opts(writer, self)
     }
+
+    /// Creates a [`ParallelEncoder`] with the configuration that compresses frames on `threads`
+    /// threads.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the encoder cannot be created or the frame size policy is not
+    /// [`FrameSizePolicy::Uncompressed`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::fs::File;
+    /// use zeekstd::{EncodeOptions, FrameSizePolicy};
+    ///
+    /// let output = File::create("data.zst").unwrap();
+    /// let encoder = EncodeOptions::new()
+    ///     .frame_size_policy(FrameSizePolicy::Uncompressed(1024 * 1024))
+    ///     .into_parallel_encoder(output, 4)
+    ///     .unwrap();
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn into_parallel_encoder<W>(
+        self,
+        writer: W,
+        threads: usize,
+    ) -> Result<ParallelEncoder<'a, W>> {
+        ParallelEncoder::with_opts(writer, self, threads)
+    }
+
+    /// Creates `num` raw encoders with the configuration, and returns them together with the
+    /// uncompressed frame size.
+    ///
+    /// The first encoder uses the configured context and seek table, all others use new contexts
+    /// and empty seek tables. The pledged source size is ignored.
+    #[cfg(feature = "std")]
+    pub(crate) fn into_raw_encoders(mut self, num: usize) -> Result<(Vec<RawEncoder<'a>>, u32)> {
+        let FrameSizePolicy::Uncompressed(frame_size) = self.settings.frame_policy else {
+            return Err(Error::zstd(
+                ZSTD_ErrorCode::ZSTD_error_parameter_unsupported,
+            ));
+        };
+        let frame_size = frame_size.max(1);
+        self.settings.frame_policy = FrameSizePolicy::Uncompressed(frame_size);
+        self.settings.pledged_src_size = None;
+
+        let mut encoders = Vec::with_capacity(num);
+        for _ in 1..num {
+            let cctx = CCtx::try_create()
+                .ok_or(Error::zstd(ZSTD_ErrorCode::ZSTD_error_memory_allocation))?;
+            let opts = EncodeOptions {
+                cctx,
+                seek_table: None,
+                settings: self.settings.clone(),
+            };
+            encoders.push(opts.into_raw_encoder()?);
+        }
+        encoders.insert(0, self.into_raw_encoder()?);
+
+        Ok((encoders, frame_size))
+    }
 }
 
 /// A reusable, seekable encoder.
@@ -247,7 +639,7 @@ impl<'a> EncodeOptions<'a> {
 /// }
 ///
 /// // Finally, append the seek table...
-/// let mut seek_table_serializer = encoder.into_seek_table().into_serializer();
+/// let mut seek_table_serializer = encoder.into_seek_table().into_serializer()?;
 /// loop {
 ///     let n = seek_table_serializer.write_into(&mut buf[out_progress..]);
 ///     if n == 0 {
@@ -264,10 +656,17 @@ impl<'a> EncodeOptions<'a> {
 /// # Ok::<(), zeekstd::Error>(())
 /// ```
 pub struct RawEncoder<'a> {
+    // Declared before the dictionary, so the context is dropped before the referenced dictionary
     cctx: CCtx<'a>,
+    // Only kept alive for the context
+    _cdict: Option<CDict<'static>>,
     frame_policy: FrameSizePolicy,
     frame_c_size: u32,
     frame_d_size: u32,
+    content_size: bool,
+    pledged_remaining: Option<u64>,
+    pending_level: Option<CompressionLevel>,
+    hasher: Option<FrameHasher>,
     seek_table: SeekTable,
 }
 
@@ -276,19 +675,83 @@ impl<'a> RawEncoder<'a> {
     ///
     /// # Errors
     ///
-    /// Fails if the raw encoder could not be created.
+    /// Fails if the raw encoder could not be created or if the frame size of the
+    /// [`FrameSizePolicy`] is greater than [`SEEKABLE_MAX_FRAME_SIZE`].
     pub fn with_opts(mut opts: EncodeOptions<'a>) -> Result<Self> {
+        if matches!(
+            opts.settings.frame_policy,
+            FrameSizePolicy::Compressed(size) | FrameSizePolicy::Uncompressed(size)
+                if size > MAX_FRAME_SIZE
+        ) {
+            return Err(Error::frame_size_too_large());
+        }
+        opts.cctx.set_parameter(CParameter::CompressionLevel(
+            opts.settings.compression_level,
+        ))?;
         opts.cctx
-            .set_parameter(CParameter::CompressionLevel(opts.compression_level))?;
+            .set_parameter(CParameter::ChecksumFlag(opts.settings.content_checksum))?;
         opts.cctx
-            .set_parameter(CParameter::ChecksumFlag(opts.checksum_flag))?;
+            .set_parameter(CParameter::ContentSizeFlag(opts.settings.content_size))?;
+        #[cfg(feature = "zstdmt")]
+        if let Some(workers) = opts.settings.workers {
+            opts.cctx.set_parameter(CParameter::NbWorkers(workers))?;
+        }
+        if let Some(window_log) = opts.settings.window_log {
+            opts.cctx.set_parameter(CParameter::WindowLog(window_log))?;
+        }
+        if opts.settings.long_distance_matching {
+            opts.cctx
+                .set_parameter(CParameter::EnableLongDistanceMatching(true))?;
+        }
+        for param in opts.settings.parameters {
+            opts.cctx.set_parameter(param)?;
+        }
+        // Applied last, so that worker threads set in any way are disabled
+        if opts.settings.deterministic {
+            opts.cctx.set_parameter(CParameter::NbWorkers(0))?;
+        }
+        // The dictionary is kept when the session is reset at the end of a frame
+        let mut dictionary_id = None;
+        if let Some(dict) = opts.settings.dictionary {
+            opts.cctx.load_dictionary(dict)?;
+            dictionary_id = zstd_safe::get_dict_id_from_dict(dict);
+        }
+        let cdict = opts
+            .settings
+            .prefix_dictionary
+            .map(|prefix| {
+                CDict::try_create(prefix, opts.settings.compression_level)
+                    .ok_or(Error::zstd(ZSTD_ErrorCode::ZSTD_error_memory_allocation))
+            })
+            .transpose()?;
+        if let Some(cdict) = &cdict {
+            opts.cctx.ref_cdict(cdict)?;
+            dictionary_id = cdict.get_dict_id();
+        }
+        let mut seek_table = opts.seek_table.unwrap_or_default();
+        if let Some(id) = dictionary_id.filter(|_| opts.settings.seek_table_dictionary_id) {
+            seek_table.set_dictionary_id(Some(id.get()));
+        }
+        // Frame sizes are only known in advance with the uncompressed policy
+        let pledged_remaining = opts
+            .settings
+            .pledged_src_size
+            .filter(|_| matches!(opts.settings.frame_policy, FrameSizePolicy::Uncompressed(_)));
 
         Ok(Self {
             cctx: opts.cctx,
-            frame_policy: opts.frame_policy,
+            _cdict: cdict,
+            frame_policy: opts.settings.frame_policy,
             frame_c_size: 0,
             frame_d_size: 0,
-            seek_table: SeekTable::new(),
+            content_size: opts.settings.content_size,
+            pledged_remaining,
+            pending_level: None,
+            hasher: opts
+                .settings
+                .seek_table_checksum
+                .then(|| FrameHasher::new(opts.settings.checksum_algo)),
+            seek_table,
         })
     }
 
@@ -303,7 +766,8 @@ impl<'a> RawEncoder<'a> {
     /// end of frame. Referencing a prefix involves building tables, which is a CPU consuming
     /// operation, with non-negligible impact on latency. This should be avoided for small frame
     /// sizes. If there is a need to use the same prefix multiple times without long distance mode,
-    /// consider loading a dictionary into the compression context instead.
+    /// consider using a
+    /// [persistent prefix](EncodeOptions::persistent_prefix) instead.
     ///
     /// # Errors
     ///
@@ -326,14 +790,36 @@ impl<'a> RawEncoder<'a> {
 
             Ok(CompressionProgress::new(0, out_progress))
         } else {
-            let limit = input.len().min(self.remaining_frame_size());
+            let limit = input.len().min(self.remaining_in_frame());
             let mut in_buf = InBuffer::around(&input[..limit]);
             let mut out_buf = OutBuffer::around(output);
+            // The compression level can only change between frames
+            if let Some(level) = self.pending_level
+                && self.frame_d_size == 0
+            {
+                self.cctx
+                    .set_parameter(CParameter::CompressionLevel(level))?;
+                self.pending_level = None;
+            }
             // Reference prefix at the beginning of a frame
-            // TODO: chain when stable
-            if let Some(pref) = prefix {
-                if self.frame_d_size == 0 {
-                    self.cctx.ref_prefix(pref)?;
+            if let Some(pref) = prefix
+                && self.frame_d_size == 0
+            {
+                self.cctx.ref_prefix(pref)?;
+            }
+            if self.frame_d_size == 0 {
+                // The size of the frame is known from the pledged size, or if the input contains
+                // the complete frame
+                let frame_size = self.remaining_in_frame() as u64;
+                let pledge = match self.pledged_remaining {
+                    Some(remaining) => Some(remaining.min(frame_size)),
+                    None => (self.content_size
+                        && matches!(self.frame_policy, FrameSizePolicy::Uncompressed(_))
+                        && limit as u64 == frame_size)
+                        .then_some(frame_size),
+                };
+                if pledge.is_some() {
+                    self.cctx.set_pledged_src_size(pledge)?;
                 }
             }
 
@@ -348,6 +834,9 @@ impl<'a> RawEncoder<'a> {
             // Casting should always be fine
             self.frame_c_size += out_buf.pos() as u32;
             self.frame_d_size += in_buf.pos() as u32;
+            if let Some(hasher) = &mut self.hasher {
+                hasher.update(&input[..in_buf.pos()]);
+            }
 
             Ok(CompressionProgress::new(in_buf.pos(), out_buf.pos()))
         }
@@ -399,6 +888,42 @@ impl RawEncoder<'_> {
         self.compress_with_prefix(input, output, None)
     }
 
+    /// Compresses all of `input` and appends the compressed data to `output`.
+    ///
+    /// This calls [`Self::compress`] until `input` has been entirely consumed and grows `output`
+    /// as needed. Like with [`Self::compress`], frames are ended according to the configured
+    /// [`FrameSizePolicy`], the last frame has to be ended manually.
+    ///
+    /// # Errors
+    ///
+    /// If compression fails or any parameter is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zeekstd::RawEncoder;
+    ///
+    /// let mut encoder = RawEncoder::new()?;
+    /// let mut output = vec![];
+    ///
+    /// encoder.compress_all(b"Hello, World!", &mut output)?;
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    pub fn compress_all(&mut self, input: &[u8], output: &mut Vec<u8>) -> Result<()> {
+        let mut in_progress = 0;
+
+        while in_progress < input.len() {
+            let len = output.len();
+            output.resize(len + CCtx::out_size(), 0);
+            let prog = self.compress(&input[in_progress..], &mut output[len..]);
+            // Drop the unused part of output, also if compression failed
+            output.truncate(len + prog.as_ref().map_or(0, CompressionProgress::out_progress));
+            in_progress += prog?.in_progress();
+        }
+
+        Ok(())
+    }
+
     /// Ends the current frame and adds it to the seek table.
     ///
     /// Call this repetitively to write the frame epilogue to `output`. The Encoder terminates
@@ -463,14 +988,75 @@ impl RawEncoder<'_> {
             }
         }
 
+        let checksum = self.hasher.as_ref().map(FrameHasher::checksum);
         self.seek_table
-            .log_frame(self.frame_c_size, self.frame_d_size)?;
+            .log_frame_with_checksum(self.frame_c_size, self.frame_d_size, checksum)?;
+        if let Some(remaining) = &mut self.pledged_remaining {
+            *remaining = remaining.saturating_sub(u64::from(self.frame_d_size));
+        }
         self.reset_frame();
 
         // If we get here the frame is complete
         Ok(EpilogueProgress::new(out_buf.pos(), 0))
     }
 
+    /// Flushes the data of the current frame that was compressed so far, without ending the
+    /// frame.
+    ///
+    /// Call this repetitively until no more bytes are left to flush. Unlike [`end_frame`], the
+    /// frame remains open and compression continues within the same frame afterwards. Flushing
+    /// often reduces the compression ratio.
+    ///
+    /// [`end_frame`]: Self::end_frame
+    ///
+    /// # Errors
+    ///
+    /// Fails if the compressed data cannot be flushed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zeekstd::RawEncoder;
+    ///
+    /// let mut encoder = RawEncoder::new()?;
+    /// let mut output: Vec<u8> = vec![];
+    /// let mut buf = [0u8; 128];
+    ///
+    /// let prog = encoder.compress(b"Hello, World!", &mut buf)?;
+    /// output.extend(&buf[..prog.out_progress()]);
+    /// loop {
+    ///     let prog = encoder.flush(&mut buf)?;
+    ///     output.extend(&buf[..prog.out_progress()]);
+    ///     if prog.data_left() == 0 {
+    ///         break;
+    ///     }
+    /// }
+    ///
+    /// assert!(!output.is_empty());
+    /// assert_eq!(encoder.seek_table().num_frames(), 0);
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    pub fn flush(&mut self, output: &mut [u8]) -> Result<EpilogueProgress> {
+        let mut empty_buf = InBuffer::around(&[]);
+        let mut out_buf = OutBuffer::around(output);
+
+        let data_left = loop {
+            let n = self.cctx.compress_stream2(
+                &mut out_buf,
+                &mut empty_buf,
+                ZSTD_EndDirective::ZSTD_e_flush,
+            )?;
+            if n == 0 || out_buf.pos() == out_buf.capacity() {
+                break n;
+            }
+        };
+
+        // Casting should always be fine
+        self.frame_c_size += out_buf.pos() as u32;
+
+        Ok(EpilogueProgress::new(out_buf.pos(), data_left))
+    }
+
     /// Returns a reference to the internal [`SeekTable`].
     ///
     /// # Examples
@@ -501,6 +1087,9 @@ impl RawEncoder<'_> {
     pub fn reset_frame(&mut self) {
         self.frame_c_size = 0;
         self.frame_d_size = 0;
+        if let Some(hasher) = &mut self.hasher {
+            hasher.reset();
+        }
         self.cctx
             .reset(ResetDirective::SessionOnly)
             .expect("Resetting session never fails");
@@ -522,41 +1111,164 @@ impl RawEncoder<'_> {
     /// # Ok::<(), zeekstd::Error>(())
     /// ```
     pub fn reset_seek_table(&mut self) {
+        let dictionary_id = self.seek_table.dictionary_id();
         self.seek_table = SeekTable::new();
+        self.seek_table.set_dictionary_id(dictionary_id);
     }
 
-    fn remaining_frame_size(&self) -> usize {
-        let n = match self.frame_policy {
-            FrameSizePolicy::Compressed(_) => MAX_FRAME_SIZE - self.frame_d_size,
-            FrameSizePolicy::Uncompressed(limit) => MAX_FRAME_SIZE.min(limit) - self.frame_d_size,
-        };
+    /// Sets the compression level for the following frames.
+    ///
+    /// The compression level cannot change within a frame. If a frame is in progress, the new
+    /// level takes effect after the current frame ends, otherwise it applies to the next frame.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the compression level cannot be set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zeekstd::RawEncoder;
+    ///
+    /// let mut encoder = RawEncoder::new()?;
+    /// let mut buf = [0u8; 128];
+    ///
+    /// encoder.set_compression_level(1)?;
+    /// encoder.compress(b"Hello", &mut buf)?;
+    /// // Applies to the next frame
+    /// encoder.set_compression_level(19)?;
+    /// encoder.end_frame(&mut buf)?;
+    /// encoder.compress(b", World!", &mut buf)?;
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    pub fn set_compression_level(&mut self, level: CompressionLevel) -> Result<()> {
+        if self.frame_d_size == 0 {
+            self.cctx
+                .set_parameter(CParameter::CompressionLevel(level))?;
+            self.pending_level = None;
+        } else {
+            self.pending_level = Some(level);
+        }
 
-        n.try_into().expect("Remaining frame size fits in usize")
+        Ok(())
     }
 
-    fn is_frame_complete(&self) -> bool {
-        match self.frame_policy {
-            FrameSizePolicy::Compressed(size) => {
-                size <= self.frame_c_size || MAX_FRAME_SIZE <= self.frame_d_size
-            }
-            FrameSizePolicy::Uncompressed(limit) => MAX_FRAME_SIZE.min(limit) <= self.frame_d_size,
-        }
+    /// The position in the uncompressed data, i.e. the number of uncompressed bytes in all
+    /// frames of the seek table plus the current frame.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zeekstd::RawEncoder;
+    ///
+    /// let mut encoder = RawEncoder::new()?;
+    /// let mut buf = [0u8; 128];
+    ///
+    /// encoder.compress(b"Hello", &mut buf)?;
+    /// encoder.end_frame(&mut buf)?;
+    /// encoder.compress(b", World!", &mut buf)?;
+    /// assert_eq!(encoder.uncompressed_pos(), 13);
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    pub fn uncompressed_pos(&self) -> u64 {
+        self.seek_table.size_decomp() + u64::from(self.frame_d_size)
     }
-}
 
-/// A single-use seekable encoder.
-///
-/// The `Encoder` starts new frames automatically at 2MiB of uncompressed data by default. See
-/// [`EncodeOptions`] to change this and other compression parameters.
-///
-/// # Examples
-///
-/// Creates a seekable compressed file using an `Encoder`.
-///
-/// ```no_run
-/// use std::{fs::File, io};
-/// use zeekstd::Encoder;
-///
+    /// The number of uncompressed bytes that were consumed in the current frame.
+    ///
+    /// Together with [`Self::remaining_in_frame`], this allows to align frame boundaries with
+    /// record sizes of the input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zeekstd::RawEncoder;
+    ///
+    /// let mut encoder = RawEncoder::new()?;
+    /// let mut buf = [0u8; 128];
+    ///
+    /// encoder.compress(b"Hello", &mut buf)?;
+    /// assert_eq!(encoder.frame_uncompressed_len(), 5);
+    /// encoder.end_frame(&mut buf)?;
+    /// assert_eq!(encoder.frame_uncompressed_len(), 0);
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    pub fn frame_uncompressed_len(&self) -> u32 {
+        self.frame_d_size
+    }
+
+    /// The number of uncompressed bytes that still fit into the current frame.
+    ///
+    /// With [`FrameSizePolicy::Uncompressed`], the current frame is complete after this many
+    /// bytes have been consumed. Callers can use this to align their input with frame boundaries,
+    /// e.g. by compressing exactly the remaining bytes and then calling [`Self::end_frame`]. With
+    /// [`FrameSizePolicy::Compressed`] and [`FrameSizePolicy::Custom`], a frame may be completed
+    /// earlier, the returned value only accounts for the maximum frame size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zeekstd::{EncodeOptions, FrameSizePolicy};
+    ///
+    /// let mut encoder = EncodeOptions::new()
+    ///     .frame_size_policy(FrameSizePolicy::Uncompressed(10))
+    ///     .into_raw_encoder()?;
+    /// let mut buf = [0u8; 128];
+    ///
+    /// let prog = encoder.compress(b"Hello", &mut buf)?;
+    /// assert_eq!(prog.in_progress(), 5);
+    /// assert_eq!(encoder.remaining_in_frame(), 5);
+    ///
+    /// encoder.compress(b", World!", &mut buf)?;
+    /// assert_eq!(encoder.remaining_in_frame(), 0);
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    #[allow(clippy::missing_panics_doc)]
+    pub fn remaining_in_frame(&self) -> usize {
+        let n = match self.frame_policy {
+            FrameSizePolicy::Compressed(_) | FrameSizePolicy::Custom(_) => {
+                MAX_FRAME_SIZE - self.frame_d_size
+            }
+            FrameSizePolicy::Uncompressed(limit) => MAX_FRAME_SIZE.min(limit) - self.frame_d_size,
+        };
+
+        n.try_into().expect("Remaining frame size fits in usize")
+    }
+
+    /// The number of uncompressed bytes that still fit into the current frame.
+    ///
+    /// Same as [`Self::remaining_in_frame`].
+    pub fn remaining_frame_capacity(&self) -> usize {
+        self.remaining_in_frame()
+    }
+
+    fn is_frame_complete(&mut self) -> bool {
+        match &self.frame_policy {
+            FrameSizePolicy::Compressed(size) => {
+                *size <= self.frame_c_size || MAX_FRAME_SIZE <= self.frame_d_size
+            }
+            FrameSizePolicy::Uncompressed(limit) => MAX_FRAME_SIZE.min(*limit) <= self.frame_d_size,
+            FrameSizePolicy::Custom(cut) => {
+                MAX_FRAME_SIZE <= self.frame_d_size
+                    || (self.frame_d_size > 0 && cut(self.frame_c_size, self.frame_d_size))
+            }
+        }
+    }
+}
+
+/// A single-use seekable encoder.
+///
+/// The `Encoder` starts new frames automatically at 2MiB of uncompressed data by default. See
+/// [`EncodeOptions`] to change this and other compression parameters.
+///
+/// # Examples
+///
+/// Creates a seekable compressed file using an `Encoder`.
+///
+/// ```no_run
+/// use std::{fs::File, io};
+/// use zeekstd::Encoder;
+///
 /// let mut input = File::open("foo")?;
 /// let output = File::create("foo.zst")?;
 /// let mut encoder = Encoder::new(output)?;
@@ -573,6 +1285,9 @@ pub struct Encoder<'a, W> {
     out_buf_pos: usize,
     writer: W,
     written_compressed: u64,
+    written_uncompressed: u64,
+    // Buffers the current frame if frames are length prefixed
+    frame_buf: Option<Vec<u8>>,
 }
 
 #[cfg(feature = "std")]
@@ -594,12 +1309,16 @@ impl<'a, W> Encoder<'a, W> {
     ///
     /// Fails if the encoder could not be created.
     pub fn with_opts(writer: W, opts: EncodeOptions<'a>) -> Result<Self> {
+        let frame_buf = opts.settings.length_prefixed.then(Vec::new);
+
         Ok(Self {
             raw: opts.into_raw_encoder()?,
             out_buf: alloc::vec![0; CCtx::out_size()],
             out_buf_pos: 0,
             writer,
             written_compressed: 0,
+            written_uncompressed: 0,
+            frame_buf,
         })
     }
 }
@@ -616,10 +1335,23 @@ impl<W> Encoder<'_, W> {
         self.written_compressed
     }
 
+    /// The total number of uncompressed bytes that have been consumed by this encoder.
+    pub fn written_uncompressed(&self) -> u64 {
+        self.written_uncompressed
+    }
+
     /// Converts this encoder into the internal [`SeekTable`].
     pub fn into_seek_table(self) -> SeekTable {
         self.raw.into_seek_table()
     }
+
+    /// The number of compressed bytes that are buffered and not yet written to the internal
+    /// writer.
+    ///
+    /// Data that is buffered by the compression context itself is not included.
+    pub fn pending_output(&self) -> usize {
+        self.out_buf_pos + self.frame_buf.as_ref().map_or(0, Vec::len)
+    }
 }
 
 #[cfg(feature = "std")]
@@ -633,7 +1365,8 @@ impl<'a, W: std::io::Write> Encoder<'a, W> {
     /// end of frame. Referencing a prefix involves building tables, which is a CPU consuming
     /// operation, with non-negligible impact on latency. This should be avoided for small frame
     /// sizes. If there is a need to use the same prefix multiple times without long distance mode,
-    /// consider loading a dictionary into the compression context instead.
+    /// consider using a
+    /// [persistent prefix](EncodeOptions::persistent_prefix) instead.
     ///
     /// # Errors
     ///
@@ -646,6 +1379,7 @@ impl<'a, W: std::io::Write> Encoder<'a, W> {
         let mut input_progress = 0;
 
         while input_progress < buf.len() {
+            let num_frames = self.raw.seek_table.num_frames();
             let progress = self.raw.compress_with_prefix(
                 &buf[input_progress..],
                 &mut self.out_buf[self.out_buf_pos..],
@@ -658,7 +1392,9 @@ impl<'a, W: std::io::Write> Encoder<'a, W> {
 
             self.out_buf_pos += progress.out_progress;
             self.flush_out_buf(false)?;
+            self.write_prefixed_frame(num_frames)?;
             input_progress += progress.in_progress;
+            self.written_uncompressed += progress.in_progress as u64;
         }
 
         Ok(input_progress)
@@ -693,6 +1429,49 @@ impl<W: std::io::Write> Encoder<'_, W> {
         self.compress_with_prefix(buf, None)
     }
 
+    /// Reads `reader` to the end and compresses everything that was read.
+    ///
+    /// Input is read in chunks of [`CCtx::in_size()`] bytes. Returns the number of bytes read
+    /// from `reader`. This doesn't end the current frame.
+    ///
+    /// # Errors
+    ///
+    /// If reading from `reader` or compression fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::fs::File;
+    /// use zeekstd::Encoder;
+    ///
+    /// let mut input = File::open("data")?;
+    /// let output = File::create("data.zst")?;
+    /// let mut encoder = Encoder::new(output)?;
+    ///
+    /// encoder.compress_from(&mut input)?;
+    /// encoder.finish()?;
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    pub fn compress_from<R: std::io::Read>(&mut self, reader: &mut R) -> Result<u64> {
+        let mut buf = alloc::vec![0; CCtx::in_size()];
+        let mut read = 0;
+
+        loop {
+            let limit = match reader.read(&mut buf) {
+                Ok(0) => return Ok(read),
+                Ok(n) => n,
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e.into()),
+            };
+            read += limit as u64;
+
+            let mut pos = 0;
+            while pos < limit {
+                pos += self.compress(&buf[pos..limit])?;
+            }
+        }
+    }
+
     /// Ends the current frame.
     ///
     /// Call this to write the frame epilogue to the internal writer. Returns the number of bytes
@@ -705,9 +1484,11 @@ impl<W: std::io::Write> Encoder<'_, W> {
         let mut progress = 0;
 
         loop {
+            let num_frames = self.raw.seek_table.num_frames();
             let prog = self.raw.end_frame(&mut self.out_buf[self.out_buf_pos..])?;
             self.out_buf_pos += prog.out_progress;
             self.flush_out_buf(false)?;
+            self.write_prefixed_frame(num_frames)?;
             progress += prog.out_progress;
 
             if prog.data_left == 0 {
@@ -754,7 +1535,14 @@ impl<W: std::io::Write> Encoder<'_, W> {
     /// Fails if the frame cannot be finished or writing the seek table fails.
     pub fn finish_format(mut self, format: Format) -> Result<u64> {
         self.end_frame()?;
-        let mut ser = self.raw.into_seek_table().into_format_serializer(format);
+        let mut ser = self.raw.into_seek_table().into_format_serializer(format)?;
+
+        if self.frame_buf.is_some() {
+            // The frame has been written entirely, the output buffer is empty
+            let len = u32::try_from(ser.encoded_len())?;
+            self.writer.write_all(&len.to_le_bytes())?;
+            self.written_compressed += 4;
+        }
 
         loop {
             let n = ser.write_into(&mut self.out_buf[self.out_buf_pos..]);
@@ -774,39 +1562,166 @@ impl<W: std::io::Write> Encoder<'_, W> {
         }
     }
 
+    /// Writes all buffered compressed data to the internal writer.
+    ///
+    /// In contrast to [`flush`](std::io::Write::flush), this neither flushes the compression
+    /// context nor the internal writer. Returns the number of bytes written to the writer. If
+    /// frames are length prefixed, data of the current frame stays buffered until the frame is
+    /// complete.
+    ///
+    /// # Errors
+    ///
+    /// If writing to the internal writer fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zeekstd::Encoder;
+    ///
+    /// let mut output = vec![];
+    /// let mut encoder = Encoder::new(&mut output)?;
+    /// encoder.compress(b"Hello, World!")?;
+    /// encoder.end_frame()?;
+    ///
+    /// let pending = encoder.pending_output();
+    /// assert_eq!(encoder.try_flush()?, pending);
+    /// assert_eq!(encoder.pending_output(), 0);
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    pub fn try_flush(&mut self) -> Result<usize> {
+        let written = self.written_compressed;
+        self.flush_out_buf(true)?;
+
+        // Casting should always be fine, at most the size of the output buffer is written
+        Ok((self.written_compressed - written) as usize)
+    }
+
     /// Flushes the internal output buffer, if it is filled with data, or force is true.
+    ///
+    /// If frames are length prefixed, the data is moved to the frame buffer instead.
     #[inline]
     fn flush_out_buf(&mut self, force: bool) -> Result<()> {
         if self.out_buf_pos == self.out_buf.len() || force {
-            self.writer.write_all(&self.out_buf[..self.out_buf_pos])?;
-            self.written_compressed += self.out_buf_pos as u64;
+            if let Some(frame_buf) = &mut self.frame_buf {
+                frame_buf.extend_from_slice(&self.out_buf[..self.out_buf_pos]);
+            } else {
+                self.writer.write_all(&self.out_buf[..self.out_buf_pos])?;
+                self.written_compressed += self.out_buf_pos as u64;
+            }
             self.out_buf_pos = 0;
         }
 
         Ok(())
     }
+
+    /// Writes the buffered frame with its length prefix, if frames are length prefixed and the
+    /// last compression step completed a frame.
+    ///
+    /// `num_frames` is the number of frames before the last compression step.
+    fn write_prefixed_frame(&mut self, num_frames: u32) -> Result<()> {
+        if self.frame_buf.is_none() || self.raw.seek_table.num_frames() == num_frames {
+            return Ok(());
+        }
+
+        self.flush_out_buf(true)?;
+        if let Some(frame_buf) = &mut self.frame_buf {
+            let len = u32::try_from(frame_buf.len())?;
+            self.writer.write_all(&len.to_le_bytes())?;
+            self.writer.write_all(frame_buf)?;
+            self.written_compressed += 4 + frame_buf.len() as u64;
+            frame_buf.clear();
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(feature = "std")]
 impl<W: std::io::Write> std::io::Write for Encoder<'_, W> {
     fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
-        self.compress(buf).map_err(std::io::Error::other)
+        Ok(self.compress(buf)?)
     }
 
     fn flush(&mut self) -> std::io::Result<()> {
-        self.flush_out_buf(true).map_err(std::io::Error::other)?;
+        self.flush_out_buf(true)?;
         self.writer.flush()
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use alloc::string::ToString;
     use alloc::vec;
 
     use crate::tests::INPUT;
 
     use super::*;
 
+    /// Compresses `input` and ends the last frame, returns the compressed frames.
+    ///
+    /// Uses a small output buffer, so that compression is interrupted regularly.
+    fn compress_frames<'a>(
+        encoder: &mut RawEncoder<'a>,
+        input: &[u8],
+        prefix: Option<&'a [u8]>,
+    ) -> Vec<u8> {
+        let mut frames = vec![];
+        let mut buf = vec![0; 64];
+        let mut in_progress = 0;
+        while in_progress < input.len() {
+            let prog = encoder
+                .compress_with_prefix(&input[in_progress..], &mut buf, prefix)
+                .unwrap();
+            frames.extend(&buf[..prog.out_progress]);
+            in_progress += prog.in_progress;
+        }
+        loop {
+            let prog = encoder.end_frame(&mut buf).unwrap();
+            frames.extend(&buf[..prog.out_progress]);
+            if prog.data_left == 0 {
+                break;
+            }
+        }
+
+        frames
+    }
+
+    /// Like [`compress_frames`], but appends the seek table to the compressed frames.
+    fn compress_seekable(mut encoder: RawEncoder<'_>, input: &[u8]) -> Vec<u8> {
+        let mut seekable = compress_frames(&mut encoder, input, None);
+        let mut ser = encoder.into_seek_table().into_serializer().unwrap();
+        let n = seekable.len();
+        seekable.resize(n + ser.encoded_len(), 0);
+        ser.write_into(&mut seekable[n..]);
+
+        seekable
+    }
+
+    #[test]
+    fn frame_size_too_large() {
+        for policy in [
+            FrameSizePolicy::Compressed(MAX_FRAME_SIZE + 1),
+            FrameSizePolicy::Uncompressed(MAX_FRAME_SIZE + 1),
+            FrameSizePolicy::Uncompressed(u32::MAX),
+        ] {
+            let res = EncodeOptions::new()
+                .frame_size_policy(policy)
+                .into_raw_encoder();
+            assert!(res.is_err_and(|e| e.is_frame_size_too_large()));
+        }
+
+        for policy in [
+            FrameSizePolicy::Compressed(MAX_FRAME_SIZE),
+            FrameSizePolicy::Uncompressed(MAX_FRAME_SIZE),
+            FrameSizePolicy::Custom(Arc::new(|_, _| false)),
+        ] {
+            let res = EncodeOptions::new()
+                .frame_size_policy(policy)
+                .into_raw_encoder();
+            assert!(res.is_ok());
+        }
+    }
+
     #[test]
     fn raw_encoder_reset() {
         let mut encoder = RawEncoder::new().unwrap();
@@ -831,33 +1746,139 @@ mod tests {
     }
 
     #[test]
-    fn checksum() {
-        let mut seekable = vec![];
+    fn compress_all() {
         let mut encoder = EncodeOptions::new()
-            .checksum_flag(true)
-            .frame_size_policy(FrameSizePolicy::Uncompressed(INPUT.len() as u32 / 3))
+            .frame_size_policy(FrameSizePolicy::Uncompressed(1024))
             .into_raw_encoder()
             .unwrap();
-
-        let mut buf = vec![0; INPUT.len()];
-
+        let mut expected: Vec<u8> = vec![];
+        let mut buf = vec![0; 64];
         let mut in_progress = 0;
         while in_progress < INPUT.len() {
-            let progress = encoder
+            let prog = encoder
                 .compress(&INPUT.as_bytes()[in_progress..], &mut buf)
                 .unwrap();
-            seekable.extend(&buf[..progress.out_progress]);
-            in_progress += progress.in_progress;
+            expected.extend(&buf[..prog.out_progress()]);
+            in_progress += prog.in_progress();
         }
 
+        let mut encoder = EncodeOptions::new()
+            .frame_size_policy(FrameSizePolicy::Uncompressed(1024))
+            .into_raw_encoder()
+            .unwrap();
+        let mut output = vec![1, 2, 3];
+        encoder.compress_all(INPUT.as_bytes(), &mut output).unwrap();
+        assert_eq!(output[..3], [1, 2, 3]);
+        assert_eq!(output[3..], expected);
+
+        // Nothing to compress
+        let len = output.len();
+        encoder.compress_all(&[], &mut output).unwrap();
+        assert_eq!(output.len(), len);
+    }
+
+    #[test]
+    fn progress_helpers() {
+        let prog = CompressionProgress::new(12, 34);
+        assert_eq!(prog.total(), 46);
+        assert_eq!(prog.to_string(), "in=12 out=34");
+        assert_eq!(CompressionProgress::new(0, 0).total(), 0);
+
+        let prog = EpilogueProgress::new(12, 3);
+        assert!(!prog.is_finished());
+        assert_eq!(prog.to_string(), "out=12 left=3");
+        assert!(EpilogueProgress::new(12, 0).is_finished());
+
+        let mut encoder = RawEncoder::new().unwrap();
+        let mut buf = vec![0; 1024];
+        let prog = encoder.compress(b"Hello", &mut buf).unwrap();
+        assert_eq!(prog.total(), prog.in_progress() + prog.out_progress());
+        let mut prog = encoder.end_frame(&mut buf[..4]).unwrap();
+        while !prog.is_finished() {
+            prog = encoder.end_frame(&mut buf).unwrap();
+        }
+        assert_eq!(encoder.seek_table().num_frames(), 1);
+    }
+
+    #[test]
+    fn remaining_in_frame() {
+        let mut encoder = EncodeOptions::new()
+            .frame_size_policy(FrameSizePolicy::Uncompressed(1000))
+            .into_raw_encoder()
+            .unwrap();
+        let mut buf = vec![0; 4096];
+
+        assert_eq!(encoder.remaining_in_frame(), 1000);
+        assert_eq!(encoder.remaining_frame_capacity(), 1000);
+        for (i, chunk) in INPUT.as_bytes()[..900].chunks(300).enumerate() {
+            let before = encoder.remaining_in_frame();
+            let prog = encoder.compress(chunk, &mut buf).unwrap();
+            assert_eq!(prog.in_progress(), 300);
+            assert_eq!(encoder.remaining_in_frame(), before - 300);
+            assert_eq!(encoder.frame_uncompressed_len() as usize, (i + 1) * 300);
+        }
+
+        // Only the remaining capacity is consumed
+        let prog = encoder
+            .compress(&INPUT.as_bytes()[900..1200], &mut buf)
+            .unwrap();
+        assert_eq!(prog.in_progress(), 100);
+        assert_eq!(encoder.remaining_in_frame(), 0);
+        assert_eq!(encoder.frame_uncompressed_len(), 1000);
+    }
+
+    #[test]
+    fn set_compression_level() {
+        let data = INPUT.as_bytes()[..4096].repeat(4);
+        let mut encoder = EncodeOptions::new()
+            .frame_size_policy(FrameSizePolicy::Uncompressed(4096))
+            .into_raw_encoder()
+            .unwrap();
+        let mut output = vec![];
+
+        encoder.set_compression_level(1).unwrap();
+        encoder.compress_all(&data[..6000], &mut output).unwrap();
+        // Frame 1 is in progress and still uses level 1
+        encoder.set_compression_level(19).unwrap();
+        encoder.compress_all(&data[6000..], &mut output).unwrap();
+        let mut buf = vec![0; 1024];
         loop {
             let prog = encoder.end_frame(&mut buf).unwrap();
-            seekable.extend(&buf[..prog.out_progress]);
-            if prog.data_left == 0 {
+            output.extend(&buf[..prog.out_progress()]);
+            if prog.data_left() == 0 {
                 break;
             }
         }
 
+        let st = encoder.into_seek_table();
+        assert_eq!(st.num_frames(), 4);
+        let sizes: Vec<_> = (0..4).map(|i| st.frame_size_comp(i).unwrap()).collect();
+        assert_eq!(sizes[0], sizes[1]);
+        assert_eq!(sizes[2], sizes[3]);
+        assert!(sizes[2] < sizes[1]);
+
+        let mut ser = st.into_serializer().unwrap();
+        let n = output.len();
+        output.resize(n + ser.encoded_len(), 0);
+        ser.write_into(&mut output[n..]);
+        let mut decoder = crate::Decoder::new(crate::BytesWrapper::new(&output)).unwrap();
+        let mut decompressed = vec![0; data.len()];
+        let mut pos = 0;
+        while pos < decompressed.len() {
+            pos += decoder.decompress(&mut decompressed[pos..]).unwrap();
+        }
+        assert_eq!(decompressed, data);
+    }
+
+    #[test]
+    fn checksum() {
+        let mut encoder = EncodeOptions::new()
+            .content_checksum(true)
+            .frame_size_policy(FrameSizePolicy::Uncompressed(INPUT.len() as u32 / 3))
+            .into_raw_encoder()
+            .unwrap();
+        let seekable = compress_frames(&mut encoder, INPUT.as_bytes(), None);
+
         let num_frames = encoder.seek_table().num_frames();
         let st = encoder.into_seek_table();
         for i in 0..num_frames {
@@ -868,4 +1889,727 @@ mod tests {
             assert!(descriptor & 0x4 > 0);
         }
     }
+
+    #[test]
+    fn content_size() {
+        let compress = |flag: bool| {
+            let mut encoder = EncodeOptions::new()
+                .content_size_flag(flag)
+                .frame_size_policy(FrameSizePolicy::Uncompressed(1000))
+                .into_raw_encoder()
+                .unwrap();
+            let seekable = compress_frames(&mut encoder, INPUT.as_bytes(), None);
+
+            (seekable, encoder.into_seek_table())
+        };
+        let content_sizes = |seekable: &[u8], st: &SeekTable| {
+            (0..st.num_frames())
+                .map(|i| {
+                    let start = st.frame_start_comp(i).unwrap() as usize;
+                    let end = st.frame_end_comp(i).unwrap() as usize;
+                    // Get the Frame_Header_Descriptor field
+                    let descriptor = seekable[start + 4];
+                    let size = zstd_safe::get_frame_content_size(&seekable[start..end]).unwrap();
+                    // The Frame_Content_Size field is present if the flag or the
+                    // Single_Segment_flag is set
+                    assert_eq!(size.is_some(), descriptor & 0xE0 > 0);
+                    size
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let (seekable, st) = compress(true);
+        let sizes = content_sizes(&seekable, &st);
+        let (last, full) = sizes.split_last().unwrap();
+        assert!(full.iter().all(|s| *s == Some(1000)));
+        // The size of the shorter last frame is unknown when it starts
+        assert_eq!(*last, None);
+
+        let (seekable, st) = compress(false);
+        assert!(content_sizes(&seekable, &st).iter().all(Option::is_none));
+    }
+
+    #[test]
+    fn pledged_src_size() {
+        let compress = |pledged: u64| -> Result<Vec<u8>> {
+            let mut encoder = EncodeOptions::new()
+                .pledged_src_size(pledged)
+                .content_size_flag(true)
+                .frame_size_policy(FrameSizePolicy::Uncompressed(1000))
+                .into_raw_encoder()?;
+            let mut seekable = vec![];
+            encoder.compress_all(INPUT.as_bytes(), &mut seekable)?;
+            let mut buf = vec![0; 1024];
+            loop {
+                let prog = encoder.end_frame(&mut buf)?;
+                seekable.extend(&buf[..prog.out_progress]);
+                if prog.data_left == 0 {
+                    break;
+                }
+            }
+            let mut ser = encoder.into_seek_table().into_serializer()?;
+            let n = seekable.len();
+            seekable.resize(n + ser.encoded_len(), 0);
+            ser.write_into(&mut seekable[n..]);
+
+            Ok(seekable)
+        };
+
+        let seekable = compress(INPUT.len() as u64).unwrap();
+        let mut decoder = crate::Decoder::new(crate::BytesWrapper::new(&seekable)).unwrap();
+        let st = decoder.seek_table().clone();
+        // Every frame has a content size, including the shorter last frame
+        for i in 0..st.num_frames() {
+            let start = st.frame_start_comp(i).unwrap() as usize;
+            let end = st.frame_end_comp(i).unwrap() as usize;
+            let size = zstd_safe::get_frame_content_size(&seekable[start..end]).unwrap();
+            assert_eq!(size, Some(st.frame_size_decomp(i).unwrap()));
+        }
+        let mut output = vec![0; INPUT.len()];
+        let mut pos = 0;
+        while pos < output.len() {
+            pos += decoder.decompress(&mut output[pos..]).unwrap();
+        }
+        assert_eq!(output, INPUT.as_bytes());
+
+        // More or less data than pledged
+        assert!(compress(INPUT.len() as u64 - 1).is_err());
+        assert!(compress(INPUT.len() as u64 + 1).is_err());
+    }
+
+    #[test]
+    fn seek_table_checksum() {
+        let mut encoder = EncodeOptions::new()
+            .content_checksum(false)
+            .seek_table_checksum(true)
+            .frame_size_policy(FrameSizePolicy::Uncompressed(INPUT.len() as u32 / 3))
+            .into_raw_encoder()
+            .unwrap();
+        let mut seekable = compress_frames(&mut encoder, INPUT.as_bytes(), None);
+
+        let st = encoder.into_seek_table();
+        assert!(st.num_frames() > 1);
+        for i in 0..st.num_frames() {
+            let start_pos = st.frame_start_comp(i).unwrap();
+            // Check that the Content_Checksum_flag is not set
+            assert_eq!(seekable[start_pos as usize + 4] & 0x4, 0);
+        }
+
+        let mut ser = st.into_serializer().unwrap();
+        let mut st_buf = vec![0; ser.encoded_len()];
+        ser.write_into(&mut st_buf);
+        // Check that the checksum flag of the seek table descriptor is set
+        assert!(st_buf[st_buf.len() - 5] & (1 << 7) > 0);
+        seekable.extend(&st_buf);
+
+        // The reference implementation verifies the checksums stored in the seek table
+        let mut reference = zstd_safe::seekable::Seekable::create();
+        reference.init_buff(&seekable).unwrap();
+        let mut output = vec![0; INPUT.len()];
+        let mut out_pos = 0;
+        while out_pos < output.len() {
+            let n = reference
+                .decompress(&mut output[out_pos..], out_pos as u64)
+                .unwrap();
+            out_pos += n;
+        }
+        assert_eq!(INPUT.as_bytes(), output);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn length_prefixed_frames() {
+        use std::io::Read;
+
+        use crate::{BytesWrapper, Decoder, LengthPrefixedReader};
+
+        let encode = |prefixed: bool| {
+            let mut output = vec![];
+            let mut encoder = EncodeOptions::new()
+                .frame_size_policy(FrameSizePolicy::Uncompressed(1024))
+                .length_prefixed(prefixed)
+                .into_encoder(&mut output)
+                .unwrap();
+            encoder.compress(INPUT.as_bytes()).unwrap();
+            let num_frames = encoder.seek_table().num_frames() + 1;
+            let n = encoder.finish().unwrap();
+            assert_eq!(n, output.len() as u64);
+            (output, num_frames)
+        };
+        let (plain, _) = encode(false);
+        let (prefixed, num_frames) = encode(true);
+        // Every frame and the seek table have a prefix
+        assert_eq!(prefixed.len(), plain.len() + 4 * (num_frames as usize + 1));
+
+        let mut stripped = vec![];
+        LengthPrefixedReader::new(prefixed.as_slice())
+            .read_to_end(&mut stripped)
+            .unwrap();
+        assert_eq!(plain, stripped);
+
+        let mut reader = LengthPrefixedReader::new(prefixed.as_slice());
+        let mut frames = vec![];
+        while let Some(frame) = reader.read_frame().unwrap() {
+            frames.push(frame);
+        }
+        assert_eq!(frames.len(), num_frames as usize + 1);
+        assert_eq!(plain, frames.concat());
+
+        let mut decoder = Decoder::new(BytesWrapper::new(&stripped)).unwrap();
+        let mut output = vec![];
+        decoder.read_to_end(&mut output).unwrap();
+        assert_eq!(INPUT.as_bytes(), output);
+
+        // Data ending within a frame
+        let mut reader = LengthPrefixedReader::new(&prefixed[..prefixed.len() - 1]);
+        assert!(reader.read_to_end(&mut vec![]).is_err());
+    }
+
+    #[test]
+    fn compress_with_dictionary() {
+        use crate::{BytesWrapper, DecodeOptions, Decoder};
+
+        let dict = &INPUT.as_bytes()[..INPUT.len() / 2];
+        let compress = |dict: Option<&'static [u8]>| {
+            let mut opts =
+                EncodeOptions::new().frame_size_policy(FrameSizePolicy::Uncompressed(256));
+            if let Some(dict) = dict {
+                opts = opts.dictionary(dict);
+            }
+            compress_seekable(opts.into_raw_encoder().unwrap(), INPUT.as_bytes())
+        };
+
+        let plain = compress(None);
+        let seekable = compress(Some(dict));
+        assert!(seekable.len() < plain.len());
+
+        let mut decoder = DecodeOptions::new(BytesWrapper::new(&seekable))
+            .dictionary(dict)
+            .into_decoder()
+            .unwrap();
+        let mut output = vec![0; INPUT.len()];
+        let mut out_pos = 0;
+        loop {
+            let n = decoder.decompress(&mut output[out_pos..]).unwrap();
+            if n == 0 {
+                break;
+            }
+            out_pos += n;
+        }
+        assert_eq!(INPUT.as_bytes(), output);
+
+        // Decompression fails without the dictionary
+        let mut decoder = Decoder::new(BytesWrapper::new(&seekable)).unwrap();
+        assert!(decoder.decompress(&mut output).is_err());
+    }
+
+    #[test]
+    fn seek_table_dictionary_id() {
+        use crate::{BytesWrapper, DecodeOptions};
+
+        let samples: Vec<_> = INPUT.as_bytes().chunks(64).collect();
+        let dict: &'static [u8] = zstd::dict::from_samples(&samples, 4096).unwrap().leak();
+        let dict_id = zstd_safe::get_dict_id_from_dict(dict).unwrap().get();
+
+        let compress = |opts: EncodeOptions<'static>| {
+            let mut encoder = opts
+                .frame_size_policy(FrameSizePolicy::Uncompressed(1024))
+                .into_raw_encoder()
+                .unwrap();
+            let mut output = compress_frames(&mut encoder, INPUT.as_bytes(), None);
+            let seek_table = encoder.seek_table().clone();
+            // The dictionary id survives a reset of the seek table
+            encoder.reset_seek_table();
+            assert_eq!(
+                encoder.seek_table().dictionary_id(),
+                seek_table.dictionary_id()
+            );
+            let mut ser = seek_table.into_serializer().unwrap();
+            let n = output.len();
+            output.resize(n + ser.encoded_len(), 0);
+            ser.write_into(&mut output[n..]);
+            output
+        };
+
+        let seekable = compress(
+            EncodeOptions::new()
+                .dictionary(dict)
+                .seek_table_dictionary_id(true),
+        );
+        let mut decoder = DecodeOptions::new(BytesWrapper::new(&seekable))
+            .dictionary(dict)
+            .into_decoder()
+            .unwrap();
+        assert_eq!(decoder.seek_table().dictionary_id(), Some(dict_id));
+        let mut output = vec![0; INPUT.len()];
+        let mut out_pos = 0;
+        loop {
+            let n = decoder.decompress(&mut output[out_pos..]).unwrap();
+            if n == 0 {
+                break;
+            }
+            out_pos += n;
+        }
+        assert_eq!(INPUT.as_bytes(), output);
+
+        // Not recorded without opting in or without a dictionary id
+        for opts in [
+            EncodeOptions::new().dictionary(dict),
+            EncodeOptions::new().seek_table_dictionary_id(true),
+            EncodeOptions::new()
+                .dictionary(&INPUT.as_bytes()[..100])
+                .seek_table_dictionary_id(true),
+        ] {
+            let seekable = compress(opts);
+            let seek_table = SeekTable::from_seekable(&mut BytesWrapper::new(&seekable)).unwrap();
+            assert_eq!(seek_table.dictionary_id(), None);
+        }
+    }
+
+    #[test]
+    fn compress_with_prefix_as_dictionary() {
+        use crate::{BytesWrapper, DecodeOptions};
+
+        let prefix = &INPUT.as_bytes()[..INPUT.len() / 2];
+        let compress = |opts: EncodeOptions<'static>| {
+            let encoder = opts
+                .frame_size_policy(FrameSizePolicy::Uncompressed(256))
+                .into_raw_encoder()
+                .unwrap();
+            compress_seekable(encoder, INPUT.as_bytes())
+        };
+
+        let plain = compress(EncodeOptions::new());
+        let seekable = compress(EncodeOptions::new().prefix_as_dictionary(prefix));
+        assert!(seekable.len() < plain.len());
+        // The prefix replaces a previously set dictionary
+        let replaced = compress(
+            EncodeOptions::new()
+                .dictionary(&INPUT.as_bytes()[..100])
+                .prefix_as_dictionary(prefix),
+        );
+        assert_eq!(replaced, seekable);
+
+        let mut decoder = DecodeOptions::new(BytesWrapper::new(&seekable))
+            .dictionary(prefix)
+            .into_decoder()
+            .unwrap();
+        let mut output = vec![0; INPUT.len()];
+        let mut out_pos = 0;
+        while out_pos < output.len() {
+            out_pos += decoder.decompress(&mut output[out_pos..]).unwrap();
+        }
+        assert_eq!(INPUT.as_bytes(), output);
+    }
+
+    #[cfg(feature = "zstdmt")]
+    #[test]
+    fn compress_with_workers() {
+        use crate::{BytesWrapper, Decoder};
+
+        let mut encoder = EncodeOptions::new()
+            .workers(4)
+            .frame_size_policy(FrameSizePolicy::Uncompressed(1024))
+            .into_raw_encoder()
+            .unwrap();
+        // Compression is interrupted while workers are busy
+        let mut seekable = compress_frames(&mut encoder, INPUT.as_bytes(), None);
+
+        let st = encoder.into_seek_table();
+        assert!(st.num_frames() > 1);
+        assert_eq!(st.size_comp(), seekable.len() as u64);
+        assert_eq!(st.size_decomp(), INPUT.len() as u64);
+
+        let mut ser = st.into_serializer().unwrap();
+        let mut st_buf = vec![0; ser.encoded_len()];
+        ser.write_into(&mut st_buf);
+        seekable.extend(&st_buf);
+
+        let mut decoder = Decoder::new(BytesWrapper::new(&seekable)).unwrap();
+        for index in 0..decoder.seek_table().num_frames() {
+            assert!(decoder.verify_frame(index).unwrap());
+        }
+        let mut output = vec![0; INPUT.len()];
+        let mut out_pos = 0;
+        while out_pos < output.len() {
+            out_pos += decoder.decompress(&mut output[out_pos..]).unwrap();
+        }
+        assert_eq!(INPUT.as_bytes(), output);
+    }
+
+    #[test]
+    fn patch_large_prefix_with_long_distance_matching() {
+        // Incompressible data that is larger than the default window
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let old: Vec<u8> = (0..6 << 20)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state as u8
+            })
+            .collect();
+        let mut new = old.clone();
+        new[100..110].copy_from_slice(b"0123456789");
+        let old: &'static [u8] = old.leak();
+
+        let patch_size = |opts: EncodeOptions<'static>| {
+            let mut encoder = opts
+                .frame_size_policy(FrameSizePolicy::Uncompressed(MAX_FRAME_SIZE))
+                .into_raw_encoder()
+                .unwrap();
+            compress_frames(&mut encoder, &new, Some(old)).len()
+        };
+
+        let window_log = old.len().ilog2() + 1;
+        let without = patch_size(EncodeOptions::new().compression_level(1));
+        let with = patch_size(
+            EncodeOptions::new()
+                .compression_level(1)
+                .long_distance_matching(true)
+                .window_log(window_log),
+        );
+        assert!(with * 10 < without, "{with} vs {without}");
+    }
+
+    #[test]
+    fn deterministic_output() {
+        let compress = || {
+            let mut encoder = EncodeOptions::new()
+                .deterministic(true)
+                .frame_size_policy(FrameSizePolicy::Uncompressed(1024))
+                .into_raw_encoder()
+                .unwrap();
+            compress_frames(&mut encoder, INPUT.as_bytes(), None)
+        };
+
+        assert_eq!(compress(), compress());
+    }
+
+    #[cfg(feature = "zstdmt")]
+    #[test]
+    fn deterministic_overrides_workers() {
+        // Large enough to be split into multiple jobs by zstd
+        let input = INPUT.as_bytes().repeat((4 << 20) / INPUT.len());
+        let compress = |opts: EncodeOptions<'static>| {
+            let mut encoder = opts
+                .compression_level(1)
+                .frame_size_policy(FrameSizePolicy::Uncompressed(MAX_FRAME_SIZE))
+                .into_raw_encoder()
+                .unwrap();
+            compress_frames(&mut encoder, &input, None)
+        };
+
+        let single = compress(EncodeOptions::new());
+        assert_ne!(compress(EncodeOptions::new().workers(2)), single);
+        let configs: [fn() -> EncodeOptions<'static>; 3] = [
+            || {
+                EncodeOptions::new()
+                    .deterministic(true)
+                    .set_parameter(CParameter::NbWorkers(2))
+            },
+            || EncodeOptions::new().deterministic(true).workers(2),
+            || EncodeOptions::new().workers(2).deterministic(true),
+        ];
+        for opts in configs {
+            // Identical to single-threaded output on every run
+            assert_eq!(compress(opts()), single);
+            assert_eq!(compress(opts()), single);
+        }
+    }
+
+    #[cfg(feature = "crc32")]
+    #[test]
+    fn seek_table_checksum_crc32() {
+        const FRAME_SIZE: usize = 1024;
+        let mut encoder = EncodeOptions::new()
+            .seek_table_checksum(true)
+            .checksum_algo(ChecksumAlgo::Crc32)
+            .frame_size_policy(FrameSizePolicy::Uncompressed(FRAME_SIZE as u32))
+            .into_raw_encoder()
+            .unwrap();
+
+        compress_frames(&mut encoder, INPUT.as_bytes(), None);
+
+        let mut buf = vec![0; INPUT.len()];
+        let mut ser = encoder.into_seek_table().into_serializer().unwrap();
+        let n = ser.write_into(&mut buf);
+        let entries = &buf[crate::SKIPPABLE_HEADER_SIZE..n - crate::SEEK_TABLE_INTEGRITY_SIZE];
+        assert_eq!(entries.len() / 12, INPUT.len().div_ceil(FRAME_SIZE));
+        for (entry, frame) in entries
+            .chunks_exact(12)
+            .zip(INPUT.as_bytes().chunks(FRAME_SIZE))
+        {
+            let checksum = u32::from_le_bytes(entry[8..12].try_into().unwrap());
+            assert_eq!(checksum, crc32fast::hash(frame));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn resume_encoding() {
+        use std::io::Read;
+
+        use crate::{BytesWrapper, Decoder};
+
+        let (first, second) = INPUT.as_bytes().split_at(INPUT.len() / 2);
+        let mut archive = vec![];
+        let mut encoder = EncodeOptions::new()
+            .frame_size_policy(FrameSizePolicy::Uncompressed(1024))
+            .seek_table_checksum(true)
+            .into_encoder(&mut archive)
+            .unwrap();
+        encoder.compress(first).unwrap();
+        encoder.finish().unwrap();
+
+        let st = SeekTable::from_seekable(&mut BytesWrapper::new(&archive)).unwrap();
+        let old_frames = st.num_frames();
+        archive.truncate(usize::try_from(st.size_comp()).unwrap());
+
+        let mut encoder = EncodeOptions::new()
+            .frame_size_policy(FrameSizePolicy::Uncompressed(1024))
+            .seek_table_checksum(true)
+            .resume_from(st.clone())
+            .into_encoder(&mut archive)
+            .unwrap();
+        encoder.compress(second).unwrap();
+        encoder.finish().unwrap();
+
+        let mut decoder = Decoder::new(BytesWrapper::new(&archive)).unwrap();
+        let resumed = decoder.seek_table();
+        assert_eq!(
+            resumed.num_frames(),
+            old_frames + u32::try_from(second.len().div_ceil(1024)).unwrap()
+        );
+        assert!(resumed.stats().has_checksums());
+        assert_eq!(resumed.size_decomp(), INPUT.len() as u64);
+        for index in 0..old_frames {
+            assert_eq!(
+                resumed.frame_start_comp(index).unwrap(),
+                st.frame_start_comp(index).unwrap()
+            );
+        }
+        assert_eq!(
+            resumed.frame_start_comp(old_frames).unwrap(),
+            st.size_comp()
+        );
+
+        let mut output = vec![];
+        decoder.read_to_end(&mut output).unwrap();
+        assert_eq!(INPUT.as_bytes(), output);
+    }
+
+    #[test]
+    fn custom_frame_size_policy() {
+        let mut encoder = EncodeOptions::new()
+            .frame_size_policy(FrameSizePolicy::Custom(Arc::new(|_, d_size| {
+                d_size >= 1000
+            })))
+            .into_raw_encoder()
+            .unwrap();
+        let mut buf = vec![0; 1024];
+
+        // Records of 100 bytes
+        for record in INPUT.as_bytes().chunks(100) {
+            let mut in_progress = 0;
+            while in_progress < record.len() {
+                let prog = encoder.compress(&record[in_progress..], &mut buf).unwrap();
+                in_progress += prog.in_progress;
+            }
+        }
+        while encoder.end_frame(&mut buf).unwrap().data_left > 0 {}
+
+        let st = encoder.into_seek_table();
+        assert_eq!(
+            st.num_frames(),
+            u32::try_from(INPUT.len().div_ceil(1000)).unwrap()
+        );
+        for frame in st.frames() {
+            if frame.index() < st.num_frames() - 1 {
+                assert_eq!(frame.decomp_size(), 1000);
+            }
+        }
+        assert_eq!(st.size_decomp(), INPUT.len() as u64);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn advanced_parameters() {
+        use std::io::Read;
+
+        use zstd_safe::zstd_sys::ZSTD_strategy;
+
+        use crate::{BytesWrapper, Decoder};
+
+        let compress = |opts: EncodeOptions<'_>| {
+            let mut output = vec![];
+            let mut encoder = opts
+                .compression_level(19)
+                .into_encoder(&mut output)
+                .unwrap();
+            encoder.compress(INPUT.as_bytes()).unwrap();
+            encoder.finish().unwrap();
+            output
+        };
+
+        let strong = compress(EncodeOptions::new());
+        let fast = compress(
+            EncodeOptions::new().set_parameter(CParameter::Strategy(ZSTD_strategy::ZSTD_fast)),
+        );
+        // The strategy overrides the one derived from the compression level
+        assert!(fast.len() > strong.len());
+
+        let mut decoder = Decoder::new(BytesWrapper::new(&fast)).unwrap();
+        let mut output = vec![];
+        decoder.read_to_end(&mut output).unwrap();
+        assert_eq!(INPUT.as_bytes(), output);
+
+        // Invalid parameters are rejected
+        assert!(
+            EncodeOptions::new()
+                .set_parameter(CParameter::ChainLog(u32::MAX))
+                .into_raw_encoder()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn flush_mid_frame() {
+        use crate::{BytesWrapper, Decoder};
+
+        let mut encoder = RawEncoder::new().unwrap();
+        let half = INPUT.len() / 2;
+        let mut seekable = vec![];
+        let mut comp_buf = vec![0; 16];
+
+        let mut compress = |encoder: &mut RawEncoder, input: &[u8], seekable: &mut Vec<u8>| {
+            let mut in_progress = 0;
+            while in_progress < input.len() {
+                let prog = encoder
+                    .compress(&input[in_progress..], &mut comp_buf)
+                    .unwrap();
+                seekable.extend(&comp_buf[..prog.out_progress]);
+                in_progress += prog.in_progress;
+            }
+        };
+
+        compress(&mut encoder, &INPUT.as_bytes()[..half], &mut seekable);
+        let mut buf = vec![0; 16];
+        loop {
+            let prog = encoder.flush(&mut buf).unwrap();
+            seekable.extend(&buf[..prog.out_progress]);
+            if prog.data_left == 0 {
+                break;
+            }
+        }
+        // The flushed data decompresses to the input so far
+        let mut dctx = zstd_safe::DCtx::create();
+        let mut flushed = vec![0; half];
+        let mut out_buf = zstd_safe::OutBuffer::around(&mut flushed[..]);
+        dctx.decompress_stream(&mut out_buf, &mut zstd_safe::InBuffer::around(&seekable))
+            .unwrap();
+        assert_eq!(out_buf.pos(), half);
+        assert_eq!(INPUT.as_bytes()[..half], flushed);
+        assert_eq!(encoder.seek_table().num_frames(), 0);
+        assert_eq!(encoder.frame_c_size as usize, seekable.len());
+
+        compress(&mut encoder, &INPUT.as_bytes()[half..], &mut seekable);
+        loop {
+            let prog = encoder.end_frame(&mut buf).unwrap();
+            seekable.extend(&buf[..prog.out_progress]);
+            if prog.data_left == 0 {
+                break;
+            }
+        }
+        let st = encoder.into_seek_table();
+        assert_eq!(st.num_frames(), 1);
+        assert_eq!(st.size_comp(), seekable.len() as u64);
+
+        let mut ser = st.into_serializer().unwrap();
+        let mut st_buf = vec![0; ser.encoded_len()];
+        ser.write_into(&mut st_buf);
+        seekable.extend(&st_buf);
+
+        let mut decoder = Decoder::new(BytesWrapper::new(&seekable)).unwrap();
+        let mut output = vec![0; INPUT.len()];
+        let mut out_pos = 0;
+        while out_pos < output.len() {
+            out_pos += decoder.decompress(&mut output[out_pos..]).unwrap();
+        }
+        assert_eq!(INPUT.as_bytes(), output);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn written_uncompressed() {
+        let mut output = vec![];
+        let mut encoder = EncodeOptions::new()
+            .frame_size_policy(FrameSizePolicy::Uncompressed(1000))
+            .into_encoder(&mut output)
+            .unwrap();
+
+        let mut written = 0;
+        for chunk in INPUT.as_bytes().chunks(333) {
+            encoder.compress(chunk).unwrap();
+            written += chunk.len() as u64;
+            assert_eq!(encoder.written_uncompressed(), written);
+            assert_eq!(encoder.raw.uncompressed_pos(), written);
+        }
+        encoder.end_frame().unwrap();
+        assert_eq!(encoder.written_uncompressed(), INPUT.len() as u64);
+        assert_eq!(encoder.raw.uncompressed_pos(), INPUT.len() as u64);
+        encoder.finish().unwrap();
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn compress_from_reader() {
+        let mut output = vec![];
+        let mut encoder = EncodeOptions::new()
+            .frame_size_policy(FrameSizePolicy::Uncompressed(1000))
+            .into_encoder(&mut output)
+            .unwrap();
+
+        let mut reader = std::io::Cursor::new(INPUT.as_bytes());
+        let n = encoder.compress_from(&mut reader).unwrap();
+        assert_eq!(n, INPUT.len() as u64);
+        assert_eq!(encoder.written_uncompressed(), n);
+        // The reader is exhausted
+        assert_eq!(encoder.compress_from(&mut reader).unwrap(), 0);
+        encoder.finish().unwrap();
+
+        let mut decoder = crate::Decoder::new(crate::BytesWrapper::new(&output)).unwrap();
+        let mut decompressed = vec![];
+        decoder.decompress_all(&mut decompressed).unwrap();
+        assert_eq!(decompressed, INPUT.as_bytes());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn pending_output() {
+        let mut output = vec![];
+        let mut encoder = Encoder::new(&mut output).unwrap();
+        encoder.compress(INPUT.as_bytes()).unwrap();
+        encoder.end_frame().unwrap();
+        let pending = encoder.pending_output();
+        assert!(pending > 0);
+        assert_eq!(encoder.written_compressed(), 0);
+
+        assert_eq!(encoder.try_flush().unwrap(), pending);
+        assert_eq!(encoder.pending_output(), 0);
+        assert_eq!(encoder.written_compressed(), pending as u64);
+        assert_eq!(encoder.try_flush().unwrap(), 0);
+
+        // Incomplete frames stay buffered if frames are length prefixed
+        let mut encoder = EncodeOptions::new()
+            .length_prefixed(true)
+            .into_encoder(vec![])
+            .unwrap();
+        encoder.compress(INPUT.as_bytes()).unwrap();
+        let pending = encoder.pending_output();
+        assert_eq!(encoder.try_flush().unwrap(), 0);
+        assert_eq!(encoder.pending_output(), pending);
+        encoder.end_frame().unwrap();
+        assert_eq!(encoder.pending_output(), 0);
+    }
 }
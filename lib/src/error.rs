@@ -17,24 +17,59 @@ impl Error {
 
     pub(crate) fn offset_out_of_range() -> Self {
         Self {
-            kind: Kind::OffsetOutOfRange,
+            kind: Kind::OffsetOutOfRange(None),
+        }
+    }
+
+    pub(crate) fn offset_out_of_range_with(requested: u64, max: u64) -> Self {
+        Self {
+            kind: Kind::OffsetOutOfRange(Some(Exceeded { requested, max })),
         }
     }
 
     /// Returns true if the error origins from an out of range offset.
     pub fn is_offset_out_of_range(&self) -> bool {
-        matches!(self.kind, Kind::OffsetOutOfRange)
+        matches!(self.kind, Kind::OffsetOutOfRange(_))
     }
 
     pub(crate) fn frame_index_too_large() -> Self {
         Self {
-            kind: Kind::FrameIndexTooLarge,
+            kind: Kind::FrameIndexTooLarge(None),
+        }
+    }
+
+    pub(crate) fn frame_index_too_large_with(requested: u32, max: u32) -> Self {
+        Self {
+            kind: Kind::FrameIndexTooLarge(Some(Exceeded { requested, max })),
         }
     }
 
     /// Returns true if the error is related to a frame index that is too large.
     pub fn is_frame_index_too_large(&self) -> bool {
-        matches!(self.kind, Kind::FrameIndexTooLarge)
+        matches!(self.kind, Kind::FrameIndexTooLarge(_))
+    }
+
+    pub(crate) fn frame_size_too_large() -> Self {
+        Self {
+            kind: Kind::FrameSizeTooLarge,
+        }
+    }
+
+    /// Returns true if the error is related to a frame size that is too large.
+    pub fn is_frame_size_too_large(&self) -> bool {
+        matches!(self.kind, Kind::FrameSizeTooLarge)
+    }
+
+    pub(crate) fn checksum_mismatch(frame: u32) -> Self {
+        Self {
+            kind: Kind::ChecksumMismatch { frame },
+        }
+    }
+
+    /// Returns true if the checksum of a decompressed frame doesn't match the checksum in the
+    /// seek table.
+    pub fn is_checksum_mismatch(&self) -> bool {
+        matches!(self.kind, Kind::ChecksumMismatch { .. })
     }
 
     pub(crate) fn zstd(code: ZSTD_ErrorCode) -> Self {
@@ -61,8 +96,16 @@ impl core::fmt::Display for Error {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match &self.kind {
             Kind::NumberConversionFailed(err) => write!(f, "number conversion failed: {err}"),
-            Kind::OffsetOutOfRange => f.write_str("offset out of range"),
-            Kind::FrameIndexTooLarge => f.write_str("frame index too large"),
+            Kind::OffsetOutOfRange(None) => f.write_str("offset out of range"),
+            Kind::OffsetOutOfRange(Some(Exceeded { requested, max })) => {
+                write!(f, "offset {requested} out of range, max is {max}")
+            }
+            Kind::FrameIndexTooLarge(None) => f.write_str("frame index too large"),
+            Kind::FrameIndexTooLarge(Some(Exceeded { requested, max })) => {
+                write!(f, "frame index {requested} too large, max is {max}")
+            }
+            Kind::FrameSizeTooLarge => f.write_str("frame size too large"),
+            Kind::ChecksumMismatch { frame } => write!(f, "checksum mismatch in frame {frame}"),
             #[cfg(feature = "std")]
             Kind::IO(err) => write!(f, "io error: {err}"),
             Kind::Zstd(code) => f.write_str(get_error_name(*code)),
@@ -90,6 +133,30 @@ impl From<std::io::Error> for Error {
     }
 }
 
+/// Converts the error into an IO error.
+///
+/// IO errors are unwrapped, checksum mismatches and corrupted data map to
+/// [`ErrorKind::InvalidData`], all other errors to [`ErrorKind::Other`].
+///
+/// [`ErrorKind::InvalidData`]: std::io::ErrorKind::InvalidData
+/// [`ErrorKind::Other`]: std::io::ErrorKind::Other
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl From<Error> for std::io::Error {
+    fn from(value: Error) -> Self {
+        use std::io::ErrorKind;
+
+        let kind = match value.kind {
+            Kind::IO(err) => return err,
+            Kind::ChecksumMismatch { .. } => ErrorKind::InvalidData,
+            Kind::Zstd(code) if is_corruption(code) => ErrorKind::InvalidData,
+            _ => ErrorKind::Other,
+        };
+
+        Self::new(kind, value)
+    }
+}
+
 impl From<ErrorCode> for Error {
     fn from(value: ErrorCode) -> Self {
         Self {
@@ -98,13 +165,30 @@ impl From<ErrorCode> for Error {
     }
 }
 
+/// Whether the zstd error code indicates corrupted input data.
+#[cfg(feature = "std")]
+fn is_corruption(code: ErrorCode) -> bool {
+    [
+        ZSTD_ErrorCode::ZSTD_error_corruption_detected,
+        ZSTD_ErrorCode::ZSTD_error_checksum_wrong,
+        ZSTD_ErrorCode::ZSTD_error_literals_headerWrong,
+        ZSTD_ErrorCode::ZSTD_error_prefix_unknown,
+    ]
+    .into_iter()
+    .any(|c| 0_usize.wrapping_sub(c as usize) == code)
+}
+
 enum Kind {
     /// Out of range integral type conversion attempted
     NumberConversionFailed(core::num::TryFromIntError),
     /// The desired offset is out of range.
-    OffsetOutOfRange,
+    OffsetOutOfRange(Option<Exceeded<u64>>),
     /// The passed frame index is too large.
-    FrameIndexTooLarge,
+    FrameIndexTooLarge(Option<Exceeded<u32>>),
+    /// The configured frame size exceeds the maximum frame size.
+    FrameSizeTooLarge,
+    /// The checksum of a decompressed frame doesn't match.
+    ChecksumMismatch { frame: u32 },
     /// IO error.
     #[cfg(feature = "std")]
     IO(std::io::Error),
@@ -112,17 +196,87 @@ enum Kind {
     Zstd(ErrorCode),
 }
 
+/// A requested offset or frame index and the maximum valid value.
+#[derive(Debug)]
+struct Exceeded<T> {
+    requested: T,
+    max: T,
+}
+
 impl core::fmt::Debug for Kind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::NumberConversionFailed(arg0) => {
                 f.debug_tuple("NumberConversionFailed").field(arg0).finish()
             }
-            Self::OffsetOutOfRange => write!(f, "OffsetOutOfRange"),
-            Self::FrameIndexTooLarge => write!(f, "FrameIndexTooLarge"),
+            Self::OffsetOutOfRange(arg0) => f.debug_tuple("OffsetOutOfRange").field(arg0).finish(),
+            Self::FrameIndexTooLarge(arg0) => {
+                f.debug_tuple("FrameIndexTooLarge").field(arg0).finish()
+            }
+            Self::FrameSizeTooLarge => write!(f, "FrameSizeTooLarge"),
+            Self::ChecksumMismatch { frame } => f
+                .debug_struct("ChecksumMismatch")
+                .field("frame", frame)
+                .finish(),
             #[cfg(feature = "std")]
             Self::IO(arg0) => f.debug_tuple("IO").field(arg0).finish(),
             Self::Zstd(c) => write!(f, "{}; code {}", zstd_safe::get_error_name(*c), c),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn display_requested_and_max() {
+        assert_eq!(
+            Error::offset_out_of_range_with(120, 100).to_string(),
+            "offset 120 out of range, max is 100"
+        );
+        assert_eq!(
+            Error::frame_index_too_large_with(7, 3).to_string(),
+            "frame index 7 too large, max is 3"
+        );
+        assert!(Error::offset_out_of_range_with(120, 100).is_offset_out_of_range());
+        assert!(Error::frame_index_too_large_with(7, 3).is_frame_index_too_large());
+
+        // Errors without context keep the plain message
+        assert_eq!(
+            Error::offset_out_of_range().to_string(),
+            "offset out of range"
+        );
+        assert_eq!(
+            Error::frame_index_too_large().to_string(),
+            "frame index too large"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn into_io_error() {
+        use std::io::ErrorKind;
+
+        let err = std::io::Error::from(Error::offset_out_of_range());
+        assert_eq!(err.kind(), ErrorKind::Other);
+        assert_eq!(err.to_string(), "offset out of range");
+
+        let err = std::io::Error::from(Error::checksum_mismatch(3));
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+
+        let err = std::io::Error::from(Error::zstd(ZSTD_ErrorCode::ZSTD_error_corruption_detected));
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+
+        let err = std::io::Error::from(Error::zstd(ZSTD_ErrorCode::ZSTD_error_memory_allocation));
+        assert_eq!(err.kind(), ErrorKind::Other);
+
+        // IO errors are passed through
+        let io = std::io::Error::from(ErrorKind::UnexpectedEof);
+        let err = std::io::Error::from(Error::from(io));
+        assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+        assert!(err.get_ref().is_none());
+    }
+}
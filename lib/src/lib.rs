@@ -29,22 +29,49 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+#[cfg(feature = "tokio")]
+mod async_decode;
+#[cfg(feature = "tokio")]
+mod async_encode;
 mod decode;
 mod encode;
 mod error;
+#[cfg(feature = "std")]
+mod parallel_encode;
 pub mod seek_table;
 mod seekable;
 
-pub use decode::{DecodeOptions, Decoder};
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+pub use async_decode::AsyncDecoder;
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+pub use async_encode::AsyncEncoder;
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+pub use decode::spawn_decompress;
+pub use decode::{
+    Archives, Chunks, DecodeOptions, Decoder, FrameDecoder, SourceAccess, archives,
+    recommended_decompress_buffer_sizes,
+};
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use decode::{DecodeStats, LengthPrefixedReader, SalvageReport};
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub use encode::Encoder;
 pub use encode::{
     CompressionProgress, EncodeOptions, EpilogueProgress, FrameSizePolicy, RawEncoder,
+    recommended_compress_buffer_sizes,
 };
 pub use error::{Error, Result};
-pub use seek_table::SeekTable;
-pub use seekable::{BytesWrapper, OffsetFrom, Seekable};
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use parallel_encode::ParallelEncoder;
+pub use seek_table::{ChecksumAlgo, SeekTable};
+pub use seekable::{
+    ArchiveMember, BytesWrapper, OffsetFrom, Seekable, SeekableSlice, is_seekable_archive,
+};
 // Re-export as it's part of the API.
 pub use zstd_safe::CompressionLevel;
 
@@ -79,6 +106,34 @@ mod tests {
 
     pub const INPUT: &str = include_str!("./lib.rs");
 
+    /// A source that returns at most one byte per read.
+    pub struct ShortReads<'a>(pub BytesWrapper<'a>);
+
+    impl Seekable for ShortReads<'_> {
+        fn set_offset(&mut self, offset: OffsetFrom) -> Result<u64> {
+            self.0.set_offset(offset)
+        }
+
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+            let len = buf.len().min(1);
+            self.0.read(&mut buf[..len])
+        }
+
+        fn seek_table_integrity(
+            &mut self,
+            format: Format,
+        ) -> Result<[u8; SEEK_TABLE_INTEGRITY_SIZE]> {
+            self.0.seek_table_integrity(format)
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl std::io::Read for ShortReads<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            Ok(Seekable::read(self, buf)?)
+        }
+    }
+
     fn test_cycle(frame_size_policy: Option<FrameSizePolicy>) {
         let mut seekable = vec![];
         let mut opts = EncodeOptions::new();
@@ -109,7 +164,7 @@ mod tests {
             }
         }
 
-        let mut ser = encoder.into_seek_table().into_serializer();
+        let mut ser = encoder.into_seek_table().into_serializer().unwrap();
         loop {
             let n = ser.write_into(&mut buf);
             if n == 0 {
@@ -166,7 +221,10 @@ mod tests {
             }
         }
 
-        let mut ser = encoder.into_seek_table().into_format_serializer(format);
+        let mut ser = encoder
+            .into_seek_table()
+            .into_format_serializer(format)
+            .unwrap();
         let mut seek_table = Vec::with_capacity(ser.encoded_len());
         loop {
             let n = ser.write_into(&mut buf);
@@ -236,7 +294,7 @@ mod tests {
             }
         }
 
-        let mut ser = encoder.into_seek_table().into_serializer();
+        let mut ser = encoder.into_seek_table().into_serializer().unwrap();
         loop {
             let n = ser.write_into(&mut buf);
             if n == 0 {
@@ -286,6 +344,62 @@ mod tests {
         assert_eq!(INPUT.as_bytes(), output.get_ref());
     }
 
+    #[test]
+    fn seekable_size() {
+        let mut src = BytesWrapper::new(INPUT.as_bytes());
+        src.set_offset(OffsetFrom::Start(100)).unwrap();
+        assert_eq!(src.size().unwrap(), INPUT.len() as u64);
+
+        // The override leaves the offset untouched
+        let mut buf = [0u8; 10];
+        src.read(&mut buf).unwrap();
+        assert_eq!(INPUT.as_bytes()[100..110], buf);
+
+        let mut default = ShortReads(BytesWrapper::new(INPUT.as_bytes()));
+        assert_eq!(default.size().unwrap(), src.size().unwrap());
+        assert_eq!(BytesWrapper::new(&[]).size().unwrap(), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn embedded_archive() {
+        use std::io::{Cursor, Read};
+
+        let mut container = b"leading junk".to_vec();
+        let start = container.len();
+        let mut encoder = EncodeOptions::new()
+            .frame_size_policy(FrameSizePolicy::Uncompressed(1024))
+            .into_encoder(&mut container)
+            .unwrap();
+        std::io::copy(&mut INPUT.as_bytes(), &mut encoder).unwrap();
+        encoder.finish().unwrap();
+        let end = container.len();
+        container.extend(b"trailing junk");
+
+        let mut output = vec![];
+        let sub = BytesWrapper::new(&container).sub(start..end);
+        let mut decoder = Decoder::new(sub).unwrap();
+        decoder.read_to_end(&mut output).unwrap();
+        assert_eq!(INPUT.as_bytes(), output);
+
+        let mut slice =
+            SeekableSlice::new(Cursor::new(&container), start as u64, (end - start) as u64);
+        assert_eq!(slice.size().unwrap(), (end - start) as u64);
+        assert!(
+            slice
+                .set_offset(OffsetFrom::Start((end - start + 1) as u64))
+                .unwrap_err()
+                .is_offset_out_of_range()
+        );
+
+        // Decode from the middle of the archive
+        let mut decoder = Decoder::new(slice).unwrap();
+        decoder.set_offset(3000).unwrap();
+        output.clear();
+        decoder.read_to_end(&mut output).unwrap();
+        assert_eq!(&INPUT.as_bytes()[3000..], output);
+    }
+
     #[test]
     fn cycle() {
         test_cycle(None);
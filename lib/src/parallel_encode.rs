@@ -0,0 +1,329 @@
+use alloc::vec::Vec;
+use std::io::Write;
+
+use zstd_safe::CCtx;
+
+use crate::{EncodeOptions, RawEncoder, SeekTable, error::Result, seek_table::Format};
+
+/// Compresses chunks of input into frames, one at a time.
+struct Worker<'a> {
+    raw: RawEncoder<'a>,
+    output: Vec<u8>,
+}
+
+impl Worker<'_> {
+    /// Compresses `input` into a single frame, replacing the content of the output buffer.
+    fn compress_frame(&mut self, input: &[u8]) -> Result<()> {
+        self.output.clear();
+        self.output
+            .resize(zstd_safe::compress_bound(input.len()), 0);
+        let mut in_pos = 0;
+        let mut out_pos = 0;
+
+        loop {
+            if out_pos == self.output.len() {
+                self.output.resize(out_pos + CCtx::out_size(), 0);
+            }
+
+            if in_pos < input.len() {
+                let prog = self
+                    .raw
+                    .compress(&input[in_pos..], &mut self.output[out_pos..])?;
+                in_pos += prog.in_progress();
+                out_pos += prog.out_progress();
+            } else {
+                let prog = self.raw.end_frame(&mut self.output[out_pos..])?;
+                out_pos += prog.out_progress();
+                if prog.data_left() == 0 {
+                    break;
+                }
+            }
+        }
+        self.output.truncate(out_pos);
+
+        Ok(())
+    }
+}
+
+/// A single-use seekable encoder that compresses frames in parallel.
+///
+/// The input is split into chunks of the frame size of the [`FrameSizePolicy::Uncompressed`]
+/// policy, every chunk is compressed into its own frame. Input is buffered until there is a chunk
+/// for every thread, then the chunks are compressed at the same time, each on its own thread with
+/// its own compression context. The frames are written to the writer in order. Frames are
+/// independent of each other, so this scales with the number of threads, and the output
+/// decompresses to the same data as the output of an [`Encoder`] with the same options.
+///
+/// Up to `threads` times the frame size of uncompressed and compressed data is buffered. Only
+/// [`FrameSizePolicy::Uncompressed`] is supported. The [pledged source
+/// size](EncodeOptions::pledged_src_size) and [length prefixes](EncodeOptions::length_prefixed)
+/// have no effect. Every thread but the first uses a new compression context that is configured
+/// with the options, parameters that were set directly on a context passed with
+/// [`EncodeOptions::cctx`] only apply to the first thread.
+///
+/// [`Encoder`]: crate::Encoder
+/// [`FrameSizePolicy::Uncompressed`]: crate::FrameSizePolicy::Uncompressed
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::{fs::File, io};
+/// use zeekstd::EncodeOptions;
+///
+/// let mut input = File::open("foo")?;
+/// let output = File::create("foo.zst")?;
+/// let mut encoder = EncodeOptions::new().into_parallel_encoder(output, 4)?;
+/// io::copy(&mut input, &mut encoder)?;
+/// // Compress the remaining input and write the seek table to the output
+/// encoder.finish()?;
+/// # Ok::<(), zeekstd::Error>(())
+/// ```
+pub struct ParallelEncoder<'a, W> {
+    workers: Vec<Worker<'a>>,
+    frame_size: usize,
+    in_buf: Vec<u8>,
+    seek_table: SeekTable,
+    writer: W,
+    written_compressed: u64,
+    written_uncompressed: u64,
+}
+
+impl<'a, W> ParallelEncoder<'a, W> {
+    /// Creates a new `ParallelEncoder` with default parameters that uses `threads` threads.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the encoder could not be created.
+    pub fn new(writer: W, threads: usize) -> Result<Self> {
+        Self::with_opts(writer, EncodeOptions::new(), threads)
+    }
+
+    /// Creates a new `ParallelEncoder` with the given [`EncodeOptions`] that uses `threads`
+    /// threads.
+    ///
+    /// At least one thread is used.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the encoder could not be created or the frame size policy is not
+    /// [`FrameSizePolicy::Uncompressed`].
+    ///
+    /// [`FrameSizePolicy::Uncompressed`]: crate::FrameSizePolicy::Uncompressed
+    pub fn with_opts(writer: W, opts: EncodeOptions<'a>, threads: usize) -> Result<Self> {
+        let (encoders, frame_size) = opts.into_raw_encoders(threads.max(1))?;
+        let mut workers: Vec<_> = encoders
+            .into_iter()
+            .map(|raw| Worker {
+                raw,
+                output: Vec::new(),
+            })
+            .collect();
+        // The first encoder holds the seek table to resume from, frames are logged by this
+        // encoder from now on
+        let seek_table = workers[0].raw.seek_table().clone();
+        workers[0].raw.reset_seek_table();
+
+        Ok(Self {
+            frame_size: usize::try_from(frame_size)?,
+            in_buf: Vec::with_capacity(workers.len() * usize::try_from(frame_size)?),
+            workers,
+            seek_table,
+            writer,
+            written_compressed: 0,
+            written_uncompressed: 0,
+        })
+    }
+}
+
+impl<W> ParallelEncoder<'_, W> {
+    /// Returns a reference to the [`SeekTable`] of the frames that have been written.
+    pub fn seek_table(&self) -> &SeekTable {
+        &self.seek_table
+    }
+
+    /// The total number of compressed bytes that have been written to the internal writer.
+    pub fn written_compressed(&self) -> u64 {
+        self.written_compressed
+    }
+
+    /// The total number of uncompressed bytes that have been consumed by this encoder.
+    ///
+    /// This includes buffered input that has not been compressed yet.
+    pub fn written_uncompressed(&self) -> u64 {
+        self.written_uncompressed
+    }
+}
+
+impl<W: Write> ParallelEncoder<'_, W> {
+    /// Consumes input data from `buf`.
+    ///
+    /// The input is buffered and compressed as soon as there is a complete chunk for every
+    /// thread. Returns the number of bytes consumed, which is always the length of `buf`.
+    ///
+    /// # Errors
+    ///
+    /// If compression or writing to the internal writer fails.
+    pub fn compress(&mut self, buf: &[u8]) -> Result<usize> {
+        let capacity = self.workers.len() * self.frame_size;
+        let mut pos = 0;
+
+        while pos < buf.len() {
+            let n = (capacity - self.in_buf.len()).min(buf.len() - pos);
+            self.in_buf.extend_from_slice(&buf[pos..pos + n]);
+            pos += n;
+            if self.in_buf.len() == capacity {
+                self.compress_batch()?;
+            }
+        }
+        self.written_uncompressed += buf.len() as u64;
+
+        Ok(buf.len())
+    }
+
+    /// Compresses the buffered input and writes the seek table.
+    ///
+    /// The seek table is written in `Foot` format. Returns the total number of bytes, i.e. all
+    /// compressed data plus the size of the seek table, written by this `ParallelEncoder`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if compression or writing to the internal writer fails.
+    pub fn finish(self) -> Result<u64> {
+        self.finish_format(Format::Foot)
+    }
+
+    /// Compresses the buffered input and writes the seek table in the given format.
+    ///
+    /// Returns the total number of bytes, i.e. all compressed data plus the size of the seek table,
+    /// written by this `ParallelEncoder`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if compression or writing to the internal writer fails.
+    pub fn finish_format(mut self, format: Format) -> Result<u64> {
+        // Like the `Encoder`, write an empty frame if there is no input at all
+        if !self.in_buf.is_empty() || self.seek_table.num_frames() == 0 {
+            self.compress_batch()?;
+        }
+
+        let seek_table = core::mem::take(&mut self.seek_table);
+        self.written_compressed += seek_table.write_to(&mut self.writer, format)?;
+        self.writer.flush()?;
+
+        Ok(self.written_compressed)
+    }
+
+    /// Compresses every chunk of the input buffer on its own thread and writes the frames.
+    fn compress_batch(&mut self) -> Result<()> {
+        let mut chunks: Vec<_> = self.in_buf.chunks(self.frame_size).collect();
+        if chunks.is_empty() {
+            chunks.push(&[]);
+        }
+        let workers = &mut self.workers[..chunks.len()];
+
+        if let ([worker], [chunk]) = (&mut *workers, chunks.as_slice()) {
+            worker.compress_frame(chunk)?;
+        } else {
+            std::thread::scope(|scope| {
+                let handles: Vec<_> = workers
+                    .iter_mut()
+                    .zip(&chunks)
+                    .map(|(worker, chunk)| scope.spawn(move || worker.compress_frame(chunk)))
+                    .collect();
+
+                handles.into_iter().try_for_each(|handle| {
+                    handle.join().expect("Compression thread does not panic")
+                })
+            })?;
+        }
+
+        for (worker, chunk) in workers.iter_mut().zip(&chunks) {
+            self.writer.write_all(&worker.output)?;
+            self.written_compressed += worker.output.len() as u64;
+            let checksum = worker.raw.seek_table().frame_checksum(0)?;
+            self.seek_table.log_frame_with_checksum(
+                u32::try_from(worker.output.len())?,
+                u32::try_from(chunk.len())?,
+                checksum,
+            )?;
+            worker.raw.reset_seek_table();
+        }
+        self.in_buf.clear();
+
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for ParallelEncoder<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        Ok(self.compress(buf)?)
+    }
+
+    /// Flushes the internal writer.
+    ///
+    /// Buffered input is not compressed, frames are only written once there is a chunk for every
+    /// thread or when the encoder is finished.
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use std::io::Read;
+
+    use crate::{BytesWrapper, Decoder, FrameSizePolicy, tests::INPUT};
+
+    use super::*;
+
+    fn options() -> EncodeOptions<'static> {
+        EncodeOptions::new()
+            .frame_size_policy(FrameSizePolicy::Uncompressed(1024))
+            .seek_table_checksum(true)
+    }
+
+    fn compress_sequential(input: &[u8]) -> Vec<u8> {
+        let mut seekable = vec![];
+        let mut encoder = options().into_encoder(&mut seekable).unwrap();
+        encoder.write_all(input).unwrap();
+        encoder.finish().unwrap();
+        seekable
+    }
+
+    #[test]
+    fn same_output_as_encoder() {
+        for threads in [0, 1, 3, 4] {
+            for input in [&[][..], &INPUT.as_bytes()[..100], INPUT.as_bytes()] {
+                let mut seekable = vec![];
+                let mut encoder = options()
+                    .into_parallel_encoder(&mut seekable, threads)
+                    .unwrap();
+                for chunk in input.chunks(1000) {
+                    encoder.write_all(chunk).unwrap();
+                }
+                assert_eq!(encoder.written_uncompressed(), input.len() as u64);
+                let written = encoder.finish().unwrap();
+                assert_eq!(written, seekable.len() as u64);
+
+                let sequential = compress_sequential(input);
+                assert_eq!(seekable, sequential);
+
+                let mut decoder = Decoder::new(BytesWrapper::new(&seekable)).unwrap();
+                assert_eq!(
+                    decoder.num_frames() as usize,
+                    input.len().div_ceil(1024).max(1)
+                );
+                let mut output = vec![];
+                decoder.read_to_end(&mut output).unwrap();
+                assert_eq!(output, input);
+            }
+        }
+    }
+
+    #[test]
+    fn unsupported_frame_size_policy() {
+        let opts = EncodeOptions::new().frame_size_policy(FrameSizePolicy::Compressed(1024));
+        assert!(ParallelEncoder::with_opts(Vec::<u8>::new(), opts, 4).is_err());
+    }
+}
@@ -1,12 +1,16 @@
 use alloc::vec;
 use alloc::vec::Vec;
 
-use zstd_safe::zstd_sys::ZSTD_ErrorCode;
+use xxhash_rust::xxh64::Xxh64;
+use zstd_safe::{
+    DCtx, InBuffer, OutBuffer,
+    zstd_sys::{ZSTD_ErrorCode, ZSTD_MAGIC_SKIPPABLE_MASK, ZSTD_MAGIC_SKIPPABLE_START},
+};
 
 use crate::{
     SEEK_TABLE_INTEGRITY_SIZE, SEEKABLE_MAGIC_NUMBER, SEEKABLE_MAX_FRAMES, SKIPPABLE_HEADER_SIZE,
     error::{Error, Result},
-    seekable::{OffsetFrom, Seekable},
+    seekable::{OffsetFrom, Seekable, WithoutTrailing},
 };
 
 // Reads 4 bytes (little endian) from buf starting at offset into an u32
@@ -59,6 +63,15 @@ macro_rules! write_frame {
             $self.frames[$self.frame_index].d_size,
             $offset + 4
         );
+        if $self.with_checksum {
+            write_le32!(
+                $buf,
+                $buf_pos,
+                $self.write_pos,
+                $self.frames[$self.frame_index].checksum.unwrap_or_default(),
+                $offset + 8
+            );
+        }
         $self.frame_index += 1;
     };
 }
@@ -67,9 +80,16 @@ macro_rules! write_frame {
 macro_rules! write_integrity {
     ($buf:expr, $buf_pos:expr, $self:expr, $num_frames:expr, $offset:expr) => {
         write_le32!($buf, $buf_pos, $self.write_pos, $num_frames, $offset);
-        // Write the "seek table descriptor", always 0
+        // Write the "seek table descriptor", only the checksum and dictionary id flags may be set
         if $self.write_pos < $offset + 5 {
-            $buf[$buf_pos] = 0;
+            let mut descriptor = 0;
+            if $self.with_checksum {
+                descriptor |= CHECKSUM_FLAG;
+            }
+            if $self.dictionary_id.is_some() {
+                descriptor |= DICT_ID_FLAG;
+            }
+            $buf[$buf_pos] = descriptor;
             $buf_pos += 1;
             $self.write_pos += 1;
         }
@@ -85,18 +105,33 @@ macro_rules! write_integrity {
 
 /// The size of each frame entry in the seek table.
 const SIZE_PER_FRAME: usize = 8;
+/// The size of each frame entry in the seek table, if the entries contain checksums.
+const SIZE_PER_FRAME_CHECKSUM: usize = 12;
+/// The checksum flag of the seek table descriptor.
+const CHECKSUM_FLAG: u8 = 1 << 7;
+/// The flag of the seek table descriptor that marks the extended format, in which the dictionary
+/// id precedes the frame entries.
+///
+/// This is a reserved bit in the seekable format, readers that don't know the extension reject
+/// such seek tables.
+const DICT_ID_FLAG: u8 = 1 << 6;
+/// The size of the dictionary id in the extended format.
+const DICT_ID_SIZE: usize = 4;
 /// The skippable magic number of the skippable frame containing the seek table.
 const SKIPPABLE_MAGIC_NUMBER: u32 = zstd_safe::zstd_sys::ZSTD_MAGIC_SKIPPABLE_START | 0xE;
 
 struct Frame {
     c_size: u32,
     d_size: u32,
+    checksum: Option<u32>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct Entry {
     c_offset: u64,
     d_offset: u64,
+    /// The checksum of the frame that ends at this entry.
+    checksum: Option<u32>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -110,12 +145,18 @@ impl Entries {
         Self(Vec::with_capacity(cap))
     }
 
+    /// Whether every frame has a checksum.
+    fn with_checksum(&self) -> bool {
+        self.0.len() > 1 && self.0[1..].iter().all(|e| e.checksum.is_some())
+    }
+
     fn into_frames(self) -> Vec<Frame> {
         self.0
             .windows(2)
             .map(|w| Frame {
                 c_size: (w[1].c_offset - w[0].c_offset) as u32,
                 d_size: (w[1].d_offset - w[0].d_offset) as u32,
+                checksum: w[1].checksum,
             })
             .collect()
     }
@@ -130,6 +171,14 @@ impl core::ops::Index<u32> for Entries {
     }
 }
 
+/// The size of a seek table in [`Format::Foot`], read from its integrity field.
+///
+/// Fails if `integrity` is not a valid seek table integrity field.
+#[cfg(feature = "tokio")]
+pub(crate) fn foot_size(integrity: &[u8; SEEK_TABLE_INTEGRITY_SIZE]) -> Result<usize> {
+    Parser::from_bytes(integrity).map(|p| p.seek_table_size)
+}
+
 #[derive(Debug)]
 struct Parser {
     num_frames: usize,
@@ -138,6 +187,9 @@ struct Parser {
     entries: Entries,
     c_offset: u64,
     d_offset: u64,
+    checksum: Option<u32>,
+    with_dictionary_id: bool,
+    dictionary_id: Option<u32>,
 }
 
 impl Parser {
@@ -147,19 +199,26 @@ impl Parser {
         }
 
         // Check reserved descriptor bits are not set
-        if ((buf[4] >> 2) & 0x1f) > 0 {
+        if ((buf[4] >> 2) & 0x0f) > 0 {
             return Err(Error::zstd(ZSTD_ErrorCode::ZSTD_error_corruption_detected));
         }
 
-        let with_checksum = (buf[4] & (1 << 7)) > 0;
+        let with_checksum = (buf[4] & CHECKSUM_FLAG) > 0;
+        let with_dictionary_id = (buf[4] & DICT_ID_FLAG) > 0;
         let num_frames = read_le32!(buf, 0);
         if num_frames > SEEKABLE_MAX_FRAMES {
-            return Err(Error::frame_index_too_large());
+            return Err(Error::frame_index_too_large_with(
+                num_frames - 1,
+                SEEKABLE_MAX_FRAMES - 1,
+            ));
         }
         let num_frames = usize::try_from(num_frames).expect("Number of frames never exceeds usize");
-        let size_per_frame: usize = if with_checksum { 12 } else { 8 };
-        let seek_table_size =
-            num_frames * size_per_frame + SKIPPABLE_HEADER_SIZE + SEEK_TABLE_INTEGRITY_SIZE;
+        let size_per_frame = if with_checksum {
+            SIZE_PER_FRAME_CHECKSUM
+        } else {
+            SIZE_PER_FRAME
+        };
+        let seek_table_size = encoded_len(num_frames, with_checksum, with_dictionary_id);
 
         Ok(Self {
             num_frames,
@@ -168,6 +227,9 @@ impl Parser {
             entries: Entries::with_num_frames(num_frames),
             c_offset: 0,
             d_offset: 0,
+            checksum: None,
+            with_dictionary_id,
+            dictionary_id: None,
         })
     }
 
@@ -189,6 +251,15 @@ impl Parser {
     fn parse_entries(&mut self, buf: &[u8]) -> usize {
         let mut pos: usize = 0;
 
+        // The dictionary id precedes the frame entries
+        if self.with_dictionary_id && self.dictionary_id.is_none() {
+            if buf.len() < DICT_ID_SIZE {
+                return pos;
+            }
+            self.dictionary_id = Some(read_le32!(buf, pos));
+            pos += DICT_ID_SIZE;
+        }
+
         while self.entries.0.len() < self.num_frames {
             if pos + self.size_per_frame > buf.len() {
                 return pos;
@@ -199,6 +270,10 @@ impl Parser {
             // Casting u32 to u64 is fine
             self.c_offset += read_le32!(buf, pos) as u64;
             self.d_offset += read_le32!(buf, pos + 4) as u64;
+            // The checksum belongs to the entry that marks the end of this frame
+            if self.size_per_frame == SIZE_PER_FRAME_CHECKSUM {
+                self.checksum = Some(read_le32!(buf, pos + 8));
+            }
             pos += self.size_per_frame;
         }
 
@@ -212,11 +287,14 @@ impl Parser {
         self.entries.0.push(Entry {
             c_offset: self.c_offset,
             d_offset: self.d_offset,
+            checksum: self.checksum,
         });
     }
 
     fn verify(&self) -> Result<()> {
-        if self.entries.0.len() == self.num_frames + 1 {
+        if self.entries.0.len() == self.num_frames + 1
+            && self.with_dictionary_id == self.dictionary_id.is_some()
+        {
             Ok(())
         } else {
             Err(Error::zstd(ZSTD_ErrorCode::ZSTD_error_corruption_detected))
@@ -224,6 +302,187 @@ impl Parser {
     }
 }
 
+/// The algorithm used to compute the frame checksums that are stored in the seek table.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ChecksumAlgo {
+    /// The lower 32 bits of the XXH64 digest of the decompressed frame data.
+    ///
+    /// This is the algorithm defined by the seekable format.
+    #[default]
+    Xxh64,
+    /// The CRC32 (IEEE) checksum of the decompressed frame data.
+    ///
+    /// **Note**: This is a zeekstd extension, the reference implementation expects XXH64
+    /// checksums and will fail to verify frames with CRC32 checksums.
+    #[cfg(feature = "crc32")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "crc32")))]
+    Crc32,
+}
+
+/// Computes frame checksums with a [`ChecksumAlgo`].
+pub(crate) enum FrameHasher {
+    Xxh64(Xxh64),
+    #[cfg(feature = "crc32")]
+    Crc32(crc32fast::Hasher),
+}
+
+impl FrameHasher {
+    pub(crate) fn new(algo: ChecksumAlgo) -> Self {
+        match algo {
+            ChecksumAlgo::Xxh64 => Self::Xxh64(Xxh64::new(0)),
+            #[cfg(feature = "crc32")]
+            ChecksumAlgo::Crc32 => Self::Crc32(crc32fast::Hasher::new()),
+        }
+    }
+
+    pub(crate) fn update(&mut self, data: &[u8]) {
+        match self {
+            Self::Xxh64(h) => h.update(data),
+            #[cfg(feature = "crc32")]
+            Self::Crc32(h) => h.update(data),
+        }
+    }
+
+    /// Returns the checksum of all data passed to this hasher since the last reset.
+    pub(crate) fn checksum(&self) -> u32 {
+        match self {
+            // The seekable format stores the lower 32 bits of the XXH64 digest
+            Self::Xxh64(h) => h.digest() as u32,
+            #[cfg(feature = "crc32")]
+            Self::Crc32(h) => h.clone().finalize(),
+        }
+    }
+
+    pub(crate) fn reset(&mut self) {
+        match self {
+            Self::Xxh64(h) => h.reset(0),
+            #[cfg(feature = "crc32")]
+            Self::Crc32(h) => h.reset(),
+        }
+    }
+}
+
+/// Statistics about the frames of a [`SeekTable`].
+///
+/// Returned by [`SeekTable::stats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SeekTableStats {
+    num_frames: u32,
+    min_frame_size_decomp: u64,
+    max_frame_size_decomp: u64,
+    mean_frame_size_decomp: u64,
+    size_comp: u64,
+    size_decomp: u64,
+    has_checksums: bool,
+}
+
+impl SeekTableStats {
+    /// The number of frames.
+    pub fn num_frames(&self) -> u32 {
+        self.num_frames
+    }
+
+    /// The minimum decompressed frame size, zero if there are no frames.
+    pub fn min_frame_size_decomp(&self) -> u64 {
+        self.min_frame_size_decomp
+    }
+
+    /// The maximum decompressed frame size, zero if there are no frames.
+    pub fn max_frame_size_decomp(&self) -> u64 {
+        self.max_frame_size_decomp
+    }
+
+    /// The mean decompressed frame size, rounded down. Zero if there are no frames.
+    pub fn mean_frame_size_decomp(&self) -> u64 {
+        self.mean_frame_size_decomp
+    }
+
+    /// The total compressed size of all frames.
+    pub fn size_comp(&self) -> u64 {
+        self.size_comp
+    }
+
+    /// The total decompressed size of all frames.
+    pub fn size_decomp(&self) -> u64 {
+        self.size_decomp
+    }
+
+    /// Whether every frame has a checksum.
+    pub fn has_checksums(&self) -> bool {
+        self.has_checksums
+    }
+}
+
+/// Information about a seek table, parsed from its integrity field.
+///
+/// Returned by [`SeekTable::read_integrity`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SeekTableInfo {
+    num_frames: u32,
+    with_checksum: bool,
+    seek_table_size: usize,
+}
+
+impl SeekTableInfo {
+    /// The number of frames.
+    pub fn num_frames(&self) -> u32 {
+        self.num_frames
+    }
+
+    /// Whether the frame entries contain checksums.
+    pub fn with_checksum(&self) -> bool {
+        self.with_checksum
+    }
+
+    /// The size of the seek table, including the skippable frame header.
+    pub fn seek_table_size(&self) -> usize {
+        self.seek_table_size
+    }
+}
+
+/// Information about a single frame of a [`SeekTable`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FrameInfo {
+    index: u32,
+    comp_offset: u64,
+    decomp_offset: u64,
+    comp_size: u64,
+    decomp_size: u64,
+    checksum: Option<u32>,
+}
+
+impl FrameInfo {
+    /// The index of the frame.
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// The start position of the frame in the compressed data.
+    pub fn comp_offset(&self) -> u64 {
+        self.comp_offset
+    }
+
+    /// The start position of the frame in the decompressed data.
+    pub fn decomp_offset(&self) -> u64 {
+        self.decomp_offset
+    }
+
+    /// The compressed size of the frame.
+    pub fn comp_size(&self) -> u64 {
+        self.comp_size
+    }
+
+    /// The decompressed size of the frame.
+    pub fn decomp_size(&self) -> u64 {
+        self.decomp_size
+    }
+
+    /// The checksum of the frame, if the seek table holds one.
+    pub fn checksum(&self) -> Option<u32> {
+        self.checksum
+    }
+}
+
 /// The format that should be used when serializing or deserializing the seek table.
 #[derive(Debug, Clone, Copy, Default)]
 pub enum Format {
@@ -264,8 +523,73 @@ pub enum Format {
 /// [`Head`]: Format#variant.Head
 /// [Zstandard skippable frame]: https://github.com/facebook/zstd/blob/dev/doc/zstd_compression_format.md#skippable-frames
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "SeekTableRepr", try_from = "SeekTableRepr")
+)]
 pub struct SeekTable {
     entries: Entries,
+    dictionary_id: Option<u32>,
+}
+
+/// The representation of a [`SeekTable`] with serde, independent of the binary format.
+///
+/// Stores the sizes of the frames, the offsets follow from the sizes and are always monotonic.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct SeekTableRepr {
+    frames: Vec<FrameRepr>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    dictionary_id: Option<u32>,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct FrameRepr {
+    comp_size: u32,
+    decomp_size: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    checksum: Option<u32>,
+}
+
+#[cfg(feature = "serde")]
+impl From<SeekTable> for SeekTableRepr {
+    fn from(value: SeekTable) -> Self {
+        let frames = value
+            .frames()
+            .map(|frame| FrameRepr {
+                // Frames are logged with 32 bit sizes
+                comp_size: frame.comp_size() as u32,
+                decomp_size: frame.decomp_size() as u32,
+                checksum: frame.checksum(),
+            })
+            .collect();
+
+        Self {
+            frames,
+            dictionary_id: value.dictionary_id,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<SeekTableRepr> for SeekTable {
+    type Error = Error;
+
+    fn try_from(value: SeekTableRepr) -> Result<Self> {
+        let mut seek_table = SeekTable::new();
+        for frame in value.frames {
+            seek_table.log_frame_with_checksum(
+                frame.comp_size,
+                frame.decomp_size,
+                frame.checksum,
+            )?;
+        }
+        seek_table.dictionary_id = value.dictionary_id;
+
+        Ok(seek_table)
+    }
 }
 
 impl Default for SeekTable {
@@ -278,6 +602,7 @@ impl From<Parser> for SeekTable {
     fn from(value: Parser) -> Self {
         SeekTable {
             entries: value.entries,
+            dictionary_id: value.dictionary_id,
         }
     }
 }
@@ -288,9 +613,13 @@ impl SeekTable {
         let entries = Entries(vec![Entry {
             c_offset: 0,
             d_offset: 0,
+            checksum: None,
         }]);
 
-        Self { entries }
+        Self {
+            entries,
+            dictionary_id: None,
+        }
     }
 
     /// Parses the seek table from a seekable input.
@@ -311,7 +640,7 @@ impl SeekTable {
     /// ```
     /// # let mut seek_table = SeekTable::new();
     /// # seek_table.log_frame(123, 456)?;
-    /// # let mut ser = seek_table.into_serializer();
+    /// # let mut ser = seek_table.into_serializer()?;
     /// # let mut buf = [0u8; 32];
     /// # let n = ser.write_into(&mut buf);
     /// # let seek_table_bytes = &buf[..n];
@@ -339,6 +668,182 @@ impl SeekTable {
         Self::from_seekable_format(src, Format::Foot)
     }
 
+    /// Parses the seek table from a seekable input that has `trailing_bytes` after the seek table.
+    ///
+    /// This is useful for archives that are embedded in containers, or archives that are padded
+    /// at the end. The seek table is expected in [`Foot`] format.
+    ///
+    /// [`Foot`]: Format#variant.Foot
+    ///
+    /// # Errors
+    ///
+    /// Fails if the seek table is in the wrong format, or if verification fails for another reason.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # let mut seek_table = SeekTable::new();
+    /// # seek_table.log_frame(123, 456)?;
+    /// # let mut ser = seek_table.into_serializer()?;
+    /// # let mut buf = [0u8; 64];
+    /// # let n = ser.write_into(&mut buf);
+    /// # let archive = &buf[..n + 16];
+    /// use zeekstd::{BytesWrapper, SeekTable};
+    ///
+    /// // The archive is padded with 16 bytes
+    /// let mut wrapper = BytesWrapper::new(archive);
+    /// let seek_table = SeekTable::from_seekable_trailing(&mut wrapper, 16)?;
+    /// # assert_eq!(seek_table.num_frames(), 1);
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    pub fn from_seekable_trailing(src: &mut impl Seekable, trailing_bytes: u64) -> Result<Self> {
+        if trailing_bytes == 0 {
+            return Self::from_seekable(src);
+        }
+
+        Self::from_seekable(&mut WithoutTrailing::new(src, trailing_bytes)?)
+    }
+
+    /// Rebuilds the seek table by scanning the frames of a seekable input.
+    ///
+    /// Use this to recover archives with a missing or corrupted seek table, e.g. because writing
+    /// the seek table was interrupted. Every frame is decompressed to determine its size, which
+    /// makes this much slower than parsing a seek table. Scanning starts at the beginning of `src`
+    /// and stops at the end of `src` or at the first skippable frame, which is usually the
+    /// (damaged) seek table. An incomplete frame at the end of `src` is not included.
+    ///
+    /// Frame checksums are not restored.
+    ///
+    /// # Errors
+    ///
+    /// Fails if reading from `src` fails or a frame cannot be decompressed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[cfg(feature = "std")] {
+    /// use std::fs::File;
+    /// use zeekstd::SeekTable;
+    ///
+    /// let mut seekable = File::open("footer_missing.zst")?;
+    /// let seek_table = SeekTable::rebuild_from_frames(&mut seekable)?;
+    /// # }
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    pub fn rebuild_from_frames(src: &mut impl Seekable) -> Result<Self> {
+        Self::scan(src, false)
+    }
+
+    /// Builds a seek table by scanning the frames of a seekable input.
+    ///
+    /// This makes plain concatenated zstd frames without a seek table seekable, e.g. output of
+    /// tools that don't write the seek table. Every frame is decompressed once to determine its
+    /// size. Scanning starts at the beginning of `src` and stops at the end of `src` or at the
+    /// first skippable frame. Unlike [`Self::rebuild_from_frames`], an incomplete frame at the
+    /// end of `src` is an error.
+    ///
+    /// Frame checksums are not computed.
+    ///
+    /// # Errors
+    ///
+    /// Fails if reading from `src` fails, a frame cannot be decompressed or the last frame is
+    /// incomplete.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # let mut frame = vec![0; zstd_safe::compress_bound(5)];
+    /// # let n = zstd_safe::compress(&mut frame[..], b"Hello", 3).unwrap();
+    /// # let frames = [&frame[..n], &frame[..n]].concat();
+    /// use zeekstd::{BytesWrapper, SeekTable};
+    ///
+    /// // Two zstd frames without a seek table
+    /// let seek_table = SeekTable::scan_frames(&mut BytesWrapper::new(&frames))?;
+    /// assert_eq!(seek_table.num_frames(), 2);
+    /// assert_eq!(seek_table.size_decomp(), 10);
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    pub fn scan_frames(src: &mut impl Seekable) -> Result<Self> {
+        Self::scan(src, true)
+    }
+
+    /// Logs every frame of `src` until the end or the first skippable frame, failing on an
+    /// incomplete last frame if `strict` is set.
+    fn scan(src: &mut impl Seekable, strict: bool) -> Result<Self> {
+        let truncated = || {
+            if strict {
+                Err(Error::zstd(ZSTD_ErrorCode::ZSTD_error_srcSize_wrong))
+            } else {
+                Ok(())
+            }
+        };
+        let mut st = Self::new();
+        let mut dctx =
+            DCtx::try_create().ok_or(Error::zstd(ZSTD_ErrorCode::ZSTD_error_memory_allocation))?;
+        let mut in_buf = vec![0u8; DCtx::in_size()];
+        let mut out_buf = vec![0u8; DCtx::out_size()];
+        let mut in_pos = 0;
+        let mut in_limit = 0;
+        let mut frame_start = true;
+        let mut out_full = false;
+        let mut c_size: u64 = 0;
+        let mut d_size: u64 = 0;
+
+        src.set_offset(OffsetFrom::Start(0))?;
+        loop {
+            if frame_start {
+                // Make sure the magic number of the next frame is available
+                in_buf.copy_within(in_pos..in_limit, 0);
+                in_limit -= in_pos;
+                in_pos = 0;
+                while in_limit < 4 {
+                    let n = src.read(&mut in_buf[in_limit..])?;
+                    if n == 0 {
+                        // End of src, incomplete magic numbers are ignored if not strict
+                        if in_limit > 0 {
+                            truncated()?;
+                        }
+                        return Ok(st);
+                    }
+                    in_limit += n;
+                }
+
+                let magic = read_le32!(in_buf, 0);
+                if magic & ZSTD_MAGIC_SKIPPABLE_MASK == ZSTD_MAGIC_SKIPPABLE_START {
+                    return Ok(st);
+                }
+                frame_start = false;
+            }
+
+            // Pending output may be left even if all input is consumed
+            if in_pos == in_limit && !out_full {
+                in_limit = src.read(&mut in_buf)?;
+                in_pos = 0;
+                if in_limit == 0 {
+                    // End of src within a frame, the incomplete frame is ignored if not strict
+                    truncated()?;
+                    return Ok(st);
+                }
+            }
+
+            let mut input = InBuffer::around(&in_buf[in_pos..in_limit]);
+            let mut output = OutBuffer::around(&mut out_buf[..]);
+            let n = dctx.decompress_stream(&mut output, &mut input)?;
+            in_pos += input.pos();
+            c_size += input.pos() as u64;
+            d_size += output.pos() as u64;
+            out_full = output.pos() == output.capacity();
+
+            // Frame end
+            if n == 0 {
+                st.log_frame(u32::try_from(c_size)?, u32::try_from(d_size)?)?;
+                c_size = 0;
+                d_size = 0;
+                frame_start = true;
+            }
+        }
+    }
+
     /// Parses the seek table from a seekable input, expecting the given `format`.
     ///
     /// # Errors
@@ -352,7 +857,7 @@ impl SeekTable {
     /// ```
     /// # let mut seek_table = SeekTable::new();
     /// # seek_table.log_frame(123, 456)?;
-    /// # let mut ser = seek_table.into_format_serializer(Format::Head);
+    /// # let mut ser = seek_table.into_format_serializer(Format::Head)?;
     /// # let mut buf = [0u8; 32];
     /// # let n = ser.write_into(&mut buf);
     /// # let seek_table_bytes = &buf[..n];
@@ -385,11 +890,18 @@ impl SeekTable {
             Format::Foot => src.set_offset(OffsetFrom::End(-(parser.seek_table_size as i64)))?,
         };
 
+        let mut buf_start = SKIPPABLE_HEADER_SIZE;
+        if matches!(format, Format::Head) {
+            buf_start += SEEK_TABLE_INTEGRITY_SIZE;
+        }
+
         let len = 8192.min(parser.seek_table_size);
         let mut buf = vec![0u8; len];
         let mut read = 0;
-        while read < SKIPPABLE_HEADER_SIZE {
-            let n = src.read(&mut buf)?;
+        // The source may return less bytes than requested, read until everything in front of the
+        // frame entries is available
+        while read < buf_start {
+            let n = src.read(&mut buf[read..])?;
             if n == 0 {
                 // Error if src is EOF already
                 return Err(Error::zstd(ZSTD_ErrorCode::ZSTD_error_corruption_detected));
@@ -398,11 +910,6 @@ impl SeekTable {
         }
         parser.verify_skippable_header(&buf[..SKIPPABLE_HEADER_SIZE])?;
 
-        let mut buf_start = SKIPPABLE_HEADER_SIZE;
-        if matches!(format, Format::Head) {
-            buf_start += SEEK_TABLE_INTEGRITY_SIZE;
-        }
-
         // Data that still has to be parsed
         let mut remaining =
             parser.seek_table_size - SKIPPABLE_HEADER_SIZE - SEEK_TABLE_INTEGRITY_SIZE;
@@ -435,6 +942,104 @@ impl SeekTable {
         Ok(parser.into())
     }
 
+    /// Reads only the integrity field of the seek table in `src`.
+    ///
+    /// The integrity field holds the number of frames and whether the frame entries contain
+    /// checksums, which also determines the size of the seek table. In contrast to parsing the
+    /// whole seek table, this reads only a few bytes, regardless of the number of frames. The
+    /// frame entries are not verified.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the integrity field cannot be read or is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zeekstd::{BytesWrapper, SeekTable, seek_table::Format};
+    ///
+    /// let mut seek_table = SeekTable::new();
+    /// seek_table.log_frame(123, 456)?;
+    /// let mut ser = seek_table.into_serializer()?;
+    /// let mut buf = vec![0; ser.encoded_len()];
+    /// ser.write_into(&mut buf);
+    ///
+    /// let info = SeekTable::read_integrity(&mut BytesWrapper::new(&buf), Format::Foot)?;
+    /// assert_eq!(1, info.num_frames());
+    /// assert_eq!(buf.len(), info.seek_table_size());
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    pub fn read_integrity(src: &mut impl Seekable, format: Format) -> Result<SeekTableInfo> {
+        let integrity = src.seek_table_integrity(format)?;
+        let parser = Parser::from_bytes(&integrity)?;
+
+        Ok(SeekTableInfo {
+            num_frames: u32::try_from(parser.num_frames)?,
+            with_checksum: parser.size_per_frame == SIZE_PER_FRAME_CHECKSUM,
+            seek_table_size: parser.seek_table_size,
+        })
+    }
+
+    /// Parses the seek table from a byte slice, expecting the given `format`.
+    ///
+    /// This is a shorthand for [`Self::from_seekable_format`] with a [`crate::BytesWrapper`].
+    ///
+    /// # Errors
+    ///
+    /// Fails if the seek table is in the wrong format, or if verification fails for another reason.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zeekstd::{SeekTable, seek_table::Format};
+    ///
+    /// let mut seek_table = SeekTable::new();
+    /// seek_table.log_frame(123, 456)?;
+    /// let mut ser = seek_table.clone().into_format_serializer(Format::Head)?;
+    /// let mut buf = vec![0; ser.encoded_len()];
+    /// ser.write_into(&mut buf);
+    ///
+    /// assert_eq!(seek_table, SeekTable::from_bytes(&buf, Format::Head)?);
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    pub fn from_bytes(buf: &[u8], format: Format) -> Result<Self> {
+        Self::from_seekable_format(&mut crate::BytesWrapper::new(buf), format)
+    }
+
+    /// Parses the seek table from a seekable input, detecting the [`Format`] automatically.
+    ///
+    /// Tries to parse the seek table in [`Head`] format first, i.e. checks whether the seek table
+    /// integrity field follows the skippable header at the start of `src`. If that fails, the
+    /// seek table is parsed in [`Foot`] format.
+    ///
+    /// [`Head`]: Format#variant.Head
+    /// [`Foot`]: Format#variant.Foot
+    ///
+    /// # Errors
+    ///
+    /// Fails if the seek table cannot be parsed in either format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # let mut seek_table = SeekTable::new();
+    /// # seek_table.log_frame(123, 456)?;
+    /// # let mut ser = seek_table.into_format_serializer(Format::Head)?;
+    /// # let mut buf = [0u8; 32];
+    /// # let n = ser.write_into(&mut buf);
+    /// # let seek_table_bytes = &buf[..n];
+    /// use zeekstd::{BytesWrapper, SeekTable, seek_table::Format};
+    ///
+    /// let mut wrapper = BytesWrapper::new(seek_table_bytes);
+    /// let seek_table = SeekTable::from_seekable_auto(&mut wrapper)?;
+    /// # assert_eq!(seek_table.num_frames(), 1);
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    pub fn from_seekable_auto(src: &mut impl Seekable) -> Result<Self> {
+        Self::from_seekable_format(src, Format::Head)
+            .or_else(|_| Self::from_seekable_format(src, Format::Foot))
+    }
+
     /// Reads and parses a seek table from `reader`.
     ///
     /// Only works if the seek table is in [`Head`] format.
@@ -492,15 +1097,89 @@ impl SeekTable {
         Ok(parser.into())
     }
 
-    /// Adds a frame to this seek table.
+    /// Reads a complete seekable archive of `total_len` bytes from `reader` and parses the seek
+    /// table at its end.
+    ///
+    /// Use this for seek tables in [`Foot`] format if the archive is only available as a stream.
+    /// Everything in front of the seek table is read and discarded. The seek table is located by
+    /// its skippable frame header, whose frame size has to point exactly to the end of the
+    /// archive.
+    ///
+    /// **Memory cost**: Only the bytes from the start of the seek table onward are buffered, the
+    /// buffer grows to roughly the size of the serialized seek table. If the compressed data
+    /// contains a byte sequence that looks like such a header, everything from that position is
+    /// buffered as well.
+    ///
+    /// [`Foot`]: Format#variant.Foot
     ///
     /// # Errors
     ///
-    /// Fails if [`Self::num_frames()`] reaches [`SEEKABLE_MAX_FRAMES`].
+    /// Fails if `reader` doesn't yield exactly `total_len` bytes, if no seek table is found, or if
+    /// verification fails for another reason.
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
+    /// use std::fs::File;
+    /// use zeekstd::SeekTable;
+    ///
+    /// let mut reader = File::open("seekable.zst")?;
+    /// let len = reader.metadata()?.len();
+    /// let seek_table = SeekTable::from_reader_foot(&mut reader, len)?;
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn from_reader_foot(mut reader: impl std::io::Read, total_len: u64) -> Result<Self> {
+        let mut buf = vec![0u8; 8192];
+        // Holds the bytes from the first possible seek table start onward, or the last few bytes
+        // that could be the beginning of a skippable header
+        let mut tail = Vec::new();
+        // The position of the first byte of tail in the archive
+        let mut tail_pos: u64 = 0;
+        let mut found = false;
+
+        loop {
+            let n = reader.read(&mut buf)?;
+            if n == 0 {
+                break;
+            }
+            tail.extend_from_slice(&buf[..n]);
+            if found {
+                continue;
+            }
+
+            let start = (0..=tail.len().saturating_sub(SKIPPABLE_HEADER_SIZE)).find(|&i| {
+                i + SKIPPABLE_HEADER_SIZE <= tail.len()
+                    && read_le32!(tail, i) == SKIPPABLE_MAGIC_NUMBER
+                    && tail_pos
+                        + (i + SKIPPABLE_HEADER_SIZE) as u64
+                        + u64::from(read_le32!(tail, i + 4))
+                        == total_len
+            });
+            // Keep the bytes that may still start a skippable header
+            let drain = start.unwrap_or(tail.len().saturating_sub(SKIPPABLE_HEADER_SIZE - 1));
+            found = start.is_some();
+            tail.drain(..drain);
+            tail_pos += drain as u64;
+        }
+
+        if tail_pos + tail.len() as u64 != total_len || !found {
+            return Err(Error::zstd(ZSTD_ErrorCode::ZSTD_error_corruption_detected));
+        }
+
+        Self::from_seekable(&mut crate::BytesWrapper::new(&tail))
+    }
+
+    /// Adds a frame to this seek table.
+    ///
+    /// # Errors
+    ///
+    /// Fails if [`Self::num_frames()`] reaches [`SEEKABLE_MAX_FRAMES`].
+    ///
+    /// # Examples
+    ///
+    /// ```
     /// use zeekstd::SeekTable;
     ///
     /// let mut seek_table = SeekTable::new();
@@ -511,19 +1190,262 @@ impl SeekTable {
     /// # Ok::<(), zeekstd::Error>(())
     /// ```
     pub fn log_frame(&mut self, c_size: u32, d_size: u32) -> Result<()> {
+        self.log_frame_with_checksum(c_size, d_size, None)
+    }
+
+    /// Adds a frame with an optional checksum to this seek table.
+    ///
+    /// The checksum is computed over the decompressed frame data with the [`ChecksumAlgo`] of the
+    /// archive, by default it is the lower 32 bits of the XXH64 digest. Checksums are only
+    /// serialized if every frame in the seek table has one.
+    ///
+    /// # Errors
+    ///
+    /// Fails if [`Self::num_frames()`] reaches [`SEEKABLE_MAX_FRAMES`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zeekstd::SeekTable;
+    ///
+    /// let mut seek_table = SeekTable::new();
+    /// seek_table.log_frame_with_checksum(123, 456, Some(0xc0ffee))?;
+    ///
+    /// assert_eq!(Some(0xc0ffee), seek_table.frame_checksum(0)?);
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    pub fn log_frame_with_checksum(
+        &mut self,
+        c_size: u32,
+        d_size: u32,
+        checksum: Option<u32>,
+    ) -> Result<()> {
         if self.num_frames() >= SEEKABLE_MAX_FRAMES {
-            return Err(Error::frame_index_too_large());
+            return Err(Error::frame_index_too_large_with(
+                self.num_frames(),
+                SEEKABLE_MAX_FRAMES - 1,
+            ));
         }
 
         let last = &self.entries[self.num_frames()];
         self.entries.0.push(Entry {
             c_offset: last.c_offset + c_size as u64,
             d_offset: last.d_offset + d_size as u64,
+            checksum,
         });
 
         Ok(())
     }
 
+    /// Appends the frames of `other` to this seek table.
+    ///
+    /// The offsets of the appended frames are shifted by the compressed and decompressed size of
+    /// this seek table. Use this to create the seek table of concatenated frames, e.g. when
+    /// archives are compressed in shards and their frames are written one after another. Note
+    /// that only the frames are concatenated, the seek tables of the shards must not be part of
+    /// the data.
+    ///
+    /// The [dictionary id](Self::dictionary_id) of `other` is taken over if this seek table has
+    /// none.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the combined number of frames exceeds [`SEEKABLE_MAX_FRAMES`] or if both seek
+    /// tables have a different dictionary id. This seek table remains unchanged in these cases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zeekstd::SeekTable;
+    ///
+    /// let mut seek_table = SeekTable::new();
+    /// seek_table.log_frame(123, 456)?;
+    /// let mut other = SeekTable::new();
+    /// other.log_frame(333, 444)?;
+    ///
+    /// seek_table.merge(&other)?;
+    ///
+    /// assert_eq!(2, seek_table.num_frames());
+    /// assert_eq!(123, seek_table.frame_start_comp(1)?);
+    /// assert_eq!(456, seek_table.frame_start_decomp(1)?);
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    pub fn merge(&mut self, other: &SeekTable) -> Result<()> {
+        if self.num_frames() as u64 + other.num_frames() as u64 > SEEKABLE_MAX_FRAMES as u64 {
+            // Both tables hold at most the maximum number of frames, the sum fits
+            return Err(Error::frame_index_too_large_with(
+                self.num_frames() + other.num_frames() - 1,
+                SEEKABLE_MAX_FRAMES - 1,
+            ));
+        }
+        if let (Some(id), Some(other_id)) = (self.dictionary_id, other.dictionary_id)
+            && id != other_id
+        {
+            return Err(Error::zstd(ZSTD_ErrorCode::ZSTD_error_dictionary_wrong));
+        }
+
+        self.dictionary_id = self.dictionary_id.or(other.dictionary_id);
+        let (c_size, d_size) = (self.size_comp(), self.size_decomp());
+        self.entries
+            .0
+            .extend(other.entries.0[1..].iter().map(|e| Entry {
+                c_offset: c_size + e.c_offset,
+                d_offset: d_size + e.d_offset,
+                checksum: e.checksum,
+            }));
+
+        Ok(())
+    }
+
+    /// Shortens the seek table, keeping the first `num_frames` frames and dropping the rest.
+    ///
+    /// Has no effect if `num_frames` is greater than or equal to the current number of frames.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zeekstd::SeekTable;
+    ///
+    /// let mut seek_table = SeekTable::new();
+    /// seek_table.log_frame(123, 456)?;
+    /// seek_table.log_frame(333, 444)?;
+    ///
+    /// seek_table.truncate(1);
+    ///
+    /// assert_eq!(1, seek_table.num_frames());
+    /// assert_eq!(123, seek_table.size_comp());
+    /// assert_eq!(456, seek_table.size_decomp());
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    pub fn truncate(&mut self, num_frames: u32) {
+        // The entry at index num_frames marks the end of the last kept frame
+        let len = usize::try_from(num_frames).map_or(usize::MAX, |n| n.saturating_add(1));
+        self.entries.0.truncate(len);
+    }
+
+    /// Creates a seek table for the frames `from` to `to` (inclusive) of this seek table.
+    ///
+    /// The offsets are rebased, so the first frame of the range starts at offset 0. Together with
+    /// the compressed data of the range, i.e. the bytes from [`Self::frame_start_comp`]`(from)` to
+    /// [`Self::frame_end_comp`]`(to)`, this forms a stand-alone seekable archive. The frames don't
+    /// have to be recompressed, they are independent of each other.
+    ///
+    /// # Errors
+    ///
+    /// Fails if `to` is out of range or `from` is greater than `to`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zeekstd::SeekTable;
+    ///
+    /// let mut seek_table = SeekTable::new();
+    /// seek_table.log_frame(123, 456)?;
+    /// seek_table.log_frame(333, 444)?;
+    /// seek_table.log_frame(555, 666)?;
+    ///
+    /// let sub = seek_table.subtable(1, 2)?;
+    ///
+    /// assert_eq!(2, sub.num_frames());
+    /// assert_eq!(333, sub.frame_start_comp(1)?);
+    /// assert_eq!(444 + 666, sub.size_decomp());
+    /// // The compressed data of the sub archive
+    /// let range = seek_table.frame_start_comp(1)?..seek_table.frame_end_comp(2)?;
+    /// assert_eq!(range.end - range.start, sub.size_comp());
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    pub fn subtable(&self, from: u32, to: u32) -> Result<SeekTable> {
+        if from > to {
+            return Err(Error::frame_index_too_large());
+        }
+        self.check_index(to)?;
+
+        let start = &self.entries[from];
+        let mut entries = Vec::with_capacity((to - from) as usize + 2);
+        entries.push(Entry {
+            c_offset: 0,
+            d_offset: 0,
+            checksum: None,
+        });
+        entries.extend(
+            self.entries.0[from as usize + 1..=to as usize + 1]
+                .iter()
+                .map(|e| Entry {
+                    c_offset: e.c_offset - start.c_offset,
+                    d_offset: e.d_offset - start.d_offset,
+                    checksum: e.checksum,
+                }),
+        );
+
+        Ok(Self {
+            entries: Entries(entries),
+            dictionary_id: self.dictionary_id,
+        })
+    }
+
+    /// Checks whether the seek table of the archive in `src` is identical to this seek table.
+    ///
+    /// The seek table of `src` is parsed from the end of `src` and compared frame by frame,
+    /// including the frame checksums. Use this to pin the frame layout of an archive and detect
+    /// modifications without decompressing it. Note that only the seek table is compared, the
+    /// frame data itself is not verified.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the seek table of `src` cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[cfg(feature = "std")] {
+    /// use std::fs::File;
+    /// use zeekstd::SeekTable;
+    ///
+    /// let mut expected = File::open("expected_seek_table")?;
+    /// let expected = SeekTable::from_reader(&mut expected)?;
+    /// let mut seekable = File::open("seekable.zst")?;
+    ///
+    /// assert!(expected.matches(&mut seekable)?);
+    /// # }
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    pub fn matches(&self, src: &mut impl Seekable) -> Result<bool> {
+        let other = Self::from_seekable(src)?;
+
+        Ok(*self == other)
+    }
+
+    /// The id of the dictionary that is needed to decompress the frames, if recorded.
+    ///
+    /// Only seek tables in the extended format carry a dictionary id, see
+    /// [`Self::set_dictionary_id`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zeekstd::SeekTable;
+    ///
+    /// let mut seek_table = SeekTable::new();
+    /// assert_eq!(None, seek_table.dictionary_id());
+    ///
+    /// seek_table.set_dictionary_id(Some(42));
+    /// assert_eq!(Some(42), seek_table.dictionary_id());
+    /// ```
+    pub fn dictionary_id(&self) -> Option<u32> {
+        self.dictionary_id
+    }
+
+    /// Sets the id of the dictionary that is needed to decompress the frames.
+    ///
+    /// A seek table with a dictionary id is serialized in an extended format, which sets a
+    /// reserved bit of the seek table descriptor and places the id in front of the frame entries.
+    /// Readers that don't support the extension, including older versions of this crate and other
+    /// implementations of the seekable format, reject such seek tables. Pass `None` to serialize
+    /// the seek table in the standard format.
+    pub fn set_dictionary_id(&mut self, dictionary_id: Option<u32>) {
+        self.dictionary_id = dictionary_id;
+    }
+
     /// The number of frames in the seek table.
     ///
     /// # Examples
@@ -580,6 +1502,114 @@ impl SeekTable {
         self.frame_index_at(offset, |i| self.entries[i].d_offset)
     }
 
+    /// The frame index at the given decompressed offset, failing for offsets past the end.
+    ///
+    /// Unlike [`Self::frame_index_decomp`], this doesn't map every offset beyond the decompressed
+    /// data to the last frame. The end of the decompressed data, i.e. `offset == size_decomp()`,
+    /// still maps to the last frame.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the seek table has no frames or `offset` is greater than the total decompressed
+    /// size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zeekstd::SeekTable;
+    ///
+    /// let mut seek_table = SeekTable::new();
+    /// seek_table.log_frame(100, 200)?;
+    /// seek_table.log_frame(100, 200)?;
+    ///
+    /// assert_eq!(1, seek_table.try_frame_index_decomp(400)?);
+    /// assert!(seek_table.try_frame_index_decomp(401).is_err());
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    pub fn try_frame_index_decomp(&self, offset: u64) -> Result<u32> {
+        if offset > self.size_decomp() {
+            return Err(Error::offset_out_of_range_with(offset, self.size_decomp()));
+        }
+        if self.num_frames() == 0 {
+            return Err(Error::offset_out_of_range());
+        }
+
+        Ok(self.frame_index_decomp(offset))
+    }
+
+    /// The inclusive range of frame indices that cover the decompressed byte range from
+    /// `start` (inclusive) to `end` (exclusive).
+    ///
+    /// An empty range, i.e. `start == end`, maps to the single frame that contains `start`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the seek table has no frames, `start` is greater than `end`, or `end` is greater
+    /// than the total decompressed size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zeekstd::SeekTable;
+    ///
+    /// let mut seek_table = SeekTable::new();
+    /// seek_table.log_frame(100, 200)?;
+    /// seek_table.log_frame(100, 200)?;
+    /// seek_table.log_frame(100, 200)?;
+    ///
+    /// assert_eq!((0, 1), seek_table.byte_range_to_frames(150, 201)?);
+    /// assert_eq!((1, 2), seek_table.byte_range_to_frames(200, 600)?);
+    /// assert!(seek_table.byte_range_to_frames(0, 601).is_err());
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    pub fn byte_range_to_frames(&self, start: u64, end: u64) -> Result<(u32, u32)> {
+        if start > end {
+            return Err(Error::offset_out_of_range());
+        }
+        if end > self.size_decomp() {
+            return Err(Error::offset_out_of_range_with(end, self.size_decomp()));
+        }
+        if self.num_frames() == 0 {
+            return Err(Error::offset_out_of_range());
+        }
+
+        let first = self.frame_index_decomp(start);
+        let last = if start == end {
+            first
+        } else {
+            self.frame_index_decomp(end - 1)
+        };
+
+        Ok((first, last))
+    }
+
+    /// The decompressed start position of the frame that contains the compressed `offset`.
+    ///
+    /// This maps a position in the compressed data, e.g. of a damaged byte, to the decompressed
+    /// data. Offsets beyond the end of the compressed data map to the start of the last frame.
+    /// Returns 0 if the seek table has no frames.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zeekstd::SeekTable;
+    ///
+    /// let mut seek_table = SeekTable::new();
+    /// seek_table.log_frame(100, 200)?;
+    /// seek_table.log_frame(100, 200)?;
+    ///
+    /// assert_eq!(0, seek_table.decomp_offset_for_comp(99));
+    /// assert_eq!(200, seek_table.decomp_offset_for_comp(100));
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    pub fn decomp_offset_for_comp(&self, c_offset: u64) -> u64 {
+        if self.num_frames() == 0 {
+            return 0;
+        }
+
+        self.entries[self.frame_index_comp(c_offset)].d_offset
+    }
+
     /// The start position of frame `index` in the compressed data.
     ///
     /// # Errors
@@ -602,9 +1632,7 @@ impl SeekTable {
     /// # Ok::<(), zeekstd::Error>(())
     /// ```
     pub fn frame_start_comp(&self, index: u32) -> Result<u64> {
-        if index >= self.num_frames() {
-            return Err(Error::frame_index_too_large());
-        }
+        self.check_index(index)?;
 
         Ok(self.entries[index].c_offset)
     }
@@ -631,9 +1659,7 @@ impl SeekTable {
     /// # Ok::<(), zeekstd::Error>(())
     /// ```
     pub fn frame_start_decomp(&self, index: u32) -> Result<u64> {
-        if index >= self.num_frames() {
-            return Err(Error::frame_index_too_large());
-        }
+        self.check_index(index)?;
 
         Ok(self.entries[index].d_offset)
     }
@@ -660,9 +1686,7 @@ impl SeekTable {
     /// # Ok::<(), zeekstd::Error>(())
     /// ```
     pub fn frame_end_comp(&self, index: u32) -> Result<u64> {
-        if index >= self.num_frames() {
-            return Err(Error::frame_index_too_large());
-        }
+        self.check_index(index)?;
 
         Ok(self.entries[index + 1].c_offset)
     }
@@ -689,9 +1713,7 @@ impl SeekTable {
     /// # Ok::<(), zeekstd::Error>(())
     /// ```
     pub fn frame_end_decomp(&self, index: u32) -> Result<u64> {
-        if index >= self.num_frames() {
-            return Err(Error::frame_index_too_large());
-        }
+        self.check_index(index)?;
 
         Ok(self.entries[index + 1].d_offset)
     }
@@ -718,9 +1740,7 @@ impl SeekTable {
     /// # Ok::<(), zeekstd::Error>(())
     /// ```
     pub fn frame_size_comp(&self, index: u32) -> Result<u64> {
-        if index >= self.num_frames() {
-            return Err(Error::frame_index_too_large());
-        }
+        self.check_index(index)?;
 
         let size = self.entries[index + 1].c_offset - self.entries[index].c_offset;
         Ok(size)
@@ -748,14 +1768,73 @@ impl SeekTable {
     /// # Ok::<(), zeekstd::Error>(())
     /// ```
     pub fn frame_size_decomp(&self, index: u32) -> Result<u64> {
-        if index >= self.num_frames() {
-            return Err(Error::frame_index_too_large());
-        }
+        self.check_index(index)?;
 
         let size = self.entries[index + 1].d_offset - self.entries[index].d_offset;
         Ok(size)
     }
 
+    /// The checksum of frame `index`, if the seek table holds one.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the frame index is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zeekstd::SeekTable;
+    ///
+    /// let mut seek_table = SeekTable::new();
+    /// seek_table.log_frame(100, 200)?;
+    ///
+    /// assert_eq!(None, seek_table.frame_checksum(0).unwrap());
+    /// assert!(seek_table.frame_checksum(1).unwrap_err().is_frame_index_too_large());
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    pub fn frame_checksum(&self, index: u32) -> Result<Option<u32>> {
+        self.check_index(index)?;
+
+        Ok(self.entries[index + 1].checksum)
+    }
+
+    /// The total compressed and decompressed size of the frames `start..=end`.
+    ///
+    /// Returns a tuple of `(compressed, decompressed)` size. Both sizes are zero if `start` is
+    /// greater than `end`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if a frame index is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zeekstd::SeekTable;
+    ///
+    /// let mut seek_table = SeekTable::new();
+    /// seek_table.log_frame(100, 200)?;
+    /// seek_table.log_frame(150, 250)?;
+    /// seek_table.log_frame(100, 200)?;
+    ///
+    /// assert_eq!((250, 450), seek_table.range_sizes(0, 1)?);
+    /// assert_eq!((100, 200), seek_table.range_sizes(2, 2)?);
+    /// assert_eq!((0, 0), seek_table.range_sizes(2, 1)?);
+    ///
+    /// assert!(seek_table.range_sizes(0, 3).unwrap_err().is_frame_index_too_large());
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    pub fn range_sizes(&self, start: u32, end: u32) -> Result<(u64, u64)> {
+        let comp = self
+            .frame_end_comp(end)?
+            .saturating_sub(self.frame_start_comp(start)?);
+        let decomp = self
+            .frame_end_decomp(end)?
+            .saturating_sub(self.frame_start_decomp(start)?);
+
+        Ok((comp, decomp))
+    }
+
     /// The maximum compressed frame size.
     ///
     /// # Examples
@@ -792,18 +1871,124 @@ impl SeekTable {
     /// seek_table.log_frame(150, 250)?;
     /// seek_table.log_frame(100, 200)?;
     ///
-    /// assert_eq!(250, seek_table.max_frame_size_decomp());
+    /// assert_eq!(250, seek_table.max_frame_size_decomp());
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    pub fn max_frame_size_decomp(&self) -> u64 {
+        self.stats().max_frame_size_decomp()
+    }
+
+    /// Computes statistics about the frames of this seek table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zeekstd::SeekTable;
+    ///
+    /// let mut seek_table = SeekTable::new();
+    /// seek_table.log_frame(150, 250)?;
+    /// seek_table.log_frame(100, 200)?;
+    ///
+    /// let stats = seek_table.stats();
+    /// assert_eq!(2, stats.num_frames());
+    /// assert_eq!(200, stats.min_frame_size_decomp());
+    /// assert_eq!(250, stats.max_frame_size_decomp());
+    /// assert_eq!(225, stats.mean_frame_size_decomp());
+    /// assert_eq!(250, stats.size_comp());
+    /// assert_eq!(450, stats.size_decomp());
+    /// assert!(!stats.has_checksums());
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    pub fn stats(&self) -> SeekTableStats {
+        let mut min = u64::MAX;
+        let mut max = 0;
+        for w in self.entries.0.windows(2) {
+            let d_size = w[1].d_offset - w[0].d_offset;
+            min = min.min(d_size);
+            max = max.max(d_size);
+        }
+
+        let num_frames = self.num_frames();
+        let size_decomp = self.size_decomp();
+
+        SeekTableStats {
+            num_frames,
+            min_frame_size_decomp: if num_frames == 0 { 0 } else { min },
+            max_frame_size_decomp: max,
+            mean_frame_size_decomp: size_decomp.checked_div(num_frames.into()).unwrap_or(0),
+            size_comp: self.size_comp(),
+            size_decomp,
+            has_checksums: self.entries.with_checksum(),
+        }
+    }
+
+    /// Returns an iterator over all frames of this seek table, ordered by frame index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zeekstd::SeekTable;
+    ///
+    /// let mut seek_table = SeekTable::new();
+    /// seek_table.log_frame(150, 250)?;
+    /// seek_table.log_frame(100, 200)?;
+    ///
+    /// let offsets: Vec<_> = seek_table.frames().map(|f| f.comp_offset()).collect();
+    /// assert_eq!(vec![0, 150], offsets);
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    pub fn frames(&self) -> impl ExactSizeIterator<Item = FrameInfo> + DoubleEndedIterator + '_ {
+        (0..self.num_frames()).map(|i| self.frame_info(i))
+    }
+
+    /// Returns the first and the last `n` frames.
+    ///
+    /// This is useful to inspect huge seek tables quickly. Both lists are ordered by frame index
+    /// and contain fewer than `n` frames if the seek table is smaller. The lists overlap if the
+    /// seek table has fewer than `2 * n` frames.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zeekstd::SeekTable;
+    ///
+    /// let mut seek_table = SeekTable::new();
+    /// for _ in 0..10 {
+    ///     seek_table.log_frame(100, 200)?;
+    /// }
+    ///
+    /// let (head, tail) = seek_table.head_tail(2);
+    /// assert_eq!(vec![0, 1], head.iter().map(|f| f.index()).collect::<Vec<_>>());
+    /// assert_eq!(vec![8, 9], tail.iter().map(|f| f.index()).collect::<Vec<_>>());
+    /// assert_eq!(800, tail[0].comp_offset());
+    /// assert_eq!(1600, tail[0].decomp_offset());
     /// # Ok::<(), zeekstd::Error>(())
     /// ```
-    #[allow(clippy::missing_panics_doc)]
-    pub fn max_frame_size_decomp(&self) -> u64 {
-        (0..self.num_frames())
-            .map(|i| {
-                self.frame_size_decomp(i)
-                    .expect("Frame index is never out of range")
-            })
-            .max()
-            .unwrap_or(0)
+    pub fn head_tail(&self, n: usize) -> (Vec<FrameInfo>, Vec<FrameInfo>) {
+        let num_frames = self.num_frames();
+        let n = u32::try_from(n).unwrap_or(u32::MAX).min(num_frames);
+
+        let head = (0..n).map(|i| self.frame_info(i)).collect();
+        let tail = (num_frames - n..num_frames)
+            .map(|i| self.frame_info(i))
+            .collect();
+
+        (head, tail)
+    }
+
+    /// Returns information about frame `index`, which must be in range.
+    fn frame_info(&self, index: u32) -> FrameInfo {
+        let start = &self.entries[index];
+        let end = &self.entries[index + 1];
+
+        FrameInfo {
+            index,
+            comp_offset: start.c_offset,
+            decomp_offset: start.d_offset,
+            comp_size: end.c_offset - start.c_offset,
+            decomp_size: end.d_offset - start.d_offset,
+            checksum: end.checksum,
+        }
     }
 
     /// The compressed size of the seekable file.
@@ -864,6 +2049,10 @@ impl SeekTable {
     ///
     /// [`Foot`]: Format#variant.Foot
     ///
+    /// # Errors
+    ///
+    /// Fails if the size of the seek table frame does not fit in its 32 bit frame size field.
+    ///
     /// # Examples
     ///
     /// ```
@@ -872,7 +2061,7 @@ impl SeekTable {
     /// let mut seek_table = SeekTable::new();
     /// seek_table.log_frame(123, 456)?;
     ///
-    /// let mut serializer = seek_table.into_serializer();
+    /// let mut serializer = seek_table.into_serializer()?;
     /// let len = serializer.encoded_len();
     /// let mut buf = vec![0; len];
     ///
@@ -880,7 +2069,7 @@ impl SeekTable {
     /// assert_eq!(n, len);
     /// # Ok::<(), zeekstd::Error>(())
     /// ```
-    pub fn into_serializer(self) -> Serializer {
+    pub fn into_serializer(self) -> Result<Serializer> {
         self.into_format_serializer(Format::Foot)
     }
 
@@ -888,6 +2077,10 @@ impl SeekTable {
     ///
     /// The seek table is serialized according to `format`.
     ///
+    /// # Errors
+    ///
+    /// Fails if the size of the seek table frame does not fit in its 32 bit frame size field.
+    ///
     /// # Examples
     ///
     /// ```
@@ -896,7 +2089,7 @@ impl SeekTable {
     /// let mut seek_table = SeekTable::new();
     /// seek_table.log_frame(123, 456)?;
     ///
-    /// let mut serializer = seek_table.into_format_serializer(Format::Head);
+    /// let mut serializer = seek_table.into_format_serializer(Format::Head)?;
     /// let len = serializer.encoded_len();
     /// let mut buf = vec![0; len];
     ///
@@ -904,12 +2097,94 @@ impl SeekTable {
     /// assert_eq!(n, len);
     /// # Ok::<(), zeekstd::Error>(())
     /// ```
-    pub fn into_format_serializer(self, format: Format) -> Serializer {
-        Serializer {
-            frames: self.entries.into_frames(),
+    pub fn into_format_serializer(self, format: Format) -> Result<Serializer> {
+        // Checksums are only written if every frame has one
+        let with_checksum = self.entries.with_checksum();
+        // The frame size field of the skippable frame is 32 bit wide
+        let frame_size = u32::try_from(self.encoded_len() - SKIPPABLE_HEADER_SIZE)?;
+        let frames = self.entries.into_frames();
+
+        Ok(Serializer {
+            frames,
             frame_index: 0,
             write_pos: 0,
             format,
+            frame_size,
+            with_checksum,
+            dictionary_id: self.dictionary_id,
+        })
+    }
+
+    /// Serializes this seek table with the given `format` and writes it to `writer`.
+    ///
+    /// Returns the number of bytes written. This is the counterpart of [`Self::from_reader`].
+    ///
+    /// # Errors
+    ///
+    /// Fails if the seek table cannot be serialized or writing to `writer` fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::fs::File;
+    /// use zeekstd::seek_table::{Format, SeekTable};
+    ///
+    /// let mut seek_table = SeekTable::new();
+    /// seek_table.log_frame(123, 456)?;
+    ///
+    /// let mut file = File::create("my_seek_table")?;
+    /// let n = seek_table.write_to(&mut file, Format::Head)?;
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn write_to<W: std::io::Write>(self, writer: &mut W, format: Format) -> Result<u64> {
+        let mut ser = self.into_format_serializer(format)?;
+        let n = std::io::copy(&mut ser, writer)?;
+
+        Ok(n)
+    }
+
+    /// The length of the entire skippable frame that contains this seek table when serialized.
+    ///
+    /// Includes the skippable header and frame size field. This is the same length that
+    /// [`Serializer::encoded_len`] reports after converting this seek table, without consuming it.
+    /// Both formats have the same length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zeekstd::SeekTable;
+    ///
+    /// let mut seek_table = SeekTable::new();
+    /// seek_table.log_frame(123, 456)?;
+    ///
+    /// let len = seek_table.encoded_len();
+    /// let serializer = seek_table.into_serializer()?;
+    /// assert_eq!(len, serializer.encoded_len());
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    pub fn encoded_len(&self) -> usize {
+        encoded_len(
+            self.entries.0.len() - 1,
+            self.entries.with_checksum(),
+            self.dictionary_id.is_some(),
+        )
+    }
+
+    /// The number of bytes this seek table occupies when serialized.
+    ///
+    /// Alias for [`Self::encoded_len`].
+    pub fn serialized_len(&self) -> usize {
+        self.encoded_len()
+    }
+
+    /// Returns an error if there is no frame `index`.
+    fn check_index(&self, index: u32) -> Result<()> {
+        match self.num_frames().checked_sub(1) {
+            Some(max) if index <= max => Ok(()),
+            Some(max) => Err(Error::frame_index_too_large_with(index, max)),
+            None => Err(Error::frame_index_too_large()),
         }
     }
 
@@ -945,7 +2220,7 @@ impl SeekTable {
 /// seek_table.log_frame(123, 456)?;
 /// seek_table.log_frame(333, 444)?;
 ///
-/// let mut ser = seek_table.into_serializer();
+/// let mut ser = seek_table.into_serializer()?;
 /// let mut buf = vec![0; ser.encoded_len()];
 ///
 /// let n = ser.write_into(&mut buf);
@@ -957,6 +2232,9 @@ pub struct Serializer {
     frame_index: usize,
     write_pos: usize,
     format: Format,
+    frame_size: u32,
+    with_checksum: bool,
+    dictionary_id: Option<u32>,
 }
 
 impl Serializer {
@@ -969,7 +2247,7 @@ impl Serializer {
 
         // Write skippable header
         write_le32!(buf, buf_pos, self.write_pos, SKIPPABLE_MAGIC_NUMBER, 0);
-        write_le32!(buf, buf_pos, self.write_pos, self.frame_size(), 4);
+        write_le32!(buf, buf_pos, self.write_pos, self.frame_size, 4);
 
         // Write the integrity field before the frame data in Head format
         if matches!(self.format, Format::Head) {
@@ -982,9 +2260,20 @@ impl Serializer {
             );
         }
 
+        // Write the dictionary id in front of the frames in the extended format
+        if let Some(dict_id) = self.dictionary_id {
+            let offset = match self.format {
+                Format::Head => SKIPPABLE_HEADER_SIZE + SEEK_TABLE_INTEGRITY_SIZE,
+                Format::Foot => SKIPPABLE_HEADER_SIZE,
+            };
+            write_le32!(buf, buf_pos, self.write_pos, dict_id, offset);
+        }
+
         // Write frames
         while self.frame_index < self.frames.len() {
-            let offset = SKIPPABLE_HEADER_SIZE + SIZE_PER_FRAME * self.frame_index;
+            let offset = SKIPPABLE_HEADER_SIZE
+                + self.dict_id_size()
+                + self.size_per_frame() * self.frame_index;
             match self.format {
                 Format::Head => {
                     write_frame!(buf, buf_pos, self, offset + SEEK_TABLE_INTEGRITY_SIZE);
@@ -997,7 +2286,9 @@ impl Serializer {
 
         // Write the integrity field after the frame data in Foot format
         if matches!(self.format, Format::Foot) {
-            let offset = SKIPPABLE_HEADER_SIZE + SIZE_PER_FRAME * self.frames.len();
+            let offset = SKIPPABLE_HEADER_SIZE
+                + self.dict_id_size()
+                + self.size_per_frame() * self.frames.len();
             write_integrity!(buf, buf_pos, self, self.frames.len() as u32, offset);
         }
 
@@ -1016,7 +2307,7 @@ impl Serializer {
     /// # let mut seek_table = SeekTable::new();
     /// # seek_table.log_frame(123, 456)?;
     /// # seek_table.log_frame(333, 444)?;
-    /// let mut ser = seek_table.into_serializer();
+    /// let mut ser = seek_table.into_serializer()?;
     /// let mut first = vec![0; ser.encoded_len()];
     /// let mut second = vec![0; ser.encoded_len()];
     ///
@@ -1036,20 +2327,89 @@ impl Serializer {
         self.frame_index = 0;
     }
 
+    /// Sets the [`Format`] of this serializer and resets the serialization progress.
+    ///
+    /// This allows to serialize the same seek table in both formats, without the need to convert
+    /// it into another serializer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zeekstd::seek_table::{Format, SeekTable};
+    ///
+    /// # let mut seek_table = SeekTable::new();
+    /// # seek_table.log_frame(123, 456)?;
+    /// let mut ser = seek_table.into_format_serializer(Format::Head)?;
+    /// let mut head = vec![0; ser.encoded_len()];
+    /// let mut foot = vec![0; ser.encoded_len()];
+    ///
+    /// let n = ser.write_into(&mut head);
+    /// assert_eq!(n, ser.encoded_len());
+    ///
+    /// ser.set_format(Format::Foot);
+    ///
+    /// let n = ser.write_into(&mut foot);
+    /// assert_eq!(n, ser.encoded_len());
+    ///
+    /// assert_ne!(head, foot);
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    pub fn set_format(&mut self, format: Format) {
+        self.format = format;
+        self.reset();
+    }
+
     /// The length of the entire skippable frame that contains the seek table.
     ///
     /// Includes the skippable header and frame size field.
     pub fn encoded_len(&self) -> usize {
-        SKIPPABLE_HEADER_SIZE + SEEK_TABLE_INTEGRITY_SIZE + self.frames.len() * SIZE_PER_FRAME
+        encoded_len(
+            self.frames.len(),
+            self.with_checksum,
+            self.dictionary_id.is_some(),
+        )
+    }
+
+    fn dict_id_size(&self) -> usize {
+        if self.dictionary_id.is_some() {
+            DICT_ID_SIZE
+        } else {
+            0
+        }
     }
 
-    // The length of the seek table frame, not including the SKIPPABLE_MAGIC_NUMBER and
-    // the size of the skippable frame. Should always fit in u32.
-    fn frame_size(&self) -> u32 {
-        (self.encoded_len() - SKIPPABLE_HEADER_SIZE) as u32
+    fn size_per_frame(&self) -> usize {
+        if self.with_checksum {
+            SIZE_PER_FRAME_CHECKSUM
+        } else {
+            SIZE_PER_FRAME
+        }
     }
 }
 
+/// Returns the size of a seek table frame, given its integrity field.
+pub(crate) fn encoded_len_from_integrity(integrity: &[u8]) -> usize {
+    let num_frames =
+        usize::try_from(read_le32!(integrity, 0)).expect("Number of frames never exceeds usize");
+    encoded_len(
+        num_frames,
+        integrity[4] & CHECKSUM_FLAG > 0,
+        integrity[4] & DICT_ID_FLAG > 0,
+    )
+}
+
+/// The length of the entire skippable seek table frame with `num_frames` frames.
+fn encoded_len(num_frames: usize, with_checksum: bool, with_dictionary_id: bool) -> usize {
+    let size_per_frame = if with_checksum {
+        SIZE_PER_FRAME_CHECKSUM
+    } else {
+        SIZE_PER_FRAME
+    };
+    let dict_id_size = if with_dictionary_id { DICT_ID_SIZE } else { 0 };
+
+    SKIPPABLE_HEADER_SIZE + SEEK_TABLE_INTEGRITY_SIZE + dict_id_size + num_frames * size_per_frame
+}
+
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 impl std::io::Read for Serializer {
@@ -1060,7 +2420,10 @@ impl std::io::Read for Serializer {
 
 #[cfg(test)]
 mod tests {
-    use crate::BytesWrapper;
+    use crate::{
+        BytesWrapper,
+        tests::{INPUT, ShortReads},
+    };
 
     use super::*;
 
@@ -1070,17 +2433,517 @@ mod tests {
     fn seek_table(num_frames: u32) -> SeekTable {
         let mut st = SeekTable::new();
 
-        let mut c_size = 3;
-        let mut d_size = 6;
-        for _ in 0..num_frames {
-            st.log_frame(c_size, d_size).unwrap();
-            c_size += 1;
-            d_size += 1;
+        for i in 0..num_frames {
+            st.log_frame(i + 3, i + 6).unwrap();
         }
 
         st
     }
 
+    /// Compresses `INPUT` and returns the compressed frames and the seek table.
+    fn compressed_frames(frame_size: u32) -> (Vec<u8>, SeekTable) {
+        let mut encoder = crate::EncodeOptions::new()
+            .frame_size_policy(crate::FrameSizePolicy::Uncompressed(frame_size))
+            .into_raw_encoder()
+            .unwrap();
+        let mut buf = vec![0; INPUT.len()];
+        let mut frames = vec![];
+
+        let mut in_progress = 0;
+        while in_progress < INPUT.len() {
+            let prog = encoder
+                .compress(&INPUT.as_bytes()[in_progress..], &mut buf)
+                .unwrap();
+            frames.extend(&buf[..prog.out_progress()]);
+            in_progress += prog.in_progress();
+        }
+        loop {
+            let prog = encoder.end_frame(&mut buf).unwrap();
+            frames.extend(&buf[..prog.out_progress()]);
+            if prog.data_left() == 0 {
+                break;
+            }
+        }
+
+        (frames, encoder.into_seek_table())
+    }
+
+    #[test]
+    fn byte_range_to_frames() {
+        assert!(SeekTable::new().byte_range_to_frames(0, 0).is_err());
+
+        let mut st = SeekTable::new();
+        // Frames: [0, 100), [100, 150), [150, 400)
+        for d_size in [100, 50, 250] {
+            st.log_frame(10, d_size).unwrap();
+        }
+
+        assert_eq!(st.byte_range_to_frames(0, 400).unwrap(), (0, 2));
+        assert_eq!(st.byte_range_to_frames(0, 100).unwrap(), (0, 0));
+        assert_eq!(st.byte_range_to_frames(0, 101).unwrap(), (0, 1));
+        assert_eq!(st.byte_range_to_frames(99, 100).unwrap(), (0, 0));
+        assert_eq!(st.byte_range_to_frames(100, 150).unwrap(), (1, 1));
+        assert_eq!(st.byte_range_to_frames(149, 151).unwrap(), (1, 2));
+        assert_eq!(st.byte_range_to_frames(150, 400).unwrap(), (2, 2));
+        assert_eq!(st.byte_range_to_frames(399, 400).unwrap(), (2, 2));
+
+        // Empty ranges
+        assert_eq!(st.byte_range_to_frames(0, 0).unwrap(), (0, 0));
+        assert_eq!(st.byte_range_to_frames(100, 100).unwrap(), (1, 1));
+        assert_eq!(st.byte_range_to_frames(400, 400).unwrap(), (2, 2));
+
+        // Out of range
+        assert!(st.byte_range_to_frames(0, 401).is_err());
+        assert!(st.byte_range_to_frames(401, 401).is_err());
+        assert!(st.byte_range_to_frames(200, 100).is_err());
+    }
+
+    #[test]
+    fn try_frame_index_decomp() {
+        use alloc::string::ToString;
+
+        let st = SeekTable::new();
+        assert!(
+            st.try_frame_index_decomp(0)
+                .unwrap_err()
+                .is_offset_out_of_range()
+        );
+
+        let mut st = SeekTable::new();
+        // Frames: [0, 100), [100, 150), [150, 400)
+        for d_size in [100, 50, 250] {
+            st.log_frame(10, d_size).unwrap();
+        }
+
+        for (offset, index) in [
+            (0, 0),
+            (99, 0),
+            (100, 1),
+            (149, 1),
+            (150, 2),
+            (399, 2),
+            (400, 2),
+        ] {
+            assert_eq!(st.try_frame_index_decomp(offset).unwrap(), index);
+            assert_eq!(st.frame_index_decomp(offset), index);
+        }
+
+        for offset in [401, 1000, u64::MAX] {
+            assert!(
+                st.try_frame_index_decomp(offset)
+                    .unwrap_err()
+                    .is_offset_out_of_range()
+            );
+            // The clamping version maps to the last frame
+            assert_eq!(st.frame_index_decomp(offset), 2);
+        }
+
+        assert_eq!(
+            st.try_frame_index_decomp(401).unwrap_err().to_string(),
+            "offset 401 out of range, max is 400"
+        );
+        assert_eq!(
+            st.frame_start_comp(3).unwrap_err().to_string(),
+            "frame index 3 too large, max is 2"
+        );
+        // Inverted ranges have no maximum to report
+        assert_eq!(
+            st.subtable(2, 1).unwrap_err().to_string(),
+            "frame index too large"
+        );
+        assert_eq!(
+            st.byte_range_to_frames(300, 200).unwrap_err().to_string(),
+            "offset out of range"
+        );
+    }
+
+    #[test]
+    fn frames() {
+        assert_eq!(SeekTable::new().frames().len(), 0);
+
+        let (_, st) = compressed_frames(1024);
+        let frames = st.frames().collect::<Vec<_>>();
+        assert_eq!(frames.len(), st.num_frames() as usize);
+        for (i, frame) in (0..).zip(&frames) {
+            assert_eq!(frame.index(), i);
+            assert_eq!(frame.comp_offset(), st.frame_start_comp(i).unwrap());
+            assert_eq!(frame.decomp_offset(), st.frame_start_decomp(i).unwrap());
+            assert_eq!(frame.comp_size(), st.frame_size_comp(i).unwrap());
+            assert_eq!(frame.decomp_size(), st.frame_size_decomp(i).unwrap());
+            assert_eq!(frame.checksum(), st.frame_checksum(i).unwrap());
+        }
+        assert_eq!(st.frames().next_back(), frames.last().copied());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn write_to() {
+        let (_, st) = compressed_frames(1024);
+        for st in [st, seek_table(3000), SeekTable::new()] {
+            for format in [Format::Head, Format::Foot] {
+                let mut ser = st.clone().into_format_serializer(format).unwrap();
+                let mut expected = vec![0; ser.encoded_len()];
+                ser.write_into(&mut expected);
+
+                let mut buf = vec![];
+                let n = st.clone().write_to(&mut buf, format).unwrap();
+                assert_eq!(n, buf.len() as u64);
+                assert_eq!(expected, buf);
+            }
+        }
+    }
+
+    #[test]
+    fn head_tail() {
+        let (head, tail) = SeekTable::new().head_tail(3);
+        assert!(head.is_empty());
+        assert!(tail.is_empty());
+
+        let st = seek_table(10);
+        let (head, tail) = st.head_tail(3);
+        let indices =
+            |frames: &[FrameInfo]| frames.iter().map(FrameInfo::index).collect::<Vec<_>>();
+        assert_eq!(indices(&head), [0, 1, 2]);
+        assert_eq!(indices(&tail), [7, 8, 9]);
+        for frame in head.iter().chain(&tail) {
+            let i = frame.index();
+            assert_eq!(frame.comp_offset(), st.frame_start_comp(i).unwrap());
+            assert_eq!(frame.decomp_offset(), st.frame_start_decomp(i).unwrap());
+            assert_eq!(frame.comp_size(), st.frame_size_comp(i).unwrap());
+            assert_eq!(frame.decomp_size(), st.frame_size_decomp(i).unwrap());
+            assert_eq!(frame.checksum(), None);
+        }
+
+        // Overlapping and exceeding the number of frames
+        let (head, tail) = st.head_tail(usize::MAX);
+        assert_eq!(indices(&head), (0..10).collect::<Vec<_>>());
+        assert_eq!(head, tail);
+    }
+
+    #[test]
+    fn merge() {
+        let mut all = SeekTable::new();
+        let mut first = SeekTable::new();
+        let mut second = SeekTable::new();
+        for i in 0..20 {
+            all.log_frame_with_checksum(i + 3, i + 6, Some(i)).unwrap();
+            let st = if i < 8 { &mut first } else { &mut second };
+            st.log_frame_with_checksum(i + 3, i + 6, Some(i)).unwrap();
+        }
+
+        let mut merged = SeekTable::new();
+        merged.merge(&first).unwrap();
+        merged.merge(&SeekTable::new()).unwrap();
+        merged.merge(&second).unwrap();
+        assert_eq!(merged, all);
+    }
+
+    #[test]
+    fn merge_dictionary_id() {
+        let mut with_id = seek_table(3);
+        with_id.set_dictionary_id(Some(7));
+
+        let mut merged = seek_table(2);
+        merged.merge(&with_id).unwrap();
+        assert_eq!(merged.dictionary_id(), Some(7));
+        merged.merge(&seek_table(1)).unwrap();
+        merged.merge(&with_id).unwrap();
+        assert_eq!(merged.dictionary_id(), Some(7));
+        assert_eq!(merged.num_frames(), 9);
+
+        // A different dictionary id is rejected and leaves the seek table unchanged
+        let mut other = seek_table(3);
+        other.set_dictionary_id(Some(8));
+        let before = merged.clone();
+        assert!(merged.merge(&other).is_err_and(|e| e.is_zstd()));
+        assert_eq!(merged, before);
+    }
+
+    #[test]
+    fn from_bytes() {
+        for format in [Format::Head, Format::Foot] {
+            for num_frames in [0, 1, 1000] {
+                let st = seek_table(num_frames);
+                let mut ser = st.clone().into_format_serializer(format).unwrap();
+                let mut buf = vec![0; ser.encoded_len()];
+                ser.write_into(&mut buf);
+
+                assert_eq!(SeekTable::from_bytes(&buf, format).unwrap(), st);
+                assert!(SeekTable::from_bytes(&buf[1..], format).is_err());
+            }
+        }
+        assert!(SeekTable::from_bytes(&[], Format::Foot).is_err());
+    }
+
+    #[test]
+    fn dictionary_id() {
+        for format in [Format::Head, Format::Foot] {
+            for num_frames in [0, 1, 1000] {
+                let plain = seek_table(num_frames);
+                let mut st = plain.clone();
+                st.set_dictionary_id(Some(0xdead_beef));
+
+                let mut ser = st.clone().into_format_serializer(format).unwrap();
+                assert_eq!(ser.encoded_len(), st.encoded_len());
+                assert_eq!(ser.encoded_len(), plain.encoded_len() + DICT_ID_SIZE);
+                // Serialize byte by byte
+                let mut buf = vec![0; ser.encoded_len()];
+                for pos in 0..buf.len() {
+                    assert_eq!(ser.write_into(&mut buf[pos..=pos]), 1);
+                }
+                assert_eq!(ser.write_into(&mut [0; 8]), 0);
+
+                let mut src = ShortReads(BytesWrapper::new(&buf));
+                let parsed = SeekTable::from_seekable_format(&mut src, format).unwrap();
+                assert_eq!(parsed.dictionary_id(), Some(0xdead_beef));
+                assert_eq!(parsed, st);
+                let info = SeekTable::read_integrity(&mut BytesWrapper::new(&buf), format).unwrap();
+                assert_eq!(info.seek_table_size(), buf.len());
+                #[cfg(feature = "std")]
+                if matches!(format, Format::Head) {
+                    assert_eq!(SeekTable::from_reader(&buf[..]).unwrap(), st);
+                }
+
+                // Plain seek tables don't have a dictionary id
+                let mut ser = plain.into_format_serializer(format).unwrap();
+                let mut buf = vec![0; ser.encoded_len()];
+                ser.write_into(&mut buf);
+                let parsed = SeekTable::from_bytes(&buf, format).unwrap();
+                assert_eq!(parsed.dictionary_id(), None);
+            }
+        }
+    }
+
+    #[test]
+    fn from_seekable_auto() {
+        for num_frames in [0, 1, 100, 1000] {
+            let st = seek_table(num_frames);
+            for format in [Format::Head, Format::Foot] {
+                let mut ser = st.clone().into_format_serializer(format).unwrap();
+                let mut buf = vec![0; ser.encoded_len()];
+                ser.write_into(&mut buf);
+
+                let parsed = SeekTable::from_seekable_auto(&mut BytesWrapper::new(&buf)).unwrap();
+                assert_eq!(parsed, st);
+                let parsed =
+                    SeekTable::from_seekable_auto(&mut ShortReads(BytesWrapper::new(&buf)))
+                        .unwrap();
+                assert_eq!(parsed, st);
+            }
+        }
+
+        // A complete archive has the seek table at the end
+        let (mut archive, st) = compressed_frames(1024);
+        let mut ser = st.clone().into_serializer().unwrap();
+        let n = archive.len();
+        archive.resize(n + ser.encoded_len(), 0);
+        ser.write_into(&mut archive[n..]);
+        let parsed = SeekTable::from_seekable_auto(&mut BytesWrapper::new(&archive)).unwrap();
+        assert_eq!(parsed, st);
+
+        assert!(SeekTable::from_seekable_auto(&mut BytesWrapper::new(&archive[..n])).is_err());
+    }
+
+    #[test]
+    fn read_integrity() {
+        for format in [Format::Head, Format::Foot] {
+            for (num_frames, checksum) in [(0, None), (1, Some(7)), (1000, None), (1000, Some(7))] {
+                let mut st = SeekTable::new();
+                for _ in 0..num_frames {
+                    st.log_frame_with_checksum(123, 456, checksum).unwrap();
+                }
+                let mut ser = st.clone().into_format_serializer(format).unwrap();
+                let mut buf = vec![0; ser.encoded_len()];
+                ser.write_into(&mut buf);
+
+                let info = SeekTable::read_integrity(&mut BytesWrapper::new(&buf), format).unwrap();
+                let parsed = SeekTable::from_bytes(&buf, format).unwrap();
+                assert_eq!(info.num_frames(), parsed.num_frames());
+                assert_eq!(info.with_checksum(), parsed.stats().has_checksums());
+                assert_eq!(info.seek_table_size(), buf.len());
+            }
+        }
+
+        // Only the integrity field is read
+        let mut buf = vec![0; 9];
+        buf[..4].copy_from_slice(&5u32.to_le_bytes());
+        buf[5..].copy_from_slice(&SEEKABLE_MAGIC_NUMBER.to_le_bytes());
+        let info = SeekTable::read_integrity(&mut BytesWrapper::new(&buf), Format::Foot).unwrap();
+        assert_eq!(info.num_frames(), 5);
+        assert!(
+            SeekTable::read_integrity(&mut BytesWrapper::new(&buf[1..]), Format::Foot).is_err()
+        );
+    }
+
+    #[test]
+    fn subtable() {
+        let (frames, st) = compressed_frames(1024);
+        let sub = st.subtable(3, 6).unwrap();
+        assert_eq!(sub.num_frames(), 4);
+        assert_eq!(sub.size_decomp(), 4 * 1024);
+        assert_eq!(
+            sub.frame_checksum(0).unwrap(),
+            st.frame_checksum(3).unwrap()
+        );
+
+        let range = st.frame_start_comp(3).unwrap()..st.frame_end_comp(6).unwrap();
+        let mut archive = frames[range.start as usize..range.end as usize].to_vec();
+        let mut ser = sub.clone().into_serializer().unwrap();
+        let n = archive.len();
+        archive.resize(n + ser.encoded_len(), 0);
+        ser.write_into(&mut archive[n..]);
+
+        let mut decoder = crate::Decoder::new(BytesWrapper::new(&archive)).unwrap();
+        assert_eq!(decoder.seek_table(), &sub);
+        let mut output = vec![0; 4 * 1024];
+        let mut pos = 0;
+        while pos < output.len() {
+            pos += decoder.decompress(&mut output[pos..]).unwrap();
+        }
+        assert_eq!(output, INPUT.as_bytes()[3 * 1024..7 * 1024]);
+        assert_eq!(decoder.decompress(&mut output).unwrap(), 0);
+
+        // The whole range is an identical copy
+        assert_eq!(st.subtable(0, st.num_frames() - 1).unwrap(), st);
+        assert!(st.subtable(4, 3).is_err());
+        assert!(st.subtable(0, st.num_frames()).is_err());
+    }
+
+    #[test]
+    fn truncate() {
+        let mut st = SeekTable::new();
+        for i in 0..20 {
+            st.log_frame_with_checksum(i + 3, i + 6, Some(i)).unwrap();
+        }
+        let full = st.clone();
+
+        st.truncate(20);
+        assert_eq!(st, full);
+        st.truncate(u32::MAX);
+        assert_eq!(st, full);
+
+        st.truncate(8);
+        assert_eq!(st.num_frames(), 8);
+        assert_eq!(st.size_comp(), (0..8).map(|i| i + 3).sum::<u64>());
+        assert_eq!(st.size_decomp(), (0..8).map(|i| i + 6).sum::<u64>());
+        assert_eq!(st.frame_checksum(7).unwrap(), Some(7));
+        assert!(st.frame_checksum(8).is_err());
+        // Logging frames continues after the kept frames
+        st.log_frame_with_checksum(100, 200, Some(1)).unwrap();
+        assert_eq!(
+            st.frame_start_comp(8).unwrap(),
+            full.frame_start_comp(8).unwrap()
+        );
+
+        st.truncate(0);
+        assert_eq!(st, SeekTable::new());
+    }
+
+    #[test]
+    fn matches() {
+        let (mut seekable, st) = compressed_frames(1024);
+        let mut ser = st.clone().into_serializer().unwrap();
+        let st_start = seekable.len();
+        seekable.resize(st_start + ser.encoded_len(), 0);
+        ser.write_into(&mut seekable[st_start..]);
+        assert!(st.matches(&mut BytesWrapper::new(&seekable)).unwrap());
+
+        let mut other = seek_table(st.num_frames());
+        assert!(!other.matches(&mut BytesWrapper::new(&seekable)).unwrap());
+        other = st.clone();
+        other.log_frame(1, 1).unwrap();
+        assert!(!other.matches(&mut BytesWrapper::new(&seekable)).unwrap());
+
+        // Tampered frame size
+        seekable[st_start + SKIPPABLE_HEADER_SIZE] ^= 0x01;
+        assert!(!st.matches(&mut BytesWrapper::new(&seekable)).unwrap());
+
+        assert!(st.matches(&mut BytesWrapper::new(&[0; 4])).is_err());
+    }
+
+    #[test]
+    fn decomp_offset_for_comp() {
+        assert_eq!(SeekTable::new().decomp_offset_for_comp(10), 0);
+
+        let st = seek_table(100);
+        for index in 0..st.num_frames() {
+            let start = st.frame_start_comp(index).unwrap();
+            let end = st.frame_end_comp(index).unwrap();
+            let expected = st.frame_start_decomp(index).unwrap();
+            assert_eq!(st.decomp_offset_for_comp(start), expected);
+            assert_eq!(st.decomp_offset_for_comp(end - 1), expected);
+        }
+
+        let last_start = st.frame_start_decomp(st.num_frames() - 1).unwrap();
+        assert_eq!(st.decomp_offset_for_comp(st.size_comp()), last_start);
+        assert_eq!(st.decomp_offset_for_comp(u64::MAX), last_start);
+    }
+
+    #[test]
+    fn rebuild_from_frames() {
+        let (mut frames, st) = compressed_frames(1024);
+        assert!(st.num_frames() > 2);
+
+        // No seek table at all
+        let rebuilt = SeekTable::rebuild_from_frames(&mut BytesWrapper::new(&frames)).unwrap();
+        assert_eq!(rebuilt, st);
+
+        // Truncated seek table
+        let mut ser = st.clone().into_serializer().unwrap();
+        let mut st_buf = vec![0; ser.encoded_len()];
+        ser.write_into(&mut st_buf);
+        let len = frames.len();
+        frames.extend(&st_buf[..st_buf.len() - 3]);
+        let mut src = ShortReads(BytesWrapper::new(&frames));
+        assert_eq!(SeekTable::rebuild_from_frames(&mut src).unwrap(), st);
+
+        // Incomplete skippable magic number
+        let rebuilt =
+            SeekTable::rebuild_from_frames(&mut BytesWrapper::new(&frames[..len + 2])).unwrap();
+        assert_eq!(rebuilt, st);
+
+        // Incomplete last frame
+        let rebuilt =
+            SeekTable::rebuild_from_frames(&mut BytesWrapper::new(&frames[..len - 1])).unwrap();
+        assert_eq!(rebuilt.num_frames(), st.num_frames() - 1);
+        assert_eq!(
+            rebuilt.size_comp(),
+            st.frame_start_comp(st.num_frames() - 1).unwrap()
+        );
+
+        // Corrupted frame
+        frames[0..4].copy_from_slice(&[0; 4]);
+        assert!(SeekTable::rebuild_from_frames(&mut BytesWrapper::new(&frames)).is_err());
+    }
+
+    #[test]
+    fn scan_frames() {
+        let (mut frames, st) = compressed_frames(1024);
+        assert!(st.num_frames() > 2);
+
+        // Strip the seek table from an archive
+        let mut archive = frames.clone();
+        let mut ser = st.clone().into_serializer().unwrap();
+        let mut st_buf = vec![0; ser.encoded_len()];
+        ser.write_into(&mut st_buf);
+        archive.extend(&st_buf);
+        let stripped = &archive[..st.size_comp() as usize];
+
+        let mut src = ShortReads(BytesWrapper::new(stripped));
+        let scanned = SeekTable::scan_frames(&mut src).unwrap();
+        assert_eq!(scanned, st);
+
+        // Scanning stops at the appended seek table
+        let scanned = SeekTable::scan_frames(&mut BytesWrapper::new(&archive)).unwrap();
+        assert_eq!(scanned, st);
+
+        // Incomplete last frame or magic number
+        let len = frames.len();
+        assert!(SeekTable::scan_frames(&mut BytesWrapper::new(&frames[..len - 1])).is_err());
+        frames.extend(&st_buf[..2]);
+        assert!(SeekTable::scan_frames(&mut BytesWrapper::new(&frames)).is_err());
+    }
+
     #[test]
     fn frame_functions() {
         const NUM_FRAMES: u32 = 1234;
@@ -1112,12 +2975,31 @@ mod tests {
 
         assert_eq!(st.max_frame_size_comp(), NUM_FRAMES as u64 * 7);
         assert_eq!(st.max_frame_size_decomp(), NUM_FRAMES as u64 * 13);
+
+        let stats = st.stats();
+        assert_eq!(stats.num_frames(), NUM_FRAMES);
+        assert_eq!(stats.min_frame_size_decomp(), 13);
+        assert_eq!(stats.max_frame_size_decomp(), NUM_FRAMES as u64 * 13);
+        assert_eq!(stats.mean_frame_size_decomp(), d_offset / NUM_FRAMES as u64);
+        assert_eq!(stats.size_comp(), c_offset);
+        assert_eq!(stats.size_decomp(), d_offset);
+        assert!(!stats.has_checksums());
+
+        let stats = SeekTable::new().stats();
+        assert_eq!(stats.num_frames(), 0);
+        assert_eq!(stats.min_frame_size_decomp(), 0);
+        assert_eq!(stats.mean_frame_size_decomp(), 0);
+        assert_eq!(
+            st.range_sizes(0, NUM_FRAMES - 1).unwrap(),
+            (st.size_comp(), st.size_decomp())
+        );
     }
 
     fn test_serialize(format: Format, num_frames: u32, buf_len: usize) {
         let mut ser = seek_table(num_frames)
             .clone()
-            .into_format_serializer(format);
+            .into_format_serializer(format)
+            .unwrap();
 
         // Complete serialization
         let mut buf = vec![0; ser.encoded_len()];
@@ -1142,7 +3024,8 @@ mod tests {
 
     fn test_serde_cycle(format: Format, num_frames: u32) {
         let st = seek_table(num_frames);
-        let mut ser = st.clone().into_format_serializer(format);
+        let mut ser = st.clone().into_format_serializer(format).unwrap();
+        assert_eq!(st.encoded_len(), ser.encoded_len());
 
         let mut buf = vec![0; ser.encoded_len()];
         let n = ser.write_into(&mut buf);
@@ -1153,9 +3036,38 @@ mod tests {
         assert_eq!(from_seekable, st);
     }
 
+    fn test_serde_cycle_checksums(format: Format, num_frames: u32) {
+        let mut st = SeekTable::new();
+        for i in 0..num_frames {
+            st.log_frame_with_checksum(i + 3, i + 6, Some(i.wrapping_mul(0x9e37_79b9)))
+                .unwrap();
+        }
+        let mut ser = st.clone().into_format_serializer(format).unwrap();
+        assert_eq!(st.encoded_len(), ser.encoded_len());
+        assert_eq!(st.serialized_len(), ser.encoded_len());
+        assert_eq!(
+            ser.encoded_len(),
+            encoded_len(num_frames as usize, num_frames > 0, false)
+        );
+
+        let mut buf = vec![0; ser.encoded_len()];
+        ser.write_into(&mut buf);
+
+        let mut wrapper = BytesWrapper::new(&buf);
+        let from_seekable = SeekTable::from_seekable_format(&mut wrapper, format).unwrap();
+        assert_eq!(from_seekable, st);
+        assert_eq!(from_seekable.stats().has_checksums(), num_frames > 0);
+        for i in 0..num_frames {
+            assert_eq!(
+                from_seekable.frame_checksum(i).unwrap(),
+                Some(i.wrapping_mul(0x9e37_79b9))
+            );
+        }
+    }
+
     fn test_serialize_compatible_with_zstd_seekable(num_frames: u32) {
         let st = seek_table(num_frames);
-        let mut ser = st.clone().into_serializer();
+        let mut ser = st.clone().into_serializer().unwrap();
         let mut buf = vec![0; ser.encoded_len()];
         let n = ser.write_into(&mut buf);
         assert_eq!(n, ser.encoded_len());
@@ -1208,13 +3120,53 @@ mod tests {
             let d_size = i as u64 * 13;
             assert_eq!(st.frame_size_comp(i - 1).unwrap(), c_size);
             assert_eq!(st.frame_size_decomp(i - 1).unwrap(), d_size);
+            assert_eq!(st.frame_checksum(i - 1).unwrap(), Some(i));
+        }
+    }
+
+    fn test_serde_cycle_short_reads(format: Format, num_frames: u32) {
+        let st = seek_table(num_frames);
+        let mut ser = st.clone().into_format_serializer(format).unwrap();
+
+        let mut buf = vec![0; ser.encoded_len()];
+        ser.write_into(&mut buf);
+
+        let mut src = ShortReads(BytesWrapper::new(&buf));
+        let from_seekable = SeekTable::from_seekable_format(&mut src, format).unwrap();
+        assert_eq!(from_seekable, st);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_reader_foot() {
+        for frame_size in [64, 1024, 1 << 20] {
+            let (mut archive, st) = compressed_frames(frame_size);
+            let mut ser = st.into_serializer().unwrap();
+            let n = archive.len();
+            archive.resize(n + ser.encoded_len(), 0);
+            ser.write_into(&mut archive[n..]);
+
+            let expected = SeekTable::from_seekable(&mut BytesWrapper::new(&archive)).unwrap();
+            let len = archive.len() as u64;
+            let parsed = SeekTable::from_reader_foot(std::io::Cursor::new(&archive), len).unwrap();
+            assert_eq!(parsed, expected);
+
+            // Small reads must not hide a header that spans multiple reads
+            let reader = ShortReads(BytesWrapper::new(&archive));
+            let parsed = SeekTable::from_reader_foot(reader, len).unwrap();
+            assert_eq!(parsed, expected);
+
+            // Wrong total length
+            assert!(SeekTable::from_reader_foot(std::io::Cursor::new(&archive), len + 1).is_err());
+            let truncated = &archive[..archive.len() - 1];
+            assert!(SeekTable::from_reader_foot(truncated, len - 1).is_err());
         }
     }
 
     #[cfg(feature = "std")]
     fn test_serde_cycle_std(format: Format, num_frames: u32) {
         let st = seek_table(num_frames);
-        let mut ser = st.clone().into_format_serializer(format);
+        let mut ser = st.clone().into_format_serializer(format).unwrap();
         let mut buf = std::io::Cursor::new(Vec::with_capacity(ser.encoded_len()));
         let n = std::io::copy(&mut ser, &mut buf).unwrap();
         assert_eq!(n, ser.encoded_len() as u64);
@@ -1229,7 +3181,7 @@ mod tests {
     #[cfg(feature = "std")]
     fn test_serde_cycle_buf(format: Format, num_frames: u32) {
         let st = seek_table(num_frames);
-        let mut ser = st.clone().into_format_serializer(format);
+        let mut ser = st.clone().into_format_serializer(format).unwrap();
         let mut buf = std::io::Cursor::new(Vec::with_capacity(ser.encoded_len()));
         let n = std::io::copy(&mut ser, &mut buf).unwrap();
         assert_eq!(n, ser.encoded_len() as u64);
@@ -1263,6 +3215,10 @@ mod tests {
         fn serde_cycle(num_frames in 0..4096u32) {
             test_serde_cycle(Format::Head, num_frames);
             test_serde_cycle(Format::Foot, num_frames);
+            test_serde_cycle_short_reads(Format::Head, num_frames);
+            test_serde_cycle_short_reads(Format::Foot, num_frames);
+            test_serde_cycle_checksums(Format::Head, num_frames);
+            test_serde_cycle_checksums(Format::Foot, num_frames);
         }
 
         #[test]
@@ -1275,4 +3231,103 @@ mod tests {
             test_deserialize_compatible_with_zstd_seekable(num_frames);
         }
     }
+
+    #[cfg(feature = "serde")]
+    mod serde_tests {
+        use serde_test::{Token, assert_de_tokens_error, assert_tokens};
+
+        use super::*;
+
+        #[test]
+        fn frames() {
+            assert_tokens(
+                &seek_table(2),
+                &[
+                    Token::Struct {
+                        name: "SeekTableRepr",
+                        len: 1,
+                    },
+                    Token::Str("frames"),
+                    Token::Seq { len: Some(2) },
+                    Token::Struct {
+                        name: "FrameRepr",
+                        len: 2,
+                    },
+                    Token::Str("comp_size"),
+                    Token::U32(3),
+                    Token::Str("decomp_size"),
+                    Token::U32(6),
+                    Token::StructEnd,
+                    Token::Struct {
+                        name: "FrameRepr",
+                        len: 2,
+                    },
+                    Token::Str("comp_size"),
+                    Token::U32(4),
+                    Token::Str("decomp_size"),
+                    Token::U32(7),
+                    Token::StructEnd,
+                    Token::SeqEnd,
+                    Token::StructEnd,
+                ],
+            );
+        }
+
+        #[test]
+        fn checksum_and_dictionary_id() {
+            let mut st = SeekTable::new();
+            st.log_frame_with_checksum(3, 6, Some(9)).unwrap();
+            st.set_dictionary_id(Some(42));
+
+            assert_tokens(
+                &st,
+                &[
+                    Token::Struct {
+                        name: "SeekTableRepr",
+                        len: 2,
+                    },
+                    Token::Str("frames"),
+                    Token::Seq { len: Some(1) },
+                    Token::Struct {
+                        name: "FrameRepr",
+                        len: 3,
+                    },
+                    Token::Str("comp_size"),
+                    Token::U32(3),
+                    Token::Str("decomp_size"),
+                    Token::U32(6),
+                    Token::Str("checksum"),
+                    Token::Some,
+                    Token::U32(9),
+                    Token::StructEnd,
+                    Token::SeqEnd,
+                    Token::Str("dictionary_id"),
+                    Token::Some,
+                    Token::U32(42),
+                    Token::StructEnd,
+                ],
+            );
+        }
+
+        #[test]
+        fn frame_size_too_large() {
+            assert_de_tokens_error::<SeekTable>(
+                &[
+                    Token::Struct {
+                        name: "SeekTableRepr",
+                        len: 1,
+                    },
+                    Token::Str("frames"),
+                    Token::Seq { len: Some(1) },
+                    Token::Struct {
+                        name: "FrameRepr",
+                        len: 2,
+                    },
+                    Token::Str("comp_size"),
+                    Token::U64(1 << 32),
+                ],
+                "invalid value: integer `4294967296`, expected u32",
+            );
+        }
+    }
 }
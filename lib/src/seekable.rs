@@ -1,5 +1,9 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
+
 use crate::{
-    SEEK_TABLE_INTEGRITY_SIZE, SKIPPABLE_HEADER_SIZE,
+    SEEK_TABLE_INTEGRITY_SIZE, SEEKABLE_MAGIC_NUMBER, SKIPPABLE_HEADER_SIZE,
     error::{Error, Result},
     seek_table::Format,
 };
@@ -36,6 +40,309 @@ pub trait Seekable {
     ///
     /// Fails if the integrity field cannot be retrieved.
     fn seek_table_integrity(&mut self, format: Format) -> Result<[u8; SEEK_TABLE_INTEGRITY_SIZE]>;
+
+    /// Returns the total size of this seekable in bytes.
+    ///
+    /// The default implementation sets the offset to the end of the seekable, implementors that
+    /// know their size should override it to avoid moving the read offset.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the size cannot be determined.
+    fn size(&mut self) -> Result<u64> {
+        self.set_offset(OffsetFrom::End(0))
+    }
+}
+
+/// Checks whether `src` looks like a seekable archive.
+///
+/// Only reads the seek table integrity field at the end of `src` and checks its magic number and
+/// reserved bits, the seek table is not parsed. This is a cheap check, e.g. for file type
+/// detection, a positive result does not guarantee that `src` is a valid archive. Any error is
+/// treated as `false`.
+///
+/// # Examples
+///
+/// ```
+/// use zeekstd::{BytesWrapper, SeekTable, is_seekable_archive};
+///
+/// let mut ser = SeekTable::new().into_serializer()?;
+/// let mut buf = [0u8; 32];
+/// let n = ser.write_into(&mut buf);
+///
+/// assert!(is_seekable_archive(&mut BytesWrapper::new(&buf[..n])));
+/// assert!(!is_seekable_archive(&mut BytesWrapper::new(b"Hello, World!")));
+/// # Ok::<(), zeekstd::Error>(())
+/// ```
+pub fn is_seekable_archive(src: &mut impl Seekable) -> bool {
+    src.seek_table_integrity(Format::Foot)
+        .is_ok_and(|integrity| {
+            let magic =
+                u32::from_le_bytes([integrity[5], integrity[6], integrity[7], integrity[8]]);
+            // Reserved descriptor bits must not be set
+            magic == SEEKABLE_MAGIC_NUMBER && integrity[4] & 0x3c == 0
+        })
+}
+
+/// Reads the entire content of `src` into a vector.
+pub(crate) fn read_all(src: &mut (impl Seekable + ?Sized)) -> Result<Vec<u8>> {
+    let len = usize::try_from(src.size()?)?;
+    src.set_offset(OffsetFrom::Start(0))?;
+
+    let mut buf = vec![0; len];
+    let mut read = 0;
+    while read < len {
+        let n = src.read(&mut buf[read..])?;
+        if n == 0 {
+            return Err(Error::offset_out_of_range());
+        }
+        read += n;
+    }
+
+    Ok(buf)
+}
+
+/// Hides a number of trailing bytes at the end of a [`Seekable`].
+pub(crate) struct WithoutTrailing<'s, S> {
+    src: &'s mut S,
+    trailing: i64,
+}
+
+impl<'s, S> WithoutTrailing<'s, S> {
+    pub(crate) fn new(src: &'s mut S, trailing: u64) -> Result<Self> {
+        Ok(Self {
+            src,
+            trailing: i64::try_from(trailing)?,
+        })
+    }
+}
+
+impl<S: Seekable> Seekable for WithoutTrailing<'_, S> {
+    fn set_offset(&mut self, offset: OffsetFrom) -> Result<u64> {
+        let offset = match offset {
+            OffsetFrom::End(delta) => OffsetFrom::End(
+                delta
+                    .checked_sub(self.trailing)
+                    .ok_or(Error::offset_out_of_range())?,
+            ),
+            start @ OffsetFrom::Start(_) => start,
+        };
+
+        self.src.set_offset(offset)
+    }
+
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        self.src.read(buf)
+    }
+
+    fn size(&mut self) -> Result<u64> {
+        self.src
+            .size()?
+            .checked_sub(self.trailing.cast_unsigned())
+            .ok_or(Error::offset_out_of_range())
+    }
+
+    fn seek_table_integrity(&mut self, format: Format) -> Result<[u8; SEEK_TABLE_INTEGRITY_SIZE]> {
+        if matches!(format, Format::Head) {
+            return self.src.seek_table_integrity(format);
+        }
+
+        self.set_offset(OffsetFrom::End(-(SEEK_TABLE_INTEGRITY_SIZE as i64)))?;
+        let mut buf = [0u8; SEEK_TABLE_INTEGRITY_SIZE];
+        let mut read = 0;
+        while read < buf.len() {
+            let n = self.src.read(&mut buf[read..])?;
+            if n == 0 {
+                return Err(Error::offset_out_of_range());
+            }
+            read += n;
+        }
+
+        Ok(buf)
+    }
+}
+
+/// A [`Seekable`] that is restricted to a single archive within a larger source.
+///
+/// Created by [`archives`] for every archive of a source that holds multiple concatenated
+/// archives. Offsets are relative to the start of the archive and reads end at the end of the
+/// archive.
+///
+/// [`archives`]: crate::archives
+#[derive(Debug, Clone)]
+pub struct ArchiveMember<S> {
+    slice: SeekableSlice<S>,
+}
+
+impl<S> ArchiveMember<S> {
+    pub(crate) fn new(src: S, start: u64, len: u64) -> Self {
+        Self {
+            slice: SeekableSlice::new(src, start, len),
+        }
+    }
+
+    /// The offset of the archive in the underlying source.
+    pub fn start(&self) -> u64 {
+        self.slice.start()
+    }
+
+    /// The length of the archive in bytes.
+    pub fn len(&self) -> u64 {
+        self.slice.len()
+    }
+
+    /// Whether the archive is empty.
+    pub fn is_empty(&self) -> bool {
+        self.slice.is_empty()
+    }
+
+    /// Consumes the member, returning the underlying source.
+    pub fn into_inner(self) -> S {
+        self.slice.into_inner()
+    }
+}
+
+impl<S: Seekable> Seekable for ArchiveMember<S> {
+    fn set_offset(&mut self, offset: OffsetFrom) -> Result<u64> {
+        self.slice.set_offset(offset)
+    }
+
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        self.slice.read(buf)
+    }
+
+    fn size(&mut self) -> Result<u64> {
+        self.slice.size()
+    }
+
+    fn seek_table_integrity(&mut self, format: Format) -> Result<[u8; SEEK_TABLE_INTEGRITY_SIZE]> {
+        self.slice.seek_table_integrity(format)
+    }
+}
+
+/// A [`Seekable`] view of a byte window of another source.
+///
+/// All offsets are relative to the start of the window and reads end at the end of the window,
+/// so an archive that is embedded in a larger container can be decompressed without copying it
+/// out. Works with any [`Seekable`], including files and other readers that implement
+/// [`std::io::Read`] and [`std::io::Seek`]. See [`BytesWrapper::sub`] for byte slices.
+///
+/// # Examples
+///
+/// ```no_run
+/// # #[cfg(feature = "std")] {
+/// use std::fs::File;
+/// use zeekstd::{Decoder, SeekableSlice};
+///
+/// // The archive is stored at bytes 512..4096 of the container
+/// let container = File::open("container.bin")?;
+/// let mut decoder = Decoder::new(SeekableSlice::new(container, 512, 3584))?;
+/// # }
+/// # Ok::<(), zeekstd::Error>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct SeekableSlice<S> {
+    src: S,
+    start: u64,
+    len: u64,
+    pos: u64,
+    // Whether the offset of the source is at `start + pos`
+    positioned: bool,
+}
+
+impl<S> SeekableSlice<S> {
+    /// Creates a view of the `len` bytes of `src` that start at offset `start`.
+    pub fn new(src: S, start: u64, len: u64) -> Self {
+        Self {
+            src,
+            start,
+            len,
+            pos: 0,
+            positioned: false,
+        }
+    }
+
+    /// The offset of the window in the underlying source.
+    pub fn start(&self) -> u64 {
+        self.start
+    }
+
+    /// The length of the window in bytes.
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Whether the window is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Consumes the slice, returning the underlying source.
+    pub fn into_inner(self) -> S {
+        self.src
+    }
+}
+
+impl<S: Seekable> Seekable for SeekableSlice<S> {
+    fn set_offset(&mut self, offset: OffsetFrom) -> Result<u64> {
+        let pos = match offset {
+            OffsetFrom::Start(pos) => Some(pos),
+            OffsetFrom::End(delta) => self.len.checked_add_signed(delta),
+        }
+        .ok_or(Error::offset_out_of_range())?;
+        if pos > self.len {
+            return Err(Error::offset_out_of_range_with(pos, self.len));
+        }
+        let src_offset = self
+            .start
+            .checked_add(pos)
+            .ok_or(Error::offset_out_of_range())?;
+
+        self.src.set_offset(OffsetFrom::Start(src_offset))?;
+        self.pos = pos;
+        self.positioned = true;
+
+        Ok(pos)
+    }
+
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        // The source may be anywhere before the first offset is set
+        if !self.positioned {
+            self.set_offset(OffsetFrom::Start(self.pos))?;
+        }
+        let remaining = usize::try_from(self.len - self.pos).unwrap_or(usize::MAX);
+        let len = buf.len().min(remaining);
+        let n = self.src.read(&mut buf[..len])?;
+        self.pos += n as u64;
+
+        Ok(n)
+    }
+
+    fn size(&mut self) -> Result<u64> {
+        Ok(self.len)
+    }
+
+    fn seek_table_integrity(&mut self, format: Format) -> Result<[u8; SEEK_TABLE_INTEGRITY_SIZE]> {
+        match format {
+            Format::Head => self.set_offset(OffsetFrom::Start(SKIPPABLE_HEADER_SIZE as u64))?,
+            // Last 9 bytes
+            Format::Foot => {
+                self.set_offset(OffsetFrom::End(-(SEEK_TABLE_INTEGRITY_SIZE as i64)))?
+            }
+        };
+
+        let mut buf = [0u8; SEEK_TABLE_INTEGRITY_SIZE];
+        let mut read = 0;
+        while read < buf.len() {
+            let n = self.read(&mut buf[read..])?;
+            if n == 0 {
+                return Err(Error::offset_out_of_range());
+            }
+            read += n;
+        }
+
+        Ok(buf)
+    }
 }
 
 /// A seekable wrapper around a byte slice.
@@ -50,6 +357,76 @@ impl<'a> BytesWrapper<'a> {
     pub fn new(src: &'a [u8]) -> Self {
         Self { src, pos: 0 }
     }
+
+    /// Returns a new `BytesWrapper` around `range` of the wrapped slice.
+    ///
+    /// Offsets of the returned wrapper are relative to the start of `range`, its offset starts at
+    /// zero. Useful to decompress an archive that is embedded in a larger buffer without copying
+    /// it.
+    ///
+    /// # Panics
+    ///
+    /// If `range` is out of bounds of the wrapped slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zeekstd::{BytesWrapper, Seekable};
+    ///
+    /// let wrapper = BytesWrapper::new(b"Hello, World!");
+    /// let mut sub = wrapper.sub(7..12);
+    /// let mut buf = [0u8; 16];
+    ///
+    /// assert_eq!(5, sub.size()?);
+    /// assert_eq!(5, sub.read(&mut buf)?);
+    /// assert_eq!(b"World", &buf[..5]);
+    /// # Ok::<(), zeekstd::Error>(())
+    /// ```
+    pub fn sub(&self, range: Range<usize>) -> BytesWrapper<'a> {
+        Self::new(&self.src[range])
+    }
+}
+
+impl<'a> From<&'a [u8]> for BytesWrapper<'a> {
+    fn from(src: &'a [u8]) -> Self {
+        Self::new(src)
+    }
+}
+
+impl<'a> From<&'a Vec<u8>> for BytesWrapper<'a> {
+    fn from(src: &'a Vec<u8>) -> Self {
+        Self::new(src)
+    }
+}
+
+/// Zero-copy access to a memory-mapped seekable archive.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::fs::File;
+/// use memmap2::Mmap;
+/// use zeekstd::{BytesWrapper, Decoder};
+///
+/// let file = File::open("seekable.zst")?;
+/// let mmap = unsafe { Mmap::map(&file)? };
+/// let mut decoder = Decoder::new(BytesWrapper::from(&mmap))?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "mmap")]
+#[cfg_attr(docsrs, doc(cfg(feature = "mmap")))]
+impl<'a> From<&'a memmap2::Mmap> for BytesWrapper<'a> {
+    fn from(src: &'a memmap2::Mmap) -> Self {
+        Self::new(src)
+    }
+}
+
+#[cfg(feature = "mmap")]
+#[cfg_attr(docsrs, doc(cfg(feature = "mmap")))]
+impl<'a> From<&'a memmap2::MmapMut> for BytesWrapper<'a> {
+    fn from(src: &'a memmap2::MmapMut) -> Self {
+        Self::new(src)
+    }
 }
 
 impl Seekable for BytesWrapper<'_> {
@@ -64,7 +441,10 @@ impl Seekable for BytesWrapper<'_> {
         .ok_or(Error::offset_out_of_range())?;
 
         if pos > self.src.len() {
-            return Err(Error::offset_out_of_range());
+            return Err(Error::offset_out_of_range_with(
+                pos as u64,
+                self.src.len() as u64,
+            ));
         }
 
         self.pos = pos;
@@ -80,6 +460,10 @@ impl Seekable for BytesWrapper<'_> {
         Ok(len)
     }
 
+    fn size(&mut self) -> Result<u64> {
+        Ok(self.src.len() as u64)
+    }
+
     fn seek_table_integrity(&mut self, format: Format) -> Result<[u8; SEEK_TABLE_INTEGRITY_SIZE]> {
         let offset = match format {
             Format::Head => (self.src.len() >= SKIPPABLE_HEADER_SIZE + SEEK_TABLE_INTEGRITY_SIZE)